@@ -0,0 +1,30 @@
+use mick::{Position, STARTING_POSITION_FEN};
+
+/// The positions from the perft results page of the chess programming wiki, which cover the
+/// opening, middlegame and endgame along with castling, promotions and en passant captures
+pub const POSITIONS: [(&str, &str); 6] = [
+    ("startpos", STARTING_POSITION_FEN),
+    (
+        "kiwipete",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+    ),
+    ("endgame", "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1"),
+    (
+        "promotions",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+    ),
+    (
+        "discovered_check",
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+    ),
+    (
+        "middlegame",
+        "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10",
+    ),
+];
+
+pub fn positions() -> impl Iterator<Item = (&'static str, Position)> {
+    POSITIONS
+        .iter()
+        .map(|&(name, fen)| (name, Position::from_fen(fen)))
+}
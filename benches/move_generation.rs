@@ -0,0 +1,90 @@
+mod common;
+
+use common::positions;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use mick::{
+    board::Board,
+    move_list::{move_counter::MoveCounter, move_vec::MoveVec},
+    square::Square,
+};
+
+fn legal_moves(c: &mut Criterion) {
+    let mut group = c.benchmark_group("legal_moves");
+    for (name, position) in positions() {
+        group.bench_with_input(BenchmarkId::new("vec", name), &position, |b, position| {
+            b.iter(|| {
+                let mut moves = MoveVec::new();
+                position.legal_moves(&mut moves);
+                moves
+            })
+        });
+        group.bench_with_input(
+            BenchmarkId::new("counter", name),
+            &position,
+            |b, position| {
+                b.iter(|| {
+                    let mut counter = MoveCounter::new();
+                    position.legal_moves(&mut counter);
+                    counter.moves
+                })
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("bulk", name), &position, |b, position| {
+            b.iter(|| position.count_legal_moves())
+        });
+    }
+    group.finish();
+}
+
+fn make_unmake(c: &mut Criterion) {
+    let mut group = c.benchmark_group("make_unmake");
+    for (name, mut position) in positions() {
+        let (moves, _) = position.legal_moves_vec();
+        let state = position.state().clone();
+        let hash = position.hash();
+
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                for &m in moves.iter() {
+                    let capture = position.make(black_box(m));
+                    position.unmake(m, capture, &state, hash);
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+fn attacks(c: &mut Criterion) {
+    let occupancies: Vec<Board> = positions()
+        .map(|(_, position)| position.occupied())
+        .collect();
+
+    let mut group = c.benchmark_group("attacks");
+    group.bench_function("straight", |b| {
+        b.iter(|| {
+            let mut attacks = Board(0);
+            for &occupied in occupancies.iter() {
+                for square in 0..64 {
+                    attacks |= Square(square).straight_attacks(black_box(occupied));
+                }
+            }
+            attacks
+        })
+    });
+    group.bench_function("diagonal", |b| {
+        b.iter(|| {
+            let mut attacks = Board(0);
+            for &occupied in occupancies.iter() {
+                for square in 0..64 {
+                    attacks |= Square(square).diagonal_attacks(black_box(occupied));
+                }
+            }
+            attacks
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, legal_moves, make_unmake, attacks);
+criterion_main!(benches);
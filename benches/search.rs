@@ -0,0 +1,43 @@
+mod common;
+
+use common::positions;
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use mick::position::TranspositionTable;
+
+const SEARCH_DEPTH: u8 = 5;
+
+fn evaluate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("evaluate");
+    for (name, position) in positions() {
+        let (moves, is_in_check) = position.legal_moves_vec();
+        group.bench_with_input(
+            BenchmarkId::from_parameter(name),
+            &position,
+            |b, position| b.iter(|| position.evaluate(moves.len(), is_in_check)),
+        );
+    }
+    group.finish();
+}
+
+fn search(c: &mut Criterion) {
+    let mut group = c.benchmark_group("search");
+    group.sample_size(10);
+    for (name, position) in positions() {
+        group.bench_with_input(
+            BenchmarkId::new(format!("depth_{SEARCH_DEPTH}"), name),
+            &position,
+            |b, position| {
+                // Every search starts with an empty table, which isn't part of the measurement
+                b.iter_batched_ref(
+                    TranspositionTable::default,
+                    |transpositions| position.clone().search(SEARCH_DEPTH, None, transpositions),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, evaluate, search);
+criterion_main!(benches);
@@ -0,0 +1,20 @@
+use std::{env, fs, path::Path};
+
+#[allow(dead_code)]
+#[path = "src/square/generate.rs"]
+mod generate;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/square/generate.rs");
+
+    let out_dir = env::var_os("OUT_DIR").unwrap();
+    let out_dir = Path::new(&out_dir);
+    for (file, source) in [
+        ("square_tables.rs", generate::square_tables_source()),
+        ("magic_tables.rs", generate::magic_tables_source()),
+        ("pext_tables.rs", generate::pext_tables_source()),
+    ] {
+        fs::write(out_dir.join(file), source).unwrap();
+    }
+}
@@ -0,0 +1,398 @@
+mod consts;
+
+use self::consts::POLYGLOT_RANDOM;
+use crate::{
+    board::{Board, NOT_FILE_A, NOT_FILE_H},
+    castle::KING_SIDE,
+    pgn::{Game, GameResult, PgnReader},
+    piece::PAWN,
+    r#move::Move,
+    side::{Side, WHITE},
+    square::Square,
+    Position, STARTING_POSITION_FEN,
+};
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, Write},
+};
+
+const CASTLING_OFFSET: usize = 768;
+const EN_PASSANT_OFFSET: usize = 772;
+const TURN_OFFSET: usize = 780;
+
+/// Generates the key of a position as defined by the Polyglot book format
+pub fn polyglot_key(position: &Position) -> u64 {
+    let mut key = 0;
+
+    for (sq, _) in position.occupied().iter() {
+        let piece = position.at(sq);
+        // Polyglot orders pieces as pawn, knight, bishop, rook, queen, king with black first
+        let kind = 2 * (5 - piece.kind().0 as usize) + (piece.side() == WHITE) as usize;
+        key ^= POLYGLOT_RANDOM[64 * kind + sq.0 as usize];
+    }
+
+    let state = position.state();
+
+    // The order of the castling rights matches the order of the bits in `CastlingRights`
+    for i in 0..4 {
+        if state.castling_rights.0 & (1 << i) != 0 {
+            key ^= POLYGLOT_RANDOM[CASTLING_OFFSET + i];
+        }
+    }
+
+    // The en-passant file is only hashed if a pawn of the side to move can actually capture
+    if let Some(target) = state.en_passant_target {
+        let capturers = en_passant_capturers(target, state.side_to_move);
+        if (capturers & position.piece(PAWN.to_piece(state.side_to_move))).any() {
+            key ^= POLYGLOT_RANDOM[EN_PASSANT_OFFSET + target.file_index() as usize];
+        }
+    }
+
+    if state.side_to_move == WHITE {
+        key ^= POLYGLOT_RANDOM[TURN_OFFSET];
+    }
+
+    key
+}
+
+/// Encodes a move as defined by the Polyglot book format, with castles being encoded as the king
+/// capturing its own rook
+pub fn polyglot_move(m: Move) -> u16 {
+    let from = m.from();
+    let to = match m.castle() {
+        Some(castle) if castle == KING_SIDE => Square::from(from.rank_index(), 7),
+        Some(_) => Square::from(from.rank_index(), 0),
+        None => m.to(),
+    };
+    let promotion = m.promote_to().map_or(0, |kind| 5 - kind.0 as u16);
+
+    (to.file_index() as u16)
+        | ((to.rank_index() as u16) << 3)
+        | ((from.file_index() as u16) << 6)
+        | ((from.rank_index() as u16) << 9)
+        | (promotion << 12)
+}
+
+fn en_passant_capturers(target: Square, side: Side) -> Board {
+    let target = Board::new(target);
+    if side == WHITE {
+        ((target >> 7) & NOT_FILE_A) | ((target >> 9) & NOT_FILE_H)
+    } else {
+        ((target << 7) & NOT_FILE_H) | ((target << 9) & NOT_FILE_A)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Entry {
+    pub key: u64,
+    pub mv: u16,
+    pub weight: u16,
+    pub learn: u32,
+}
+
+impl Entry {
+    pub fn to_bytes(self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        bytes[0..8].copy_from_slice(&self.key.to_be_bytes());
+        bytes[8..10].copy_from_slice(&self.mv.to_be_bytes());
+        bytes[10..12].copy_from_slice(&self.weight.to_be_bytes());
+        bytes[12..16].copy_from_slice(&self.learn.to_be_bytes());
+        bytes
+    }
+
+    #[cfg(test)]
+    pub fn from_bytes(bytes: &[u8]) -> Entry {
+        Entry {
+            key: u64::from_be_bytes(bytes[0..8].try_into().unwrap()),
+            mv: u16::from_be_bytes(bytes[8..10].try_into().unwrap()),
+            weight: u16::from_be_bytes(bytes[10..12].try_into().unwrap()),
+            learn: u32::from_be_bytes(bytes[12..16].try_into().unwrap()),
+        }
+    }
+}
+
+/// Results of all games in which a move was played, from the perspective of the side that played it
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct MoveStats {
+    games: u32,
+    wins: u32,
+    draws: u32,
+    losses: u32,
+}
+
+impl MoveStats {
+    /// Like the reference implementation, a win counts twice as much as a draw
+    fn weight(&self) -> u64 {
+        2 * self.wins as u64 + self.draws as u64
+    }
+}
+
+/// Aggregates the moves played in a collection of games into a Polyglot opening book
+pub struct BookBuilder {
+    /// Number of plies per game that are added to the book
+    depth: usize,
+    min_rating: Option<u32>,
+    stats: HashMap<(u64, u16), MoveStats>,
+    pub games_added: u64,
+    pub games_skipped: u64,
+}
+
+impl BookBuilder {
+    pub fn new(depth: usize, min_rating: Option<u32>) -> BookBuilder {
+        BookBuilder {
+            depth,
+            min_rating,
+            stats: HashMap::new(),
+            games_added: 0,
+            games_skipped: 0,
+        }
+    }
+
+    pub fn add_pgn<R: BufRead>(&mut self, reader: R) -> io::Result<()> {
+        for game in PgnReader::new(reader) {
+            self.add_game(&game?);
+        }
+        Ok(())
+    }
+
+    /// Adds the opening moves of a single game, returns false if the game was filtered out
+    pub fn add_game(&mut self, game: &Game) -> bool {
+        if game.result == GameResult::Unknown || !self.has_min_rating(game) {
+            self.games_skipped += 1;
+            return false;
+        }
+
+        let mut position = match game.tag("FEN") {
+            Some(fen) => match Position::try_from_fen(fen) {
+                Ok(position) => position,
+                Err(_) => {
+                    self.games_skipped += 1;
+                    return false;
+                }
+            },
+            None => Position::from_fen(STARTING_POSITION_FEN),
+        };
+
+        let mut moves_added = 0;
+        for san in game.moves.iter().take(self.depth) {
+            // Stop at the first move we can't make sense of, the rest of the game is unusable
+            let m = match Move::try_from_san(san, &position) {
+                Ok(m) => m,
+                Err(_) => break,
+            };
+
+            let side_to_move = position.state().side_to_move;
+            let stats = self
+                .stats
+                .entry((polyglot_key(&position), polyglot_move(m)))
+                .or_default();
+
+            stats.games += 1;
+            match (game.result, side_to_move == WHITE) {
+                (GameResult::Draw, _) => stats.draws += 1,
+                (GameResult::WhiteWins, true) | (GameResult::BlackWins, false) => stats.wins += 1,
+                _ => stats.losses += 1,
+            }
+
+            position.make(m);
+            moves_added += 1;
+        }
+
+        if moves_added == 0 {
+            self.games_skipped += 1;
+            return false;
+        }
+
+        self.games_added += 1;
+        true
+    }
+
+    fn has_min_rating(&self, game: &Game) -> bool {
+        let min_rating = match self.min_rating {
+            Some(min_rating) => min_rating,
+            None => return true,
+        };
+
+        ["WhiteElo", "BlackElo"].iter().all(|tag| {
+            game.tag(tag)
+                .and_then(|rating| rating.parse::<u32>().ok())
+                .is_some_and(|rating| rating >= min_rating)
+        })
+    }
+
+    /// Returns the book entries sorted by key and descending weight. Moves played in less than
+    /// `min_games` games, as well as moves that never scored a single point, are left out.
+    pub fn entries(&self, min_games: u32) -> Vec<Entry> {
+        let mut by_key: HashMap<u64, Vec<(u16, u64)>> = HashMap::new();
+        for (&(key, mv), stats) in self.stats.iter() {
+            if stats.games >= min_games && stats.weight() > 0 {
+                by_key.entry(key).or_default().push((mv, stats.weight()));
+            }
+        }
+
+        let mut entries = Vec::with_capacity(self.stats.len());
+        for (key, moves) in by_key {
+            // Weights are stored in 16 bits, so scale them down proportionally if necessary
+            let max_weight = moves.iter().map(|&(_, weight)| weight).max().unwrap_or(0);
+            let scale = |weight: u64| {
+                if max_weight > u16::MAX as u64 {
+                    (weight * u16::MAX as u64 / max_weight).max(1)
+                } else {
+                    weight
+                }
+            };
+
+            for (mv, weight) in moves {
+                entries.push(Entry {
+                    key,
+                    mv,
+                    weight: scale(weight) as u16,
+                    learn: 0,
+                });
+            }
+        }
+
+        entries.sort_by(|a, b| {
+            a.key
+                .cmp(&b.key)
+                .then(b.weight.cmp(&a.weight))
+                .then(a.mv.cmp(&b.mv))
+        });
+        entries
+    }
+
+    /// Writes the book in the binary Polyglot format and returns the number of entries
+    pub fn write<W: Write>(&self, writer: &mut W, min_games: u32) -> io::Result<usize> {
+        let entries = self.entries(min_games);
+        for entry in entries.iter() {
+            writer.write_all(&entry.to_bytes())?;
+        }
+        writer.flush()?;
+        Ok(entries.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        book::{polyglot_key, polyglot_move, BookBuilder, Entry},
+        r#move::Move,
+        Position, STARTING_POSITION_FEN,
+    };
+
+    fn key_after(moves: &[&str]) -> u64 {
+        let mut position = Position::from_fen(STARTING_POSITION_FEN);
+        for m in moves {
+            position.make(Move::try_from_str(m, &position).unwrap());
+        }
+        polyglot_key(&position)
+    }
+
+    #[test]
+    fn keys() {
+        // Test vectors from the Polyglot book format specification
+        assert_eq!(key_after(&[]), 0x463b_9618_1691_fc9c);
+        assert_eq!(key_after(&["e2e4"]), 0x823c_9b50_fd11_4196);
+        assert_eq!(key_after(&["e2e4", "d7d5"]), 0x0756_b944_61c5_0fb0);
+        assert_eq!(key_after(&["e2e4", "d7d5", "e4e5"]), 0x662f_afb9_65db_29d4);
+        assert_eq!(
+            key_after(&["e2e4", "d7d5", "e4e5", "f7f5"]),
+            0x22a4_8b5a_8e47_ff78
+        );
+        assert_eq!(
+            key_after(&["e2e4", "d7d5", "e4e5", "f7f5", "e1e2"]),
+            0x652a_607c_a3f2_42c1
+        );
+        assert_eq!(
+            key_after(&["e2e4", "d7d5", "e4e5", "f7f5", "e1e2", "e8f7"]),
+            0x00fd_d303_c946_bdd9
+        );
+        assert_eq!(
+            key_after(&["a2a4", "b7b5", "h2h4", "b5b4", "c2c4"]),
+            0x3c81_23ea_7b06_7637
+        );
+        assert_eq!(
+            key_after(&["a2a4", "b7b5", "h2h4", "b5b4", "c2c4", "b4c3", "a1a3"]),
+            0x5c3f_9b82_9b27_9560
+        );
+    }
+
+    #[test]
+    fn moves() {
+        let position = Position::from_fen(STARTING_POSITION_FEN);
+        let m = Move::try_from_str("e2e4", &position).unwrap();
+        assert_eq!(polyglot_move(m), 4 | (3 << 3) | (4 << 6) | (1 << 9));
+
+        // Castles are encoded as the king capturing the rook
+        let position = Position::from_fen("r3k2r/8/8/8/8/8/8/R3K2R b KQkq - 0 1");
+        let m = Move::try_from_str("e8g8", &position).unwrap();
+        assert_eq!(polyglot_move(m), 7 | (7 << 3) | (4 << 6) | (7 << 9));
+        let m = Move::try_from_str("e8c8", &position).unwrap();
+        assert_eq!(polyglot_move(m), (7 << 3) | (4 << 6) | (7 << 9));
+
+        // Promotions
+        let position = Position::from_fen("7k/P7/8/8/8/8/8/K7 w - - 0 1");
+        let m = Move::try_from_str("a7a8q", &position).unwrap();
+        assert_eq!(polyglot_move(m), (7 << 3) | (6 << 9) | (4 << 12));
+        let m = Move::try_from_str("a7a8n", &position).unwrap();
+        assert_eq!(polyglot_move(m), (7 << 3) | (6 << 9) | (1 << 12));
+    }
+
+    #[test]
+    fn builds_book() {
+        let pgn = r#"[WhiteElo "2400"]
+[BlackElo "2300"]
+
+1. e4 e5 2. Nf3 1-0
+
+[WhiteElo "2400"]
+[BlackElo "2300"]
+
+1. e4 c5 1/2-1/2
+
+[WhiteElo "2400"]
+[BlackElo "2300"]
+
+1. d4 d5 0-1
+
+[WhiteElo "1200"]
+[BlackElo "2300"]
+
+1. c4 e5 1-0
+
+[WhiteElo "2400"]
+[BlackElo "2300"]
+
+1. e5 e4 1-0
+"#;
+        let mut builder = BookBuilder::new(2, Some(2000));
+        builder.add_pgn(pgn.as_bytes()).unwrap();
+        // The last game doesn't start with a legal move, so nothing of it is used
+        assert_eq!(builder.games_added, 3);
+        assert_eq!(builder.games_skipped, 2);
+
+        let start = key_after(&[]);
+        let e4 = polyglot_move(
+            Move::try_from_str("e2e4", &Position::from_fen(STARTING_POSITION_FEN)).unwrap(),
+        );
+
+        // 1. d4 only lost, so it's not part of the book
+        let entries = builder.entries(1);
+        let start_entries: Vec<&Entry> = entries.iter().filter(|e| e.key == start).collect();
+        assert_eq!(start_entries.len(), 1);
+        assert_eq!(start_entries[0].mv, e4);
+        assert_eq!(start_entries[0].weight, 3);
+
+        // Black won with 1... d5 and drew with 1... c5, while 1... e5 only lost
+        assert_eq!(entries.len(), 3);
+        assert!(entries.windows(2).all(|w| w[0].key <= w[1].key));
+
+        // Only 1. e4 was played in at least two games
+        assert_eq!(builder.entries(2), vec![*start_entries[0]]);
+
+        let mut bytes = vec![];
+        assert_eq!(builder.write(&mut bytes, 1).unwrap(), 3);
+        assert_eq!(bytes.len(), 3 * 16);
+        assert_eq!(Entry::from_bytes(&bytes[0..16]), entries[0]);
+    }
+}
@@ -1,61 +1,127 @@
-#[derive(Copy, Clone)]
+use std::sync::atomic::{AtomicU64, Ordering};
+
+const DEPTH_BITS: u32 = 8;
+const MAX_COUNT: u64 = (1 << (64 - DEPTH_BITS)) - 1;
+
+/// The key is stored XORed with the data, so that an entry torn by a concurrent write doesn't
+/// verify and is treated as a miss
+#[derive(Default)]
 struct Entry {
-    key: u64,
-    count: u64,
-    depth: i16,
+    key: AtomicU64,
+    data: AtomicU64,
 }
 
+impl Entry {
+    /// Returns the depth and count, if the entry belongs to the key
+    fn load(&self, key: u64) -> Option<(u8, u64)> {
+        let data = self.data.load(Ordering::Relaxed);
+        let stored_key = self.key.load(Ordering::Relaxed);
+        (stored_key ^ data == key).then_some((data as u8, data >> DEPTH_BITS))
+    }
+
+    fn store(&self, key: u64, count: u64, depth: u8) {
+        let data = (count << DEPTH_BITS) | depth as u64;
+        self.key.store(key ^ data, Ordering::Relaxed);
+        self.data.store(data, Ordering::Relaxed);
+    }
+
+    fn depth(&self) -> u8 {
+        self.data.load(Ordering::Relaxed) as u8
+    }
+}
+
+/// The first entry keeps the deepest result, the second one always takes the latest
+#[derive(Default)]
+#[repr(align(32))]
+struct Bucket([Entry; 2]);
+
+/// Table of perft node counts by position hash and depth, which can be shared between threads
+/// without locking
 pub struct Cache {
-    entries: Box<[Entry]>,
+    buckets: Box<[Bucket]>,
     mask: usize,
 }
 
 impl Cache {
+    /// Sizes that aren't 2^N bytes are rounded down to the next power of two
     pub fn new(size_bytes: usize) -> Result<Cache, String> {
-        if size_bytes.count_ones() != 1 {
-            return Err("Cache size must be 2^N".to_string());
-        }
-
         if size_bytes < 1024 {
             return Err("Cache size must be at least 1024 bytes".to_string());
         }
 
-        let size = size_bytes / 16;
-
-        let vec = vec![
-            Entry {
-                key: 0,
-                count: 0,
-                depth: -1,
-            };
-            size
-        ];
+        let size_bytes = 1 << size_bytes.ilog2();
+        let size = size_bytes / std::mem::size_of::<Bucket>();
+        let buckets = (0..size).map(|_| Bucket::default()).collect();
 
         Ok(Cache {
-            entries: vec.into_boxed_slice(),
+            buckets,
             mask: size - 1,
         })
     }
 
+    fn bucket(&self, key: u64) -> &Bucket {
+        unsafe { self.buckets.get_unchecked((key as usize) & self.mask) }
+    }
+
     pub fn probe(&self, key: u64, depth: usize) -> Option<u64> {
-        let idx = (key as usize) & self.mask;
-        let entry = unsafe { self.entries.get_unchecked(idx) };
+        self.bucket(key)
+            .0
+            .iter()
+            .filter_map(|entry| entry.load(key))
+            .find(|&(entry_depth, _)| entry_depth as usize == depth)
+            .map(|(_, count)| count)
+    }
 
-        if entry.key == key && entry.depth == (depth as i16) {
-            Some(entry.count)
+    pub fn save(&self, key: u64, count: u64, depth: usize) {
+        if count > MAX_COUNT || depth > u8::MAX as usize {
+            return;
+        }
+
+        let depth = depth as u8;
+        let [deepest, latest] = &self.bucket(key).0;
+        if deepest.depth() <= depth || deepest.load(key).is_some() {
+            deepest.store(key, count, depth);
         } else {
-            None
+            latest.store(key, count, depth);
         }
     }
+}
 
-    pub fn save(&mut self, hash: u64, count: u64, depth: i16) {
-        let idx = (hash as usize) & self.mask;
-        let entry = unsafe { self.entries.get_unchecked_mut(idx) };
+#[cfg(test)]
+mod tests {
+    use crate::cache::Cache;
 
-        *entry = Entry {
-            key: hash,
-            count,
-            depth,
-        }
+    #[test]
+    fn two_bucket_replacement() {
+        let cache = Cache::new(1024).unwrap();
+        let buckets = 1024 / 32;
+
+        assert_eq!(cache.probe(5, 3), None);
+        cache.save(5, 8902, 3);
+        assert_eq!(cache.probe(5, 3), Some(8902));
+        assert_eq!(cache.probe(5, 2), None);
+
+        // A shallower position of the same bucket goes into the second entry
+        cache.save(5 + buckets, 400, 2);
+        assert_eq!(cache.probe(5, 3), Some(8902));
+        assert_eq!(cache.probe(5 + buckets, 2), Some(400));
+
+        // which always gets replaced
+        cache.save(5 + 2 * buckets, 20, 1);
+        assert_eq!(cache.probe(5 + buckets, 2), None);
+        assert_eq!(cache.probe(5 + 2 * buckets, 1), Some(20));
+
+        // while the first entry only makes way for deeper results
+        cache.save(5 + 3 * buckets, 197281, 4);
+        assert_eq!(cache.probe(5, 3), None);
+        assert_eq!(cache.probe(5 + 3 * buckets, 4), Some(197281));
+        assert_eq!(cache.probe(5 + 2 * buckets, 1), Some(20));
+    }
+
+    #[test]
+    fn size() {
+        assert_eq!(Cache::new(1500).unwrap().buckets.len(), 1024 / 32);
+        assert_eq!(Cache::new(3 << 20).unwrap().buckets.len(), (2 << 20) / 32);
+        assert!(Cache::new(1000).is_err());
     }
 }
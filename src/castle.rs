@@ -34,7 +34,7 @@ impl CastlingRights {
     }
 
     pub fn clear_side(&mut self, side: Side) {
-        let rights = WHITE_RIGHTS.0 << side.0;
+        let rights = WHITE_RIGHTS.0 << (2 * side.0);
         self.0 &= !rights;
     }
 
@@ -77,6 +77,7 @@ mod tests {
         CastlingRights, ALL_RIGHTS, BLACK_KING_SIDE, BLACK_QUEEN_SIDE, NO_RIGHTS, WHITE_KING_SIDE,
         WHITE_QUEEN_SIDE,
     };
+    use crate::side::{BLACK, WHITE};
 
     #[test]
     fn from_valid() {
@@ -88,6 +89,15 @@ mod tests {
         assert_eq!(CastlingRights::try_from_str("KkQq"), Ok(ALL_RIGHTS));
     }
 
+    #[test]
+    fn clear_side() {
+        let mut rights = ALL_RIGHTS;
+        rights.clear_side(BLACK);
+        assert_eq!(rights, CastlingRights::try_from_str("KQ").unwrap());
+        rights.clear_side(WHITE);
+        assert_eq!(rights, NO_RIGHTS);
+    }
+
     #[test]
     fn from_invalid() {
         assert_eq!(
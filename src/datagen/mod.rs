@@ -0,0 +1,209 @@
+mod record;
+
+pub use record::{Record, BLACK_WIN, DRAW, WHITE_WIN};
+
+use crate::{
+    position::{Evaluation, TranspositionTable},
+    side::{Side, BLACK, WHITE},
+    syzygy::TABLEBASE_WIN,
+    Position, STARTING_POSITION_FEN,
+};
+use rand::{rngs::StdRng, seq::IteratorRandom, Rng, SeedableRng};
+use std::{
+    io::{self, Write},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::channel,
+        Arc,
+    },
+};
+use threadpool::ThreadPool;
+
+/// Games where the opening already leaves one side this far ahead are thrown away
+const MAX_OPENING_SCORE: i32 = 1000;
+
+/// A game is adjudicated as a win once the score stays above this for a number of plies in a row
+const WIN_ADJUDICATION_SCORE: i32 = 2500;
+const WIN_ADJUDICATION_PLIES: i32 = 6;
+
+/// Games that take longer than this are adjudicated as a draw
+const MAX_GAME_PLIES: usize = 400;
+
+#[derive(Clone, Debug)]
+pub struct DatagenConfig {
+    pub games: usize,
+    pub depth: u8,
+    pub nodes: Option<u64>,
+    /// Number of random moves played at the start of each game
+    pub random_plies: usize,
+    pub threads: usize,
+    pub seed: Option<u64>,
+}
+
+/// Plays self-play games across threads and writes the quiet positions of each game to the
+/// writer, returning the number of games and positions
+pub fn generate(config: &DatagenConfig, writer: &mut impl Write) -> io::Result<(usize, usize)> {
+    let pool = ThreadPool::new(config.threads.max(1));
+    let (tx, rx) = channel();
+
+    // Every game that gets thrown away is replaced, so each worker keeps playing until enough
+    // games are finished in total
+    let finished = Arc::new(AtomicUsize::new(0));
+    for thread in 0..config.threads.max(1) {
+        let tx = tx.clone();
+        let finished = finished.clone();
+        let config = config.clone();
+
+        pool.execute(move || {
+            let mut rng = match config.seed {
+                Some(seed) => StdRng::seed_from_u64(seed.wrapping_add(thread as u64)),
+                None => StdRng::from_entropy(),
+            };
+
+            let mut transpositions = TranspositionTable::default();
+            while finished.load(Ordering::Relaxed) < config.games {
+                if let Some(records) = play_game(&config, &mut rng, &mut transpositions) {
+                    if finished.fetch_add(1, Ordering::Relaxed) < config.games {
+                        tx.send(records).unwrap();
+                    }
+                }
+            }
+        });
+    }
+    drop(tx);
+
+    let mut games = 0;
+    let mut positions = 0;
+    for records in rx.iter() {
+        for record in records.iter() {
+            record.write(writer)?;
+        }
+
+        games += 1;
+        positions += records.len();
+        if games % 100 == 0 {
+            println!("Played {games} games, {positions} positions");
+        }
+    }
+    writer.flush()?;
+
+    Ok((games, positions))
+}
+
+/// Plays a single game from a random opening, returning the quiet positions along with their
+/// scores and the result, or `None` if the opening was unusable. The transposition table is kept
+/// across the moves of the game.
+fn play_game(
+    config: &DatagenConfig,
+    rng: &mut impl Rng,
+    transpositions: &mut TranspositionTable,
+) -> Option<Vec<Record>> {
+    transpositions.clear();
+    let mut position = Position::from_fen(STARTING_POSITION_FEN);
+    position.state_mut().track_hashes();
+
+    for _ in 0..config.random_plies {
+        let (legal_moves, _) = position.legal_moves_vec();
+        position.make(*legal_moves.iter().choose(rng)?);
+    }
+
+    let mut positions = vec![];
+    // Positive while white is winning, negative while black is winning
+    let mut winning_plies: i32 = 0;
+
+    let result = loop {
+        let (legal_moves, is_in_check) = position.legal_moves_vec();
+        match position.evaluate(legal_moves.len(), is_in_check) {
+            Evaluation::Win(side) => break to_result(side),
+            Evaluation::Draw(_) => break DRAW,
+            Evaluation::None(_) => {}
+        }
+        if positions.len() >= MAX_GAME_PLIES {
+            break DRAW;
+        }
+
+        let (score, best_move) = position.search(config.depth, config.nodes, transpositions)?;
+        let side_to_move = position.state().side_to_move;
+        let white_score = if side_to_move == WHITE { score } else { -score };
+
+        if positions.is_empty() && white_score.abs() > MAX_OPENING_SCORE {
+            return None;
+        }
+
+        // Mate scores aren't useful for training, and these games are decided anyway
+        if white_score.abs() >= TABLEBASE_WIN {
+            break to_result(if white_score > 0 { WHITE } else { BLACK });
+        }
+
+        winning_plies = if white_score >= WIN_ADJUDICATION_SCORE {
+            winning_plies.max(0) + 1
+        } else if white_score <= -WIN_ADJUDICATION_SCORE {
+            winning_plies.min(0) - 1
+        } else {
+            0
+        };
+        if winning_plies.abs() >= WIN_ADJUDICATION_PLIES {
+            break to_result(if winning_plies > 0 { WHITE } else { BLACK });
+        }
+
+        // Only quiet positions are kept, as the static evaluation can't be expected to resolve
+        // captures and checks
+        let is_tactical = best_move.is_capture() || best_move.promote_to().is_some();
+        positions.push((!is_in_check && !is_tactical).then(|| (position.clone(), white_score)));
+
+        position.make(best_move);
+    };
+
+    Some(
+        positions
+            .into_iter()
+            .flatten()
+            .map(|(mut position, score)| {
+                position.state_mut().prev_hashes = None;
+                Record {
+                    position,
+                    score: score.clamp(i16::MIN as i32, i16::MAX as i32) as i16,
+                    result,
+                }
+            })
+            .collect(),
+    )
+}
+
+fn to_result(winner: Side) -> u8 {
+    if winner == WHITE {
+        WHITE_WIN
+    } else {
+        BLACK_WIN
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::datagen::{generate, DatagenConfig, Record};
+
+    #[test]
+    fn generates_quiet_positions() {
+        let config = DatagenConfig {
+            games: 2,
+            depth: 1,
+            nodes: None,
+            random_plies: 8,
+            threads: 2,
+            seed: Some(1),
+        };
+        let mut bytes = vec![];
+        let (games, positions) = generate(&config, &mut bytes).unwrap();
+        assert_eq!(games, 2);
+        assert!(positions > 0);
+
+        let mut reader = bytes.as_slice();
+        let mut count = 0;
+        while let Some(record) = Record::read(&mut reader).unwrap() {
+            let (_, is_in_check) = record.position.legal_moves_vec();
+            assert!(!is_in_check);
+            count += 1;
+        }
+        assert_eq!(count, positions);
+    }
+}
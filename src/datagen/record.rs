@@ -0,0 +1,173 @@
+use crate::{
+    castle::CastlingRights,
+    piece::{Piece, NULL_PIECE},
+    position::State,
+    side::Side,
+    square::Square,
+    Position,
+};
+use std::io::{self, Read, Write};
+
+/// Number of bytes of a single record
+pub const RECORD_SIZE: usize = 32;
+
+/// Marks a missing en passant target square
+const NO_EN_PASSANT: u8 = 64;
+
+/// Result of the game from white's perspective
+pub const BLACK_WIN: u8 = 0;
+pub const DRAW: u8 = 1;
+pub const WHITE_WIN: u8 = 2;
+
+/// A position together with its search score and the result of the game it was played in, both
+/// from white's perspective
+///
+/// Records are stored as 32 bytes in the following order, with all integers in little endian:
+/// - the occupied squares as `u64`
+/// - one nibble for each occupied piece in the order of the squares, low nibble first
+/// - the side to move in the lowest bit, followed by four bits of castling rights
+/// - the en passant target square, or 64 if there is none
+/// - the halfmove clock as `u8` and the fullmove number as `u16`
+/// - the score as `i16`
+/// - the result, which is 0 for a black win, 1 for a draw and 2 for a white win
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    pub position: Position,
+    pub score: i16,
+    pub result: u8,
+}
+
+impl Record {
+    pub fn to_bytes(&self) -> [u8; RECORD_SIZE] {
+        let mut bytes = [0; RECORD_SIZE];
+        let state = self.position.state();
+
+        let occupied = self.position.occupied();
+        bytes[0..8].copy_from_slice(&occupied.0.to_le_bytes());
+        for (i, (sq, _)) in occupied.iter().enumerate() {
+            bytes[8 + i / 2] |= self.position.at(sq).0 << (4 * (i % 2));
+        }
+
+        bytes[24] = state.side_to_move.0 | (state.castling_rights.0 << 1);
+        bytes[25] = state.en_passant_target.map_or(NO_EN_PASSANT, |sq| sq.0);
+        bytes[26] = state.halfmove_clock.min(u8::MAX as u32) as u8;
+        bytes[27..29]
+            .copy_from_slice(&(state.fullmove_number.min(u16::MAX as u32) as u16).to_le_bytes());
+        bytes[29..31].copy_from_slice(&self.score.to_le_bytes());
+        bytes[31] = self.result;
+
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8; RECORD_SIZE]) -> Result<Record, String> {
+        let occupied = u64::from_le_bytes(bytes[0..8].try_into().unwrap());
+        if occupied.count_ones() > 32 {
+            return Err("Invalid record: more than 32 pieces".to_string());
+        }
+
+        let mut pieces = [NULL_PIECE; 64];
+        let mut remaining = occupied;
+        let mut i = 0;
+        while remaining != 0 {
+            let sq = remaining.trailing_zeros() as usize;
+            let pc = (bytes[8 + i / 2] >> (4 * (i % 2))) & 0b1111;
+            if pc >= NULL_PIECE.0 {
+                return Err(format!("Invalid record: unknown piece {pc}"));
+            }
+            pieces[sq] = Piece(pc);
+            remaining &= remaining - 1;
+            i += 1;
+        }
+
+        let en_passant_target = match bytes[25] {
+            NO_EN_PASSANT => None,
+            sq if sq < 64 => Some(Square(sq)),
+            sq => return Err(format!("Invalid record: en passant square {sq}")),
+        };
+        if bytes[31] > WHITE_WIN {
+            return Err(format!("Invalid record: result {}", bytes[31]));
+        }
+
+        let state = State {
+            side_to_move: Side(bytes[24] & 1),
+            castling_rights: CastlingRights((bytes[24] >> 1) & 0b1111),
+            en_passant_target,
+            halfmove_clock: bytes[26] as u32,
+            fullmove_number: u16::from_le_bytes([bytes[27], bytes[28]]) as u32,
+            prev_hashes: None,
+        };
+
+        Ok(Record {
+            position: Position::new(pieces, state),
+            score: i16::from_le_bytes([bytes[29], bytes[30]]),
+            result: bytes[31],
+        })
+    }
+
+    pub fn write(&self, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Reads the next record, returning `None` at the end of the input
+    pub fn read(reader: &mut impl Read) -> Result<Option<Record>, String> {
+        let mut bytes = [0; RECORD_SIZE];
+        match reader.read_exact(&mut bytes) {
+            Ok(()) => Record::from_bytes(&bytes).map(Some),
+            Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
+            Err(err) => Err(format!("Failed to read record: {err}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        datagen::record::{Record, DRAW, RECORD_SIZE, WHITE_WIN},
+        Position, STARTING_POSITION_FEN,
+    };
+
+    #[test]
+    fn round_trip() {
+        for (fen, score, result) in [
+            (STARTING_POSITION_FEN, 25, DRAW),
+            (
+                "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+                -130,
+                WHITE_WIN,
+            ),
+            ("8/8/8/2k5/2pP4/8/B7/4K3 b - d3 3 57", 312, WHITE_WIN),
+        ] {
+            let record = Record {
+                position: Position::from_fen(fen),
+                score,
+                result,
+            };
+            let bytes = record.to_bytes();
+            assert_eq!(bytes.len(), RECORD_SIZE);
+
+            let decoded = Record::from_bytes(&bytes).unwrap();
+            assert_eq!(decoded.position.to_fen(), fen);
+            assert_eq!(decoded.score, score);
+            assert_eq!(decoded.result, result);
+        }
+    }
+
+    #[test]
+    fn read() {
+        let record = Record {
+            position: Position::from_fen(STARTING_POSITION_FEN),
+            score: 0,
+            result: DRAW,
+        };
+        let mut bytes = vec![];
+        record.write(&mut bytes).unwrap();
+        record.write(&mut bytes).unwrap();
+
+        let mut reader = bytes.as_slice();
+        assert_eq!(Record::read(&mut reader).unwrap(), Some(record.clone()));
+        assert_eq!(Record::read(&mut reader).unwrap(), Some(record));
+        assert_eq!(Record::read(&mut reader).unwrap(), None);
+
+        assert!(Record::from_bytes(&[0xff; RECORD_SIZE]).is_err());
+    }
+}
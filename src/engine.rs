@@ -1,15 +1,43 @@
 use std::{
     error::Error,
     io::stdin,
-    sync::mpsc::{channel, Sender},
+    sync::{
+        mpsc::{channel, Sender},
+        Arc,
+    },
     thread::spawn,
 };
 
-use crate::{r#move::Move, Position, STARTING_POSITION_FEN};
+use crate::{
+    nnue::Network,
+    perft_divide,
+    position::{SearchOptions, TranspositionTable},
+    r#move::Move,
+    syzygy::Tablebases,
+    EvalParams, Position, STARTING_POSITION_FEN,
+};
+
+/// UCI names of the switches for the selective search
+const SEARCH_OPTIONS: [&str; 8] = [
+    "NullMove",
+    "LateMoveReductions",
+    "Futility",
+    "ReverseFutility",
+    "Razoring",
+    "CheckExtensions",
+    "RecaptureExtensions",
+    "SingularExtensions",
+];
 
-pub fn engine_loop() -> Result<(), Box<dyn Error>> {
+pub fn engine_loop(mut params: Arc<EvalParams>) -> Result<(), Box<dyn Error>> {
     let mut position = Position::from_fen(STARTING_POSITION_FEN);
+    position.set_params(params.clone());
+    let mut tablebases: Option<Tablebases> = None;
+    let mut network: Option<Arc<Network>> = None;
     let mut stop: Option<Sender<()>> = None;
+    let mut search_options = SearchOptions::default();
+    let mut transpositions = TranspositionTable::default();
+    let mut mate_checks_only = false;
 
     loop {
         let mut buffer = String::new();
@@ -22,39 +50,80 @@ pub fn engine_loop() -> Result<(), Box<dyn Error>> {
             Some("uci") => {
                 println!("id name mick 0.1");
                 println!("id author Thomas Heyenbrock");
+                println!("option name SyzygyPath type string default <empty>");
+                println!("option name EvalFile type string default <empty>");
+                println!("option name EvalParams type string default <empty>");
+                for name in SEARCH_OPTIONS {
+                    println!("option name {name} type check default true");
+                }
+                println!("option name MateChecksOnly type check default false");
                 println!("uciok");
             }
             Some("isready") => println!("readyok"),
-            Some("ucinewgame") => position = Position::from_fen(STARTING_POSITION_FEN),
-            Some("position") => {
-                let mut fen = String::from(command_iter.next().unwrap_or_default());
-                let mut new_position = if fen == "startpos" {
-                    Position::from_fen(STARTING_POSITION_FEN)
-                } else {
-                    let mut next = command_iter.next();
-                    while let Some(value) = next {
-                        if value == "moves" {
-                            next = None;
-                        } else {
-                            fen.push_str(value);
-                            next = command_iter.next();
+            Some("setoption") => {
+                let (name, value) = parse_option(command_iter);
+                if name.eq_ignore_ascii_case("SyzygyPath") {
+                    tablebases = None;
+                    if !value.is_empty() && value != "<empty>" {
+                        match Tablebases::open(&value) {
+                            Ok(tb) => {
+                                println!(
+                                    "info string found {} tablebases with up to {} pieces",
+                                    tb.table_count(),
+                                    tb.max_pieces()
+                                );
+                                tablebases = Some(tb);
+                            }
+                            Err(err) => println!("info string {err}"),
                         }
                     }
-                    Position::try_from_fen(&fen)
-                        .unwrap_or(Position::from_fen(STARTING_POSITION_FEN))
-                };
-                new_position.state_mut().track_hashes();
-
-                for value in command_iter {
-                    if let Ok(m) = Move::try_from_str(value, &new_position) {
-                        new_position.make(m);
+                } else if name.eq_ignore_ascii_case("EvalFile") {
+                    network = None;
+                    if !value.is_empty() && value != "<empty>" {
+                        match Network::load(&value) {
+                            Ok(nn) => {
+                                println!("info string loaded network {value}");
+                                network = Some(Arc::new(nn));
+                            }
+                            Err(err) => println!("info string {err}"),
+                        }
                     }
+                    position.set_network(network.clone());
+                } else if name.eq_ignore_ascii_case("EvalParams") {
+                    params = EvalParams::shared_default();
+                    if !value.is_empty() && value != "<empty>" {
+                        match EvalParams::load(&value) {
+                            Ok(p) => {
+                                println!("info string loaded parameters {value}");
+                                params = Arc::new(p);
+                            }
+                            Err(err) => println!("info string {err}"),
+                        }
+                    }
+                    position.set_params(params.clone());
+                } else if name.eq_ignore_ascii_case("MateChecksOnly") {
+                    mate_checks_only = value.eq_ignore_ascii_case("true");
+                } else if let Some(option) = search_option(&mut search_options, &name) {
+                    *option = value.eq_ignore_ascii_case("true");
                 }
+            }
+            Some("ucinewgame") => {
+                transpositions.clear();
+                position = Position::from_fen(STARTING_POSITION_FEN);
+                position.set_params(params.clone());
+                position.set_network(network.clone());
+            }
+            Some("position") => {
+                let mut new_position = parse_position(command_iter, params.clone());
 
+                // Only set up the network now, so the accumulators of the moves above are dropped
+                new_position.set_network(network.clone());
                 position = new_position;
             }
             Some("go") => {
                 let mut depth = 0;
+                let mut mate = None;
+                let mut perft_depth = None;
 
                 loop {
                     let next = command_iter.next();
@@ -65,12 +134,65 @@ pub fn engine_loop() -> Result<(), Box<dyn Error>> {
                                 .and_then(|d| d.parse().ok())
                                 .unwrap_or(0)
                         }
+                        Some("mate") => mate = command_iter.next().and_then(|n| n.parse().ok()),
+                        Some("perft") => {
+                            perft_depth = command_iter.next().and_then(|d| d.parse().ok())
+                        }
                         _ => break,
                     }
                 }
 
-                if depth > 0 {
-                    let best_move = position.alphabeta(depth, i32::MIN, i32::MAX);
+                if let Some(depth) = perft_depth {
+                    let divide =
+                        perft_divide(&mut position, depth, num_cpus::get(), 1024 * 1024 * 4);
+                    for (m, count) in divide.iter() {
+                        println!("{m}: {count}");
+                    }
+                    let nodes: u64 = divide.iter().map(|(_, count)| count).sum();
+                    println!("\nNodes searched: {}", if depth == 0 { 1 } else { nodes });
+                } else if let Some(moves) = mate {
+                    let result = position.solve_mate(moves, mate_checks_only);
+                    match (result.moves(), result.line) {
+                        (Some(mate_in), Some(line)) => {
+                            println!(
+                                "info depth {} nodes {} score mate {mate_in} pv {}",
+                                line.len(),
+                                result.nodes,
+                                line.iter()
+                                    .map(|m| m.to_string())
+                                    .collect::<Vec<String>>()
+                                    .join(" ")
+                            );
+                            println!("bestmove {}", line[0]);
+                        }
+                        _ => {
+                            println!("info nodes {} string no mate in {moves}", result.nodes);
+                            println!("bestmove 0000");
+                        }
+                    }
+                } else if let Some(probe) = tablebases
+                    .as_ref()
+                    .and_then(|tb| tb.probe_root(&mut position))
+                {
+                    // Play perfectly if the position is covered by the tablebases. DTZ only gives
+                    // the distance to mate if the best move mates right away.
+                    let mut next = position.clone();
+                    next.make(probe.best_move);
+                    let (replies, is_in_check) = next.legal_moves_vec();
+                    let score = if replies.is_empty() && is_in_check {
+                        "mate 1".to_string()
+                    } else {
+                        format!("cp {}", probe.uci_score())
+                    };
+                    println!("info depth 1 score {score} tbhits 1 pv {}", probe.best_move);
+                    println!("bestmove {}", probe.best_move);
+                } else if depth > 0 {
+                    let best_move = position.alphabeta(
+                        depth,
+                        &search_options,
+                        tablebases.as_ref(),
+                        &mut transpositions,
+                    );
                     println!("bestmove {best_move}");
                 }
 
@@ -110,3 +232,99 @@ pub fn engine_loop() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+/// Returns the switch of the selective search with the given UCI name
+fn search_option<'a>(options: &'a mut SearchOptions, name: &str) -> Option<&'a mut bool> {
+    let index = SEARCH_OPTIONS
+        .iter()
+        .position(|option| option.eq_ignore_ascii_case(name))?;
+    Some(match index {
+        0 => &mut options.null_move,
+        1 => &mut options.late_move_reductions,
+        2 => &mut options.futility,
+        3 => &mut options.reverse_futility,
+        4 => &mut options.razoring,
+        5 => &mut options.check_extensions,
+        6 => &mut options.recapture_extensions,
+        _ => &mut options.singular_extensions,
+    })
+}
+
+/// Splits the arguments of a `setoption` command into the name and the value of the option, both
+/// of which can contain spaces
+fn parse_option<'a>(args: impl Iterator<Item = &'a str>) -> (String, String) {
+    let mut name = vec![];
+    let mut value = vec![];
+    let mut is_value = false;
+
+    for arg in args {
+        match arg {
+            "name" if !is_value && name.is_empty() => {}
+            "value" if !is_value => is_value = true,
+            arg if is_value => value.push(arg),
+            arg => name.push(arg),
+        }
+    }
+
+    (name.join(" "), value.join(" "))
+}
+
+/// Sets up the position from the arguments of a `position` command, which are either `startpos` or
+/// `fen` followed by the FEN, and optionally `moves` followed by the moves to make
+fn parse_position<'a>(
+    mut args: impl Iterator<Item = &'a str>,
+    params: Arc<EvalParams>,
+) -> Position {
+    let mut position = if args.next() == Some("fen") {
+        let fen = args
+            .by_ref()
+            .take_while(|&value| value != "moves")
+            .collect::<Vec<&str>>()
+            .join(" ");
+        Position::try_from_fen(&fen).unwrap_or(Position::from_fen(STARTING_POSITION_FEN))
+    } else {
+        // Skip ahead to the moves
+        args.next();
+        Position::from_fen(STARTING_POSITION_FEN)
+    };
+    position.state_mut().track_hashes();
+    position.set_params(params);
+
+    for value in args {
+        if let Ok(m) = Move::try_from_str(value, &position) {
+            position.make(m);
+        }
+    }
+
+    position
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{engine::parse_position, EvalParams};
+    use std::sync::Arc;
+
+    fn fen_after(command: &str) -> String {
+        parse_position(command.split_whitespace(), Arc::new(EvalParams::default())).to_fen()
+    }
+
+    #[test]
+    fn position_command() {
+        assert_eq!(
+            fen_after("startpos"),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
+        );
+        assert_eq!(
+            fen_after("startpos moves e2e4 e7e5 g1f3"),
+            "rnbqkbnr/pppp1ppp/8/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 1 2"
+        );
+        assert_eq!(
+            fen_after("fen r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1 moves e1g1 e8c8"),
+            "2kr3r/8/8/8/8/8/8/R4RK1 w - - 2 2"
+        );
+        assert_eq!(
+            fen_after("fen 8/8/8/8/8/8/8/K1k5 w - - 0 1"),
+            "8/8/8/8/8/8/8/K1k5 w - - 0 1"
+        );
+    }
+}
@@ -106,7 +106,7 @@ impl Zobrist {
         self.piece_square(pc_from, from) ^ self.piece_square(pc_to, to)
     }
 
-    fn piece_square(&self, piece: Piece, square: Square) -> u64 {
+    pub fn piece_square(&self, piece: Piece, square: Square) -> u64 {
         return unsafe {
             (*self.pieces.get_unchecked(piece.0 as usize)).rotate_left(square.0 as u32)
         };
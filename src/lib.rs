@@ -0,0 +1,23 @@
+pub mod board;
+pub mod book;
+pub mod cache;
+pub mod castle;
+pub mod datagen;
+pub mod engine;
+pub mod hash;
+pub mod r#move;
+pub mod move_list;
+pub mod nnue;
+pub mod perft;
+pub mod pgn;
+pub mod piece;
+pub mod play;
+pub mod position;
+pub mod side;
+pub mod square;
+pub mod syzygy;
+pub mod tune;
+pub mod utils;
+
+pub use perft::{perft, perft_divide};
+pub use position::{EvalParams, Position, STARTING_POSITION_FEN};
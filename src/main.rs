@@ -1,24 +1,5 @@
-#![feature(test)]
-
-mod board;
-mod cache;
-mod castle;
-mod engine;
-mod hash;
-mod r#move;
-mod move_list;
-mod perft;
-mod piece;
-mod play;
-mod position;
-mod side;
-mod square;
-mod utils;
-
 extern crate clap;
 extern crate num_cpus;
-#[cfg(test)]
-extern crate test;
 extern crate threadpool;
 
 use clap::{Parser, Subcommand, ValueEnum};
@@ -27,19 +8,52 @@ use crossterm::{
     terminal::{disable_raw_mode, enable_raw_mode},
     ExecutableCommand,
 };
-use engine::engine_loop;
-pub use perft::perft;
-use play::Game;
-pub use position::{Position, STARTING_POSITION_FEN};
-use side::{Side, BLACK, WHITE};
-use std::{error::Error, fmt::Display, io::stdout, time::Instant};
+use mick::{
+    book::BookBuilder,
+    datagen::{self, DatagenConfig},
+    engine::engine_loop,
+    perft::{
+        debug::{bisect, ReferenceFile, UciReference},
+        stats::{perft_stats, PerftStats},
+        suite::{parse_suite, run_entry},
+    },
+    perft_divide,
+    play::Game,
+    side::{Side, BLACK, WHITE},
+    square::generate::{current_magics_size, find_magic, magics_source, pack_magics, Rng, Slider},
+    tune::Tuner,
+    EvalParams, Position, STARTING_POSITION_FEN,
+};
+use std::{
+    error::Error,
+    fmt::Display,
+    fs::File,
+    io::{stdout, BufReader, BufWriter},
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 #[derive(Subcommand)]
 enum Commands {
+    /// Build a Polyglot opening book from PGN files
+    Book(BookArgs),
+    /// Generate training data from self-play games
+    Datagen(DatagenArgs),
+    /// Search for new magic numbers for the slider attack tables
+    Magics(MagicsArgs),
+    /// Search for a forced mate
+    Mate(MateArgs),
     /// Run perft on the starting board position
     Perft(PerftArgs),
+    /// Find the position where perft differs from a reference
+    PerftDebug(PerftDebugArgs),
+    /// Check perft against the node counts of an EPD suite
+    PerftSuite(PerftSuiteArgs),
     /// Start the engine
-    Start,
+    Start(StartArgs),
+    /// Tune the evaluation weights with labelled positions
+    Tune(TuneArgs),
     /// Play a game against the engine
     Play {
         #[arg(long, default_value_t = SideEnum::White)]
@@ -47,6 +61,81 @@ enum Commands {
     },
 }
 
+#[derive(clap::Args)]
+struct BookArgs {
+    /// The PGN files containing the games
+    #[arg(required = true)]
+    pgn: Vec<PathBuf>,
+
+    /// Path of the resulting book file
+    #[arg(long, short, default_value = "book.bin")]
+    output: PathBuf,
+
+    /// Maximum number of plies per game that are added to the book
+    #[arg(long, default_value_t = 20)]
+    depth: usize,
+
+    /// Minimum number of games a move must have been played in
+    #[arg(long, default_value_t = 1)]
+    min_games: u32,
+
+    /// Only use games where both players have at least this rating
+    #[arg(long)]
+    min_rating: Option<u32>,
+}
+
+#[derive(clap::Args)]
+struct DatagenArgs {
+    /// Path of the resulting file with the training data
+    #[arg(long, short, default_value = "data.bin")]
+    output: PathBuf,
+
+    /// Number of games to play
+    #[arg(long, default_value_t = 1000)]
+    games: usize,
+
+    /// Search depth for each move, defaults to 4 unless a node limit is given
+    #[arg(long)]
+    depth: Option<u8>,
+
+    /// Maximum number of nodes to search for each move
+    #[arg(long)]
+    nodes: Option<u64>,
+
+    /// Number of random moves at the start of each game
+    #[arg(long, default_value_t = 8)]
+    random_plies: usize,
+
+    /// Number of games played concurrently, defaults to the number of CPUs
+    #[arg(long)]
+    threads: Option<usize>,
+
+    /// Seed for the random openings, which makes the output reproducible with a single thread
+    #[arg(long)]
+    seed: Option<u64>,
+}
+
+#[derive(clap::Args)]
+struct MagicsArgs {
+    /// Seed of the random magic numbers that are tried
+    #[arg(long, default_value_t = 1)]
+    seed: u64,
+}
+
+#[derive(clap::Args)]
+struct MateArgs {
+    /// The position to solve
+    fen: String,
+
+    /// Maximum number of moves until mate
+    #[arg(long, short)]
+    moves: u8,
+
+    /// Only consider checks for the attacking side
+    #[arg(long)]
+    checks_only: bool,
+}
+
 #[derive(clap::Args)]
 struct PerftArgs {
     #[arg(long)]
@@ -54,6 +143,104 @@ struct PerftArgs {
 
     #[arg(long)]
     fen: Option<String>,
+
+    /// Print the number of nodes below each move
+    #[arg(long)]
+    divide: bool,
+
+    /// Print the number of captures, checks, mates and other kinds of moves for each depth
+    #[arg(long, conflicts_with = "divide")]
+    stats: bool,
+
+    /// Only print the node counts, without timings
+    #[arg(long, conflicts_with = "json")]
+    quiet: bool,
+
+    /// Print the results as a JSON object
+    #[arg(long)]
+    json: bool,
+
+    /// Size in MB of the hash table shared by all threads, rounded down to a power of two, or 0 to
+    /// disable it
+    #[arg(long, default_value_t = 16)]
+    hash: usize,
+
+    /// Number of threads, defaults to the number of CPUs
+    #[arg(long)]
+    threads: Option<usize>,
+}
+
+#[derive(clap::Args)]
+struct PerftDebugArgs {
+    #[arg(long)]
+    depth: usize,
+
+    #[arg(long)]
+    fen: Option<String>,
+
+    /// File with the correct divide counts
+    #[arg(long, required_unless_present = "engine")]
+    reference: Option<PathBuf>,
+
+    /// UCI engine supporting `go perft` that provides the correct divide counts
+    #[arg(long, conflicts_with = "reference")]
+    engine: Option<PathBuf>,
+
+    /// Argument passed to the engine, can be repeated
+    #[arg(long, requires = "engine", allow_hyphen_values = true)]
+    engine_arg: Vec<String>,
+}
+
+#[derive(clap::Args)]
+struct PerftSuiteArgs {
+    /// EPD file with lines like `<FEN> ;D1 20 ;D2 400`
+    suite: PathBuf,
+
+    /// Depths above this are skipped
+    #[arg(long, default_value_t = 5)]
+    max_depth: usize,
+
+    /// Skip the remaining depths of a position after this many seconds
+    #[arg(long)]
+    time_limit: Option<f64>,
+
+    /// Number of threads, defaults to the number of CPUs
+    #[arg(long)]
+    threads: Option<usize>,
+}
+
+#[derive(clap::Args)]
+struct StartArgs {
+    /// TOML or JSON file with the weights of the evaluation
+    #[arg(long)]
+    params: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct TuneArgs {
+    /// EPD files with game results, or files written by the datagen command
+    #[arg(required = true)]
+    data: Vec<PathBuf>,
+
+    /// Path of the resulting tuned weights, written as a parameter file if the extension is
+    /// `toml` or `json` and as Rust source otherwise
+    #[arg(long, short, default_value = "tuned.rs")]
+    output: PathBuf,
+
+    /// TOML or JSON file with the weights to start from
+    #[arg(long)]
+    params: Option<PathBuf>,
+
+    /// Number of passes over all positions
+    #[arg(long, default_value_t = 1000)]
+    epochs: usize,
+
+    #[arg(long, default_value_t = 1.0)]
+    learning_rate: f64,
+
+    /// Number of threads used to compute the error, defaults to the number of CPUs
+    #[arg(long)]
+    threads: Option<usize>,
 }
 
 #[derive(Clone, ValueEnum)]
@@ -91,23 +278,288 @@ fn main() -> Result<(), Box<dyn Error>> {
     let cli = Cli::parse();
 
     match cli.command {
+        Some(Commands::Book(args)) => {
+            let mut builder = BookBuilder::new(args.depth, args.min_rating);
+            for path in args.pgn.iter() {
+                builder.add_pgn(BufReader::new(File::open(path)?))?;
+            }
+
+            let mut writer = BufWriter::new(File::create(&args.output)?);
+            let entry_count = builder.write(&mut writer, args.min_games)?;
+
+            println!(
+                "Added {} games ({} skipped)",
+                builder.games_added, builder.games_skipped
+            );
+            println!("Wrote {entry_count} entries to {}", args.output.display());
+        }
+        Some(Commands::Datagen(args)) => {
+            let config = DatagenConfig {
+                games: args.games,
+                depth: match (args.depth, args.nodes) {
+                    (Some(depth), _) => depth,
+                    (None, Some(_)) => u8::MAX,
+                    (None, None) => 4,
+                },
+                nodes: args.nodes,
+                random_plies: args.random_plies,
+                threads: args.threads.unwrap_or(num_cpus::get()),
+                seed: args.seed,
+            };
+
+            let mut writer = BufWriter::new(File::create(&args.output)?);
+            let (games, positions) = datagen::generate(&config, &mut writer)?;
+
+            println!(
+                "Wrote {positions} positions from {games} games to {}",
+                args.output.display()
+            );
+        }
+        Some(Commands::Magics(args)) => {
+            let mut rng = Rng::new(args.seed);
+            let diagonal =
+                std::array::from_fn(|square| find_magic(Slider::Diagonal, square as u8, &mut rng));
+            let straight =
+                std::array::from_fn(|square| find_magic(Slider::Straight, square as u8, &mut rng));
+            let (diagonal, straight, size) = pack_magics(&diagonal, &straight);
+
+            print!("{}", magics_source(&diagonal, &straight));
+            eprintln!(
+                "Shared attack table with {size} entries, the current one has {}",
+                current_magics_size()
+            );
+        }
+        Some(Commands::Mate(args)) => {
+            let mut position = Position::try_from_fen(&args.fen)?;
+
+            let now = Instant::now();
+            let result = position.solve_mate(args.moves, args.checks_only);
+            let elapsed = now.elapsed();
+
+            match (result.moves(), result.line) {
+                (Some(mate_in), Some(line)) => {
+                    println!("Mate in {mate_in}:");
+                    for (i, pair) in line.chunks(2).enumerate() {
+                        let moves = pair
+                            .iter()
+                            .map(|m| m.to_string())
+                            .collect::<Vec<String>>()
+                            .join(" ");
+                        println!("{:>3}. {moves}", i + 1);
+                    }
+                }
+                _ => println!("No mate in {}", args.moves),
+            }
+            println!("Nodes searched: {}", result.nodes);
+            println!("Time: {:.3} sec", elapsed.as_secs_f64());
+        }
         Some(Commands::Perft(args)) => {
             let fen = args.fen.unwrap_or(String::from(STARTING_POSITION_FEN));
-            let mut position = Position::from_fen(&fen);
+            let mut position = Position::try_from_fen(&fen)?;
+            let threads = args.threads.unwrap_or(num_cpus::get());
+
+            if args.stats {
+                let stats: Vec<PerftStats> = (1..=args.depth)
+                    .map(|depth| perft_stats(&mut position, depth, threads))
+                    .collect();
+
+                if args.json {
+                    let stats: Vec<serde_json::Value> = stats
+                        .iter()
+                        .zip(1..)
+                        .map(|(stats, depth)| {
+                            let mut value = serde_json::to_value(stats).unwrap();
+                            value["depth"] = depth.into();
+                            value
+                        })
+                        .collect();
+                    println!("{}", serde_json::json!({ "fen": fen, "stats": stats }));
+                } else {
+                    println!(
+                        "{:>5} {:>14} {:>12} {:>9} {:>10} {:>11} {:>11} {:>10} {:>7} {:>10}",
+                        "Depth",
+                        "Nodes",
+                        "Captures",
+                        "E.p.",
+                        "Castles",
+                        "Promotions",
+                        "Checks",
+                        "Discovered",
+                        "Double",
+                        "Checkmates"
+                    );
+                    for (stats, depth) in stats.iter().zip(1..) {
+                        println!(
+                            "{:>5} {:>14} {:>12} {:>9} {:>10} {:>11} {:>11} {:>10} {:>7} {:>10}",
+                            depth,
+                            stats.nodes,
+                            stats.captures,
+                            stats.en_passant,
+                            stats.castles,
+                            stats.promotions,
+                            stats.checks,
+                            stats.discovered_checks,
+                            stats.double_checks,
+                            stats.checkmates
+                        );
+                    }
+                }
+                return Ok(());
+            }
 
             let now = Instant::now();
-            let move_count = perft(&mut position, args.depth, true, 1024 * 1024 * 4);
+            let divide = perft_divide(&mut position, args.depth, threads, args.hash * 1024 * 1024);
             let elapsed = now.elapsed();
 
-            let sec =
-                (elapsed.as_secs() as f64) + (elapsed.subsec_nanos() as f64 / 1_000_000_000.0);
+            let move_count = if args.depth == 0 {
+                1
+            } else {
+                divide.iter().map(|(_, count)| count).sum()
+            };
+            let sec = elapsed.as_secs_f64();
             let nps = move_count as f64 / sec;
 
-            println!("\nNodes searched: {move_count}");
-            println!("Time: {sec:5} sec");
-            println!("NPS: {nps:0}");
+            if args.json {
+                let mut result = serde_json::json!({
+                    "fen": fen,
+                    "depth": args.depth,
+                    "nodes": move_count,
+                    "time": sec,
+                    "nps": nps as u64,
+                });
+                if args.divide {
+                    result["divide"] = divide
+                        .iter()
+                        .map(|(m, count)| (m.to_string(), serde_json::json!(count)))
+                        .collect::<serde_json::Map<String, serde_json::Value>>()
+                        .into();
+                }
+                println!("{result}");
+            } else {
+                if args.divide {
+                    for (m, count) in divide.iter() {
+                        println!("{m}: {count}");
+                    }
+                }
+
+                if args.quiet {
+                    println!("{move_count}");
+                } else {
+                    println!("\nNodes searched: {move_count}");
+                    println!("Time: {sec:5} sec");
+                    println!("NPS: {nps:0}");
+                }
+            }
+        }
+        Some(Commands::PerftDebug(args)) => {
+            let fen = args.fen.unwrap_or(String::from(STARTING_POSITION_FEN));
+            let position = Position::try_from_fen(&fen)?;
+
+            let mismatch = match (args.reference, args.engine) {
+                (Some(path), _) => {
+                    let mut reference = ReferenceFile::load(path, &fen, args.depth)?;
+                    bisect(&position, args.depth, &mut reference)?
+                }
+                (None, Some(path)) => {
+                    let mut reference = UciReference::start(path, &args.engine_arg)?;
+                    bisect(&position, args.depth, &mut reference)?
+                }
+                (None, None) => unreachable!(),
+            };
+
+            match mismatch {
+                Some(mismatch) => {
+                    let moves = mismatch
+                        .moves
+                        .iter()
+                        .map(|m| m.to_string())
+                        .collect::<Vec<String>>()
+                        .join(" ");
+                    println!("Moves: {moves}");
+                    println!("FEN: {}", mismatch.fen);
+                    println!("Depth: {}", mismatch.depth);
+                    println!("{}", mismatch.difference);
+                }
+                None => println!("No differences found"),
+            }
+        }
+        Some(Commands::PerftSuite(args)) => {
+            let suite = parse_suite(&std::fs::read_to_string(&args.suite)?)?;
+            let time_limit = args.time_limit.map(Duration::from_secs_f64);
+            let threads = args.threads.unwrap_or(num_cpus::get());
+
+            let mut failed = 0;
+            let mut skipped = 0;
+            for entry in suite.iter() {
+                let result = run_entry(entry, args.max_depth, time_limit, threads);
+                let status = if !result.ran() {
+                    "SKIP"
+                } else if result.passed() {
+                    "PASS"
+                } else {
+                    "FAIL"
+                };
+                println!(
+                    "{status} {} ({} depths, {} skipped, {:.3} sec)",
+                    result.fen,
+                    result.results.len(),
+                    result.skipped,
+                    result.elapsed.as_secs_f64()
+                );
+
+                for depth in result.results.iter().filter(|depth| !depth.passed()) {
+                    println!(
+                        "     D{}: expected {}, got {}",
+                        depth.depth, depth.expected, depth.actual
+                    );
+                }
+                skipped += !result.ran() as usize;
+                failed += (result.ran() && !result.passed()) as usize;
+            }
+
+            println!(
+                "Passed {} of {} positions ({skipped} skipped)",
+                suite.len() - failed - skipped,
+                suite.len()
+            );
+            if failed > 0 {
+                return Err(format!("{failed} positions failed").into());
+            }
+        }
+        Some(Commands::Start(args)) => {
+            let params = match args.params {
+                Some(path) => Arc::new(EvalParams::load(path)?),
+                None => EvalParams::shared_default(),
+            };
+            engine_loop(params)?
+        }
+        Some(Commands::Tune(args)) => {
+            let params = match args.params {
+                Some(path) => Arc::new(EvalParams::load(path)?),
+                None => EvalParams::shared_default(),
+            };
+            let mut tuner = Tuner::new(params, args.threads.unwrap_or(num_cpus::get()));
+            for path in args.data.iter() {
+                tuner.load(path)?;
+            }
+
+            tuner.optimize_k();
+            println!("Loaded {} positions, K = {:.4}", tuner.len(), tuner.k);
+            println!("Initial error: {:.6}", tuner.error(tuner.k));
+
+            for epoch in 1..=args.epochs {
+                tuner.step(args.learning_rate);
+                if epoch % 50 == 0 || epoch == args.epochs {
+                    println!("Epoch {epoch}: error {:.6}", tuner.error(tuner.k));
+                }
+            }
+
+            match args.output.extension().and_then(|ext| ext.to_str()) {
+                Some("toml" | "json") => tuner.to_params().save(&args.output)?,
+                _ => std::fs::write(&args.output, tuner.to_rust_source())?,
+            }
+            println!("Wrote tuned weights to {}", args.output.display());
         }
-        Some(Commands::Start) => engine_loop()?,
         Some(Commands::Play { side }) => {
             let mut stdout = stdout();
 
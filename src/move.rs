@@ -72,7 +72,8 @@ impl Move {
     }
 
     pub fn is_en_passant_capture(self) -> bool {
-        self.0 & 0b01_000000 == 0b01_000000 && self.1 & 0b01_000000 == 0b01_000000
+        // Capture promotions to a rook also have the second bit set
+        self.0 & 0b11_000000 == 0b01_000000 && self.1 & 0b01_000000 == 0b01_000000
     }
 
     pub fn new_capture(from: Square, to: Square) -> Move {
@@ -179,6 +180,85 @@ impl Move {
         // If none of the above was true, the move is just a regular push
         Ok(Move::new_push(from, to))
     }
+
+    /// Parses a move in standard algebraic notation (SAN) by matching it against the legal moves
+    /// in the given position
+    pub fn try_from_san(s: &str, position: &Position) -> Result<Move, String> {
+        let san = s.trim_end_matches(['+', '#', '!', '?']);
+        let (legal_moves, _) = position.legal_moves_vec();
+
+        // Castles
+        let castle = match san {
+            "O-O" | "0-0" => Some(KING_SIDE),
+            "O-O-O" | "0-0-0" => Some(QUEEN_SIDE),
+            _ => None,
+        };
+        if let Some(castle) = castle {
+            return legal_moves
+                .iter()
+                .find(|m| m.castle() == Some(castle))
+                .copied()
+                .ok_or(format!("Illegal castle: {}", s));
+        }
+
+        let mut chars: Vec<char> = san.chars().collect();
+
+        let kind = match chars.first() {
+            Some(&c) if c.is_ascii_uppercase() => {
+                chars.remove(0);
+                PieceKind::try_from_char(c)?
+            }
+            _ => PAWN,
+        };
+
+        // Promotions are usually written as "e8=Q", but "e8Q" is common as well
+        let mut promote_to = None;
+        if let Some(index) = chars.iter().position(|&c| c == '=') {
+            let c = chars
+                .get(index + 1)
+                .ok_or(format!("Missing promotion piece: {}", s))?;
+            promote_to = Some(PieceKind::try_from_char(*c)?);
+            chars.truncate(index);
+        } else if kind == PAWN && chars.last().is_some_and(|c| c.is_ascii_alphabetic()) {
+            promote_to = Some(PieceKind::try_from_char(chars.pop().unwrap())?);
+        }
+
+        if chars.len() < 2 {
+            return Err(format!("Invalid move: {}", s));
+        }
+
+        let to_str: String = chars.split_off(chars.len() - 2).into_iter().collect();
+        let to = match Square::try_from_str(&to_str)? {
+            Some(s) => s,
+            None => return Err(String::from("Missing to square")),
+        };
+
+        // Everything that remains is either a capture marker or disambiguates the origin square
+        let mut from_file = None;
+        let mut from_rank = None;
+        for c in chars.into_iter().filter(|&c| c != 'x' && c != '-') {
+            match c {
+                'a'..='h' => from_file = Some(c as u8 - b'a'),
+                '1'..='8' => from_rank = Some(c as u8 - b'1'),
+                _ => return Err(format!("Invalid move: {}", s)),
+            }
+        }
+
+        let mut candidates = legal_moves.iter().filter(|m| {
+            m.castle().is_none()
+                && m.to() == to
+                && m.promote_to() == promote_to
+                && position.at(m.from()).kind() == kind
+                && from_file.is_none_or(|file| m.from().file_index() == file)
+                && from_rank.is_none_or(|rank| m.from().rank_index() == rank)
+        });
+
+        match (candidates.next(), candidates.next()) {
+            (Some(m), None) => Ok(*m),
+            (Some(_), Some(_)) => Err(format!("Ambiguous move: {}", s)),
+            _ => Err(format!("Illegal move: {}", s)),
+        }
+    }
 }
 
 impl Display for Move {
@@ -202,6 +282,7 @@ mod tests {
         piece::{BISHOP, KNIGHT, QUEEN, ROOK},
         r#move::Move,
         square::Square,
+        Position, STARTING_POSITION_FEN,
     };
 
     #[test]
@@ -291,4 +372,81 @@ mod tests {
             String::from("e1c1")
         );
     }
+
+    #[test]
+    fn flags() {
+        assert!(Move::new_capture_en_passant(Square(32), Square(41)).is_en_passant_capture());
+        assert!(!Move::new_capture(Square(32), Square(41)).is_en_passant_capture());
+        for kind in [QUEEN, ROOK, BISHOP, KNIGHT] {
+            let m = Move::new_capture_promotion(Square(48), Square(57), kind);
+            assert!(m.is_capture());
+            assert!(!m.is_en_passant_capture());
+            assert_eq!(m.promote_to(), Some(kind));
+        }
+    }
+
+    #[test]
+    fn from_san() {
+        let position = Position::from_fen(STARTING_POSITION_FEN);
+        assert_eq!(
+            Move::try_from_san("e4", &position),
+            Ok(Move::new_push_double_pawn(Square(12), Square(28)))
+        );
+        assert_eq!(
+            Move::try_from_san("Nf3", &position),
+            Ok(Move::new_push(Square(6), Square(21)))
+        );
+
+        // Castles, annotations and check markers
+        let position = Position::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1");
+        assert_eq!(
+            Move::try_from_san("O-O+", &position),
+            Ok(Move::new_castle(Square(4), Square(6), KING_SIDE))
+        );
+        assert_eq!(
+            Move::try_from_san("O-O-O!?", &position),
+            Ok(Move::new_castle(Square(4), Square(2), QUEEN_SIDE))
+        );
+
+        // Disambiguation by file and by rank
+        assert_eq!(
+            Move::try_from_san("Rab1", &position),
+            Ok(Move::new_push(Square(0), Square(1)))
+        );
+        let position = Position::from_fen("k7/8/8/8/R7/8/R7/K7 w - - 0 1");
+        assert_eq!(
+            Move::try_from_san("R4a3", &position),
+            Ok(Move::new_push(Square(24), Square(16)))
+        );
+
+        // Pawn captures and promotions
+        let position = Position::from_fen("1n5k/P7/8/8/8/8/8/K7 w - - 0 1");
+        assert_eq!(
+            Move::try_from_san("axb8=Q#", &position),
+            Ok(Move::new_capture_promotion(Square(48), Square(57), QUEEN))
+        );
+        assert_eq!(
+            Move::try_from_san("a8N", &position),
+            Ok(Move::new_push_promotion(Square(48), Square(56), KNIGHT))
+        );
+    }
+
+    #[test]
+    fn from_invalid_san() {
+        let position = Position::from_fen(STARTING_POSITION_FEN);
+        assert_eq!(
+            Move::try_from_san("e5", &position),
+            Err(String::from("Illegal move: e5"))
+        );
+        assert_eq!(
+            Move::try_from_san("O-O", &position),
+            Err(String::from("Illegal castle: O-O"))
+        );
+
+        let position = Position::from_fen("k7/8/8/8/R7/8/R7/K7 w - - 0 1");
+        assert_eq!(
+            Move::try_from_san("Ra3", &position),
+            Err(String::from("Ambiguous move: Ra3"))
+        );
+    }
 }
@@ -5,7 +5,7 @@ use crate::{
     square::Square,
 };
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct MoveCounter {
     pub moves: u64,
     pub captures: u64,
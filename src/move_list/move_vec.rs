@@ -19,6 +19,12 @@ pub struct MoveVec {
     moves: Vec<Move>,
 }
 
+impl Default for MoveVec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Display for MoveVec {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -94,6 +100,10 @@ impl MoveVec {
         self.moves.iter()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty()
+    }
+
     pub fn len(&self) -> usize {
         self.moves.len()
     }
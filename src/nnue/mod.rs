@@ -0,0 +1,356 @@
+mod simd;
+
+use crate::{
+    piece::{Piece, KING},
+    side::{Side, BLACK, WHITE},
+    square::Square,
+};
+use std::{fmt::Debug, fs, path::Path, sync::Arc};
+
+/// Number of neurons in the hidden layer for each perspective
+pub const HIDDEN: usize = 256;
+
+/// One feature for each square of the own king, for each of the ten non-king pieces on each square
+const INPUTS: usize = 64 * 10 * 64;
+
+/// Activations of the hidden layer are clipped to `0..=QA`
+const QA: i32 = 255;
+/// Quantization of the output weights
+const QB: i32 = 64;
+/// Converts the network output to centipawns
+const SCALE: i32 = 400;
+
+const MAGIC: &[u8; 8] = b"MICKNNUE";
+
+/// A HalfKP network with a single hidden layer, where each side has its own accumulator with the
+/// features relative to its king
+///
+/// Networks are stored as little endian integers in the following order:
+/// - the magic bytes `MICKNNUE` and the size of the hidden layer as `u32`
+/// - feature transformer weights as `i16`, `HIDDEN` for each of the `INPUTS` features
+/// - feature transformer biases as `i16`
+/// - output weights as `i16`, first for the side to move and then for the other side
+/// - the output bias as `i32`
+pub struct Network {
+    feature_weights: Vec<[i16; HIDDEN]>,
+    feature_biases: [i16; HIDDEN],
+    output_weights: [[i16; HIDDEN]; 2],
+    output_bias: i32,
+}
+
+impl Debug for Network {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Network {{ hidden: {HIDDEN} }}")
+    }
+}
+
+impl Network {
+    pub fn load(path: impl AsRef<Path>) -> Result<Network, String> {
+        let path = path.as_ref();
+        let bytes = fs::read(path)
+            .map_err(|err| format!("Failed to read network {}: {}", path.display(), err))?;
+        Network::from_bytes(&bytes)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Network, String> {
+        let expected = 12 + (INPUTS * HIDDEN + HIDDEN + 2 * HIDDEN) * 2 + 4;
+        if bytes.len() != expected || &bytes[0..8] != MAGIC {
+            return Err("Invalid network file".to_string());
+        }
+
+        let hidden = u32::from_le_bytes(bytes[8..12].try_into().unwrap()) as usize;
+        if hidden != HIDDEN {
+            return Err(format!(
+                "Network has {hidden} hidden neurons, expected {HIDDEN}"
+            ));
+        }
+
+        let mut values = bytes[12..]
+            .chunks_exact(2)
+            .map(|b| i16::from_le_bytes([b[0], b[1]]));
+        let mut read = |target: &mut [i16; HIDDEN]| {
+            for (value, byte) in target.iter_mut().zip(&mut values) {
+                *value = byte;
+            }
+        };
+
+        let mut network = Network {
+            feature_weights: vec![[0; HIDDEN]; INPUTS],
+            feature_biases: [0; HIDDEN],
+            output_weights: [[0; HIDDEN]; 2],
+            output_bias: 0,
+        };
+        for weights in network.feature_weights.iter_mut() {
+            read(weights);
+        }
+        read(&mut network.feature_biases);
+        read(&mut network.output_weights[0]);
+        read(&mut network.output_weights[1]);
+        network.output_bias = i32::from_le_bytes(bytes[bytes.len() - 4..].try_into().unwrap());
+
+        Ok(network)
+    }
+
+    #[cfg(test)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend((HIDDEN as u32).to_le_bytes());
+        for weights in self
+            .feature_weights
+            .iter()
+            .chain([&self.feature_biases])
+            .chain(self.output_weights.iter())
+        {
+            for weight in weights {
+                bytes.extend(weight.to_le_bytes());
+            }
+        }
+        bytes.extend(self.output_bias.to_le_bytes());
+        bytes
+    }
+}
+
+/// Index of a piece on a square as seen from the given side with its king on `king`. Black's
+/// perspective is mirrored vertically so that both sides see their own pieces at the bottom.
+fn feature(perspective: Side, king: Square, pc: Piece, sq: Square) -> usize {
+    let flip = if perspective == WHITE { 0 } else { 56 };
+    let piece_index = ((pc.kind().0 as usize - 1) << 1) | (pc.side() != perspective) as usize;
+    ((king.0 ^ flip) as usize * 10 + piece_index) * 64 + (sq.0 ^ flip) as usize
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Accumulator {
+    values: [[i16; HIDDEN]; 2],
+    kings: [Square; 2],
+    /// Set when the king of a side moved, so all features of its perspective need to be
+    /// recomputed
+    needs_refresh: [bool; 2],
+}
+
+/// Hidden layer values of the network for the current position, with one accumulator for each
+/// move that was made so that unmaking a move only needs to drop the last one
+#[derive(Clone, Debug)]
+pub struct NnueState {
+    network: Arc<Network>,
+    stack: Vec<Accumulator>,
+}
+
+impl PartialEq for NnueState {
+    fn eq(&self, other: &NnueState) -> bool {
+        Arc::ptr_eq(&self.network, &other.network) && self.stack == other.stack
+    }
+}
+
+impl NnueState {
+    pub fn new(network: Arc<Network>, pieces: &[Piece; 64]) -> NnueState {
+        let king_square = |side: Side| {
+            let king = KING.to_piece(side);
+            Square(pieces.iter().position(|&pc| pc == king).unwrap_or(0) as u8)
+        };
+
+        let mut state = NnueState {
+            network,
+            stack: Vec::with_capacity(128),
+        };
+        state.stack.push(Accumulator {
+            values: [[0; HIDDEN]; 2],
+            kings: [king_square(WHITE), king_square(BLACK)],
+            needs_refresh: [true; 2],
+        });
+        state.refresh(pieces);
+        state
+    }
+
+    /// Starts a new accumulator for the position after a move
+    pub fn push(&mut self) {
+        let top = self.stack.last().unwrap().clone();
+        self.stack.push(top);
+    }
+
+    /// Returns to the accumulator of the position before the last move
+    pub fn pop(&mut self) {
+        self.stack.pop();
+    }
+
+    pub fn add(&mut self, pc: Piece, sq: Square) {
+        let accumulator = self.stack.last_mut().unwrap();
+        if pc.kind() == KING {
+            // The king is not a feature itself, but all features of its side depend on it
+            accumulator.kings[pc.side().0 as usize] = sq;
+            accumulator.needs_refresh[pc.side().0 as usize] = true;
+            return;
+        }
+
+        for perspective in [WHITE, BLACK] {
+            let p = perspective.0 as usize;
+            if !accumulator.needs_refresh[p] {
+                let idx = feature(perspective, accumulator.kings[p], pc, sq);
+                simd::add(
+                    &mut accumulator.values[p],
+                    &self.network.feature_weights[idx],
+                );
+            }
+        }
+    }
+
+    pub fn remove(&mut self, pc: Piece, sq: Square) {
+        let accumulator = self.stack.last_mut().unwrap();
+        if pc.kind() == KING {
+            accumulator.needs_refresh[pc.side().0 as usize] = true;
+            return;
+        }
+
+        for perspective in [WHITE, BLACK] {
+            let p = perspective.0 as usize;
+            if !accumulator.needs_refresh[p] {
+                let idx = feature(perspective, accumulator.kings[p], pc, sq);
+                simd::sub(
+                    &mut accumulator.values[p],
+                    &self.network.feature_weights[idx],
+                );
+            }
+        }
+    }
+
+    /// Recomputes the perspectives of the last accumulator whose king moved
+    pub fn refresh(&mut self, pieces: &[Piece; 64]) {
+        let accumulator = self.stack.last_mut().unwrap();
+        for perspective in [WHITE, BLACK] {
+            let p = perspective.0 as usize;
+            if !accumulator.needs_refresh[p] {
+                continue;
+            }
+
+            let values = &mut accumulator.values[p];
+            *values = self.network.feature_biases;
+            for (idx, &pc) in pieces.iter().enumerate() {
+                if pc.is_some() && pc.kind() != KING {
+                    let sq = Square(idx as u8);
+                    let idx = feature(perspective, accumulator.kings[p], pc, sq);
+                    simd::add(values, &self.network.feature_weights[idx]);
+                }
+            }
+
+            accumulator.needs_refresh[p] = false;
+        }
+    }
+
+    /// Runs the output layer and returns the score relative to the side to move
+    pub fn evaluate(&self, side_to_move: Side) -> i32 {
+        let accumulator = self.stack.last().unwrap();
+        let us = &accumulator.values[side_to_move.0 as usize];
+        let them = &accumulator.values[1 - side_to_move.0 as usize];
+
+        // Each dot product fits into an `i32`, but their sum and the scaling might not
+        let sum = simd::clipped_dot(us, &self.network.output_weights[0]) as i64
+            + simd::clipped_dot(them, &self.network.output_weights[1]) as i64;
+
+        ((sum + self.network.output_bias as i64) * SCALE as i64 / (QA * QB) as i64) as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        nnue::{Network, HIDDEN, INPUTS, QA, QB, SCALE},
+        Position,
+    };
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use std::sync::{Arc, OnceLock};
+
+    /// A network with random weights, which is good enough to check that the evaluation is
+    /// consistent
+    fn random_network() -> Arc<Network> {
+        static NETWORK: OnceLock<Arc<Network>> = OnceLock::new();
+        NETWORK
+            .get_or_init(|| {
+                let mut rng = StdRng::seed_from_u64(1);
+                let mut random = |range: i16| {
+                    let mut values = [0; HIDDEN];
+                    for value in values.iter_mut() {
+                        *value = rng.gen_range(-range..=range);
+                    }
+                    values
+                };
+                Arc::new(Network {
+                    feature_weights: (0..INPUTS).map(|_| random(32)).collect(),
+                    feature_biases: random(32),
+                    output_weights: [random(64), random(64)],
+                    output_bias: 1000,
+                })
+            })
+            .clone()
+    }
+
+    #[test]
+    fn serialization() {
+        let network = random_network();
+        let loaded = Network::from_bytes(&network.to_bytes()).unwrap();
+        assert_eq!(loaded.feature_weights, network.feature_weights);
+        assert_eq!(loaded.output_weights, network.output_weights);
+        assert_eq!(loaded.output_bias, network.output_bias);
+
+        assert!(Network::from_bytes(&[0; 16]).is_err());
+    }
+
+    #[test]
+    fn incremental_updates() {
+        let network = random_network();
+        let mut p = Position::from_fen(
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        );
+        p.set_network(Some(network.clone()));
+        let original = p.clone();
+
+        for m in p.legal_moves_vec().0.iter() {
+            let state = p.state().clone();
+            let hash = p.hash();
+            let capture = p.make(*m);
+
+            for m2 in p.legal_moves_vec().0.iter() {
+                let state2 = p.state().clone();
+                let hash2 = p.hash();
+                let capture2 = p.make(*m2);
+
+                let mut refreshed = p.clone();
+                refreshed.set_network(Some(network.clone()));
+                assert_eq!(p.nnue_evaluate(), refreshed.nnue_evaluate(), "{m} {m2}");
+
+                p.unmake(*m2, capture2, &state2, hash2);
+            }
+
+            p.unmake(*m, capture, &state, hash);
+        }
+
+        assert_eq!(p, original);
+    }
+
+    #[test]
+    fn mirrored() {
+        // Both sides see the position from their own point of view
+        let network = random_network();
+        let mut white = Position::from_fen("4k3/pp3ppp/2n5/8/3P4/2N5/PP3PPP/4K3 w - - 0 1");
+        let mut black = Position::from_fen("4k3/pp3ppp/2n5/3p4/8/2N5/PP3PPP/4K3 b - - 0 1");
+        white.set_network(Some(network.clone()));
+        black.set_network(Some(network));
+        assert_eq!(white.nnue_evaluate(), black.nnue_evaluate());
+    }
+
+    #[test]
+    fn largest_weights() {
+        // Every activation is clipped to the maximum, and the output sums up to more than `i32`
+        let network = Network {
+            feature_weights: vec![[0; HIDDEN]; INPUTS],
+            feature_biases: [i16::MAX; HIDDEN],
+            output_weights: [[i16::MAX; HIDDEN]; 2],
+            output_bias: i32::MAX,
+        };
+        let mut position = Position::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 1");
+        position.set_network(Some(Arc::new(network)));
+        let sum = 2 * HIDDEN as i64 * QA as i64 * i16::MAX as i64 + i32::MAX as i64;
+        assert_eq!(
+            position.nnue_evaluate(),
+            Some((sum * SCALE as i64 / (QA * QB) as i64) as i32)
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use super::{HIDDEN, QA};
+#[cfg(target_arch = "x86_64")]
+use std::sync::OnceLock;
+
+// Adding and subtracting features is simple enough for the compiler to vectorize on its own
+
+pub fn add(values: &mut [i16; HIDDEN], weights: &[i16; HIDDEN]) {
+    for (value, &weight) in values.iter_mut().zip(weights.iter()) {
+        *value = value.wrapping_add(weight);
+    }
+}
+
+pub fn sub(values: &mut [i16; HIDDEN], weights: &[i16; HIDDEN]) {
+    for (value, &weight) in values.iter_mut().zip(weights.iter()) {
+        *value = value.wrapping_sub(weight);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+type ClippedDot = fn(&[i16; HIDDEN], &[i16; HIDDEN]) -> i32;
+
+/// Clips the values to `0..=QA` and returns the dot product with the weights. The sum wraps
+/// around on overflow, like the additions of the SIMD versions.
+#[cfg(target_arch = "x86_64")]
+#[inline]
+pub fn clipped_dot(values: &[i16; HIDDEN], weights: &[i16; HIDDEN]) -> i32 {
+    // The CPU features are detected the first time and then only the chosen version is called
+    static CLIPPED_DOT: OnceLock<ClippedDot> = OnceLock::new();
+    let clipped_dot = CLIPPED_DOT.get_or_init(|| {
+        if is_x86_feature_detected!("avx2") {
+            |values, weights| unsafe { clipped_dot_avx2(values, weights) }
+        } else {
+            // SSE2 is part of every x86_64 CPU
+            |values, weights| unsafe { clipped_dot_sse2(values, weights) }
+        }
+    });
+    clipped_dot(values, weights)
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+pub fn clipped_dot(values: &[i16; HIDDEN], weights: &[i16; HIDDEN]) -> i32 {
+    clipped_dot_scalar(values, weights)
+}
+
+#[cfg_attr(target_arch = "x86_64", allow(dead_code))]
+fn clipped_dot_scalar(values: &[i16; HIDDEN], weights: &[i16; HIDDEN]) -> i32 {
+    values
+        .iter()
+        .zip(weights.iter())
+        .fold(0i32, |sum, (&value, &weight)| {
+            sum.wrapping_add((value as i32).clamp(0, QA) * weight as i32)
+        })
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn clipped_dot_avx2(values: &[i16; HIDDEN], weights: &[i16; HIDDEN]) -> i32 {
+    use std::arch::x86_64::*;
+
+    let zero = _mm256_setzero_si256();
+    let max = _mm256_set1_epi16(QA as i16);
+    let mut sum = _mm256_setzero_si256();
+
+    for i in (0..HIDDEN).step_by(16) {
+        let v = _mm256_loadu_si256(values.as_ptr().add(i) as *const __m256i);
+        let w = _mm256_loadu_si256(weights.as_ptr().add(i) as *const __m256i);
+        let clipped = _mm256_min_epi16(_mm256_max_epi16(v, zero), max);
+        sum = _mm256_add_epi32(sum, _mm256_madd_epi16(clipped, w));
+    }
+
+    let sum = _mm_add_epi32(
+        _mm256_castsi256_si128(sum),
+        _mm256_extracti128_si256(sum, 1),
+    );
+    horizontal_sum_sse2(sum)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse2")]
+unsafe fn clipped_dot_sse2(values: &[i16; HIDDEN], weights: &[i16; HIDDEN]) -> i32 {
+    use std::arch::x86_64::*;
+
+    let zero = _mm_setzero_si128();
+    let max = _mm_set1_epi16(QA as i16);
+    let mut sum = _mm_setzero_si128();
+
+    for i in (0..HIDDEN).step_by(8) {
+        let v = _mm_loadu_si128(values.as_ptr().add(i) as *const __m128i);
+        let w = _mm_loadu_si128(weights.as_ptr().add(i) as *const __m128i);
+        let clipped = _mm_min_epi16(_mm_max_epi16(v, zero), max);
+        sum = _mm_add_epi32(sum, _mm_madd_epi16(clipped, w));
+    }
+
+    horizontal_sum_sse2(sum)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse2")]
+unsafe fn horizontal_sum_sse2(sum: std::arch::x86_64::__m128i) -> i32 {
+    use std::arch::x86_64::*;
+
+    let sum = _mm_add_epi32(sum, _mm_shuffle_epi32(sum, 0b01_00_11_10));
+    let sum = _mm_add_epi32(sum, _mm_shuffle_epi32(sum, 0b10_11_00_01));
+    _mm_cvtsi128_si32(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{clipped_dot, clipped_dot_scalar};
+    use crate::nnue::HIDDEN;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn matches_scalar() {
+        let mut rng = StdRng::seed_from_u64(2);
+        for round in 0..100 {
+            let mut values = [0; HIDDEN];
+            let mut weights = [0; HIDDEN];
+            for i in 0..HIDDEN {
+                values[i] = rng.gen_range(-500..500);
+                weights[i] = rng.gen_range(-128..128);
+            }
+            // The largest weights get close to the limits of `i32`
+            if round == 0 {
+                values = [i16::MAX; HIDDEN];
+                weights = [i16::MIN; HIDDEN];
+            }
+
+            let expected = clipped_dot_scalar(&values, &weights);
+            assert_eq!(clipped_dot(&values, &weights), expected);
+
+            #[cfg(target_arch = "x86_64")]
+            unsafe {
+                assert_eq!(super::clipped_dot_sse2(&values, &weights), expected);
+                if is_x86_feature_detected!("avx2") {
+                    assert_eq!(super::clipped_dot_avx2(&values, &weights), expected);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,342 @@
+use crate::{perft::perft_divide, r#move::Move, Position};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    io::{BufRead, BufReader, Write},
+    path::Path,
+    process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+};
+
+/// Provides the correct divide counts to compare against
+pub trait Reference {
+    /// Returns the number of nodes at the depth below each legal move in the notation of the
+    /// moves, or `None` if the reference doesn't know the position
+    fn divide(&mut self, fen: &str, depth: usize) -> Result<Option<Vec<(String, u64)>>, String>;
+}
+
+/// Divide counts read from a file. Each line contains a move and its count separated by a colon,
+/// as printed by `perft --divide` or the `go perft` command of most engines. The counts belong to
+/// the position that is debugged, unless they follow a line of the form `fen <FEN> depth <N>`,
+/// which allows the file to contain deeper positions as well.
+pub struct ReferenceFile {
+    sections: HashMap<(String, usize), Vec<(String, u64)>>,
+}
+
+impl ReferenceFile {
+    pub fn load(path: impl AsRef<Path>, fen: &str, depth: usize) -> Result<ReferenceFile, String> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read {}: {}", path.display(), err))?;
+        ReferenceFile::parse(&contents, fen, depth)
+    }
+
+    pub fn parse(contents: &str, fen: &str, depth: usize) -> Result<ReferenceFile, String> {
+        let mut sections = HashMap::new();
+        let mut key = (position_key(fen), depth);
+
+        for line in contents.lines() {
+            let line = line.trim();
+            if let Some(header) = line.strip_prefix("fen ") {
+                let (fen, depth) = header
+                    .rsplit_once(" depth ")
+                    .ok_or_else(|| format!("Missing depth in line \"{line}\""))?;
+                let depth = depth
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("Invalid depth in line \"{line}\""))?;
+                key = (position_key(fen), depth);
+            } else if let Some(entry) = parse_divide_line(line) {
+                sections
+                    .entry(key.clone())
+                    .or_insert_with(Vec::new)
+                    .push(entry);
+            }
+        }
+
+        Ok(ReferenceFile { sections })
+    }
+}
+
+impl Reference for ReferenceFile {
+    fn divide(&mut self, fen: &str, depth: usize) -> Result<Option<Vec<(String, u64)>>, String> {
+        Ok(self.sections.get(&(position_key(fen), depth)).cloned())
+    }
+}
+
+/// A UCI engine that supports the `go perft` command, which runs as a child process
+pub struct UciReference {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+impl UciReference {
+    pub fn start(path: impl AsRef<Path>, args: &[String]) -> Result<UciReference, String> {
+        let path = path.as_ref();
+        let mut child = Command::new(path)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|err| format!("Failed to start {}: {}", path.display(), err))?;
+
+        let mut engine = UciReference {
+            stdin: child.stdin.take().unwrap(),
+            stdout: BufReader::new(child.stdout.take().unwrap()),
+            child,
+        };
+        engine.send("uci")?;
+        while engine.read_line()? != "uciok" {}
+
+        Ok(engine)
+    }
+
+    fn send(&mut self, command: &str) -> Result<(), String> {
+        writeln!(self.stdin, "{command}")
+            .and_then(|_| self.stdin.flush())
+            .map_err(|err| format!("Failed to write to engine: {err}"))
+    }
+
+    fn read_line(&mut self) -> Result<String, String> {
+        let mut line = String::new();
+        match self.stdout.read_line(&mut line) {
+            Ok(0) => Err("Engine exited unexpectedly".to_string()),
+            Ok(_) => Ok(line.trim().to_string()),
+            Err(err) => Err(format!("Failed to read from engine: {err}")),
+        }
+    }
+}
+
+impl Reference for UciReference {
+    fn divide(&mut self, fen: &str, depth: usize) -> Result<Option<Vec<(String, u64)>>, String> {
+        self.send(&format!("position fen {fen}"))?;
+        self.send(&format!("go perft {depth}"))?;
+
+        let mut divide = vec![];
+        loop {
+            let line = self.read_line()?;
+            if line.starts_with("Nodes searched") {
+                return Ok(Some(divide));
+            }
+            divide.extend(parse_divide_line(&line));
+        }
+    }
+}
+
+impl Drop for UciReference {
+    fn drop(&mut self) {
+        let _ = self.send("quit");
+        let _ = self.child.wait();
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Difference {
+    /// The reference has a move that isn't generated
+    Missing(String),
+    /// A move is generated that the reference doesn't have
+    Illegal(Move),
+    /// The node count below a move differs, but the reference doesn't know the position after it
+    Count { m: Move, ours: u64, theirs: u64 },
+}
+
+impl Display for Difference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing(m) => write!(f, "Missing move {m}"),
+            Self::Illegal(m) => write!(f, "Illegal move {m}"),
+            Self::Count { m, ours, theirs } => {
+                write!(f, "Node count below {m} is {ours} instead of {theirs}")
+            }
+        }
+    }
+}
+
+/// The position closest to the bug, along with the moves that lead to it
+#[derive(Debug, PartialEq)]
+pub struct Mismatch {
+    pub moves: Vec<Move>,
+    pub fen: String,
+    pub depth: usize,
+    pub difference: Difference,
+}
+
+/// Compares the divide counts against the reference and descends into the first move whose count
+/// differs, until it finds the position where a move is missing or illegal. Returns `None` if
+/// there are no differences.
+pub fn bisect(
+    position: &Position,
+    mut depth: usize,
+    reference: &mut impl Reference,
+) -> Result<Option<Mismatch>, String> {
+    let mut position = position.clone();
+    let mut moves = vec![];
+
+    let fen = position.to_fen();
+    let mut theirs = reference
+        .divide(&fen, depth)?
+        .ok_or_else(|| format!("The reference doesn't know the position {fen}"))?;
+
+    loop {
+        let fen = position.to_fen();
+        let ours = perft_divide(&mut position, depth, num_cpus::get(), 1024 * 1024 * 4);
+
+        let mismatch = |difference| {
+            Ok(Some(Mismatch {
+                moves: moves.clone(),
+                fen: fen.clone(),
+                depth,
+                difference,
+            }))
+        };
+
+        for (m, _) in theirs.iter() {
+            if !ours.iter().any(|(ours, _)| ours.to_string() == *m) {
+                return mismatch(Difference::Missing(m.clone()));
+            }
+        }
+
+        let mut differing = None;
+        for &(m, count) in ours.iter() {
+            match theirs.iter().find(|(theirs, _)| *theirs == m.to_string()) {
+                None => return mismatch(Difference::Illegal(m)),
+                Some(&(_, theirs)) if theirs != count && differing.is_none() => {
+                    differing = Some((m, count, theirs));
+                }
+                _ => {}
+            }
+        }
+
+        let Some((m, our_count, their_count)) = differing else {
+            return Ok(None);
+        };
+
+        let mut next = position.clone();
+        next.make(m);
+        let next_theirs = match depth {
+            1 => None,
+            _ => reference.divide(&next.to_fen(), depth - 1)?,
+        };
+        let Some(next_theirs) = next_theirs else {
+            return mismatch(Difference::Count {
+                m,
+                ours: our_count,
+                theirs: their_count,
+            });
+        };
+
+        position = next;
+        theirs = next_theirs;
+        moves.push(m);
+        depth -= 1;
+    }
+}
+
+/// Parses a line of the form `e2e4: 20`
+fn parse_divide_line(line: &str) -> Option<(String, u64)> {
+    let (m, count) = line.split_once(':')?;
+    let m = m.trim();
+    let is_move = (4..=5).contains(&m.len()) && m.chars().all(|c| c.is_ascii_alphanumeric());
+    if !is_move {
+        return None;
+    }
+    Some((m.to_string(), count.trim().parse().ok()?))
+}
+
+/// Only the placement, side to move, castling rights and en passant square affect perft
+fn position_key(fen: &str) -> String {
+    fen.split_whitespace()
+        .take(4)
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        perft::{
+            debug::{bisect, parse_divide_line, Difference, ReferenceFile},
+            perft_divide,
+        },
+        r#move::Move,
+        Position,
+    };
+
+    const FEN: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+
+    /// Returns our divide counts in the format of a reference file, with the count below one
+    /// move increased
+    fn section(fen: &str, depth: usize, wrong: Option<&str>) -> String {
+        let mut position = Position::from_fen(fen);
+        perft_divide(&mut position, depth, 1, 0)
+            .iter()
+            .map(|(m, count)| {
+                let count = count + (wrong == Some(m.to_string().as_str())) as u64;
+                format!("{m}: {count}\n")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parses_divide_lines() {
+        assert_eq!(
+            parse_divide_line("e2e4: 20"),
+            Some(("e2e4".to_string(), 20))
+        );
+        assert_eq!(parse_divide_line("a7a8q:1"), Some(("a7a8q".to_string(), 1)));
+        assert_eq!(parse_divide_line("Nodes searched: 20"), None);
+        assert_eq!(parse_divide_line("info string NNUE: loaded"), None);
+    }
+
+    #[test]
+    fn no_difference() {
+        let mut reference = ReferenceFile::parse(&section(FEN, 2, None), FEN, 2).unwrap();
+        let position = Position::from_fen(FEN);
+        assert_eq!(bisect(&position, 2, &mut reference).unwrap(), None);
+    }
+
+    #[test]
+    fn descends_to_missing_move() {
+        // Pretend that white has an additional move after 1. O-O hxg2
+        let mut position = Position::from_fen(FEN);
+        position.make(Move::try_from_str("e1g1", &position).unwrap());
+        let after_castling = position.to_fen();
+        position.make(Move::try_from_str("h3g2", &position).unwrap());
+        let after_capture = position.to_fen();
+
+        let contents = format!(
+            "{}\nfen {after_castling} depth 2\n{}\nfen {after_capture} depth 1\n{}a2a1: 1\n",
+            section(FEN, 3, Some("e1g1")),
+            section(&after_castling, 2, Some("h3g2")),
+            section(&after_capture, 1, None),
+        );
+
+        let mut reference = ReferenceFile::parse(&contents, FEN, 3).unwrap();
+        let position = Position::from_fen(FEN);
+        let mismatch = bisect(&position, 3, &mut reference).unwrap().unwrap();
+        assert_eq!(
+            mismatch
+                .moves
+                .iter()
+                .map(|m| m.to_string())
+                .collect::<Vec<String>>(),
+            vec!["e1g1", "h3g2"]
+        );
+        assert_eq!(mismatch.fen, after_capture);
+        assert_eq!(mismatch.depth, 1);
+        assert_eq!(mismatch.difference, Difference::Missing("a2a1".to_string()));
+    }
+
+    #[test]
+    fn stops_without_reference() {
+        let contents = section(FEN, 2, Some("a2a3"));
+        let mut reference = ReferenceFile::parse(&contents, FEN, 2).unwrap();
+        let position = Position::from_fen(FEN);
+        let mismatch = bisect(&position, 2, &mut reference).unwrap().unwrap();
+        assert!(mismatch.moves.is_empty());
+        assert!(matches!(
+            mismatch.difference,
+            Difference::Count { ours, theirs, .. } if theirs == ours + 1
+        ));
+    }
+}
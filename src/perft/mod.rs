@@ -0,0 +1,266 @@
+pub mod debug;
+pub mod stats;
+pub mod suite;
+
+use crate::{cache::Cache, move_list::move_vec::MoveVec, r#move::Move, Position};
+use std::sync::{mpsc::channel, Arc};
+use threadpool::ThreadPool;
+
+/// Number of plies below the root at which the tree is split into jobs for the threads, so that
+/// they stay busy even in positions with few legal moves
+const SPLIT_DEPTH: usize = 2;
+
+/// Returns the number of nodes at the provided depth
+/// hash_bytes is the size of the cache shared by all threads, which is
+/// rounded down to 2^N bytes, or less than 1024 bytes to disable it
+/// if threads is more than 1 the search will run concurrently
+pub fn perft(position: &mut Position, depth: usize, threads: usize, hash_bytes: usize) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
+    perft_divide(position, depth, threads, hash_bytes)
+        .iter()
+        .map(|(_, count)| count)
+        .sum()
+}
+
+/// Returns the number of nodes at the provided depth below each legal move, sorted by the
+/// notation of the moves
+pub fn perft_divide(
+    position: &mut Position,
+    depth: usize,
+    threads: usize,
+    hash_bytes: usize,
+) -> Vec<(Move, u64)> {
+    if depth == 0 {
+        return vec![];
+    }
+
+    let mut moves = MoveVec::new();
+    position.legal_moves(&mut moves);
+
+    let cache = (depth > 3)
+        .then(|| Cache::new(hash_bytes).ok())
+        .flatten()
+        .map(Arc::new);
+    let mut divide = if depth == 1 || threads <= 1 {
+        let state = position.state().clone();
+        let hash = position.hash();
+
+        moves
+            .iter()
+            .map(|&m| {
+                let capture = position.make(m);
+                let count = match cache.as_deref() {
+                    Some(cache) => perft_with_cache_inner(position, depth - 1, cache),
+                    None => perft_inner(position, depth - 1),
+                };
+                position.unmake(m, capture, &state, hash);
+                (m, count)
+            })
+            .collect()
+    } else {
+        let split_depth = SPLIT_DEPTH.min(depth - 1);
+        let jobs = split_nodes(position, split_depth);
+        let pool = ThreadPool::new(threads);
+        let (tx, rx) = channel();
+
+        let job_count = jobs.len();
+        for (root_move, mut position_local) in jobs {
+            let tx = tx.clone();
+            let cache = cache.clone();
+
+            pool.execute(move || {
+                let count = match cache.as_deref() {
+                    Some(cache) => {
+                        perft_with_cache_inner(&mut position_local, depth - split_depth, cache)
+                    }
+                    None => perft_inner(&mut position_local, depth - split_depth),
+                };
+
+                tx.send((root_move, count)).unwrap();
+            });
+        }
+
+        let mut divide: Vec<(Move, u64)> = moves.iter().map(|&m| (m, 0)).collect();
+        for (root_move, count) in rx.iter().take(job_count) {
+            divide[root_move].1 += count;
+        }
+        divide
+    };
+
+    divide.sort_by_cached_key(|(m, _)| m.to_string());
+    divide
+}
+
+/// Returns the positions the given number of plies below the root, along with the index of the
+/// root move that leads to each of them
+fn split_nodes(position: &Position, plies: usize) -> Vec<(usize, Position)> {
+    fn collect(
+        position: &Position,
+        plies: usize,
+        root_move: usize,
+        nodes: &mut Vec<(usize, Position)>,
+    ) {
+        if plies == 0 {
+            nodes.push((root_move, position.clone()));
+            return;
+        }
+
+        let mut moves = MoveVec::new();
+        position.legal_moves(&mut moves);
+        for &m in moves.iter() {
+            let mut next = position.clone();
+            next.make(m);
+            collect(&next, plies - 1, root_move, nodes);
+        }
+    }
+
+    let mut moves = MoveVec::new();
+    position.legal_moves(&mut moves);
+
+    let mut nodes = vec![];
+    for (root_move, &m) in moves.iter().enumerate() {
+        let mut next = position.clone();
+        next.make(m);
+        collect(&next, plies - 1, root_move, &mut nodes);
+    }
+    nodes
+}
+
+fn perft_inner(position: &mut Position, depth: usize) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
+    if depth == 1 {
+        return position.count_legal_moves();
+    }
+
+    let mut moves = MoveVec::new();
+    position.legal_moves(&mut moves);
+
+    let state = position.state().clone();
+    let hash = position.hash();
+    let mut total = 0;
+    for &m in moves.iter() {
+        let capture = position.make(m);
+        total += perft_inner(position, depth - 1);
+        position.unmake(m, capture, &state, hash);
+    }
+
+    total
+}
+
+fn perft_with_cache_inner(position: &mut Position, depth: usize, cache: &Cache) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
+    let hash = position.hash();
+
+    let result = cache.probe(hash, depth);
+    if let Some(value) = result {
+        return value;
+    }
+
+    let mut total = 0;
+    if depth == 1 {
+        total = position.count_legal_moves();
+    } else {
+        let mut moves = MoveVec::new();
+        position.legal_moves(&mut moves);
+
+        let state = position.state().clone();
+        for &m in moves.iter() {
+            let capture = position.make(m);
+            total += perft_with_cache_inner(position, depth - 1, cache);
+            position.unmake(m, capture, &state, hash);
+        }
+    }
+
+    cache.save(hash, total, depth);
+
+    total
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        perft,
+        perft::{perft_divide, perft_inner},
+        Position, STARTING_POSITION_FEN,
+    };
+
+    #[test]
+    fn p() {
+        let mut position = Position::from_fen(STARTING_POSITION_FEN);
+        assert_eq!(perft(&mut position, 1, 4, 1024 * 1024 * 4), 20);
+
+        let mut position = Position::from_fen(STARTING_POSITION_FEN);
+        assert_eq!(perft(&mut position, 2, 4, 1024 * 1024 * 4), 400);
+
+        let mut position = Position::from_fen(STARTING_POSITION_FEN);
+        assert_eq!(perft(&mut position, 3, 4, 1024 * 1024 * 4), 8902);
+
+        let mut position = Position::from_fen(STARTING_POSITION_FEN);
+        assert_eq!(perft(&mut position, 4, 4, 1024 * 1024 * 4), 197281);
+
+        let mut position = Position::from_fen(STARTING_POSITION_FEN);
+        assert_eq!(perft(&mut position, 5, 4, 1024 * 1024 * 4), 4865609);
+    }
+
+    #[test]
+    fn kiwipete() {
+        // Castling with black used to take away the queen side castling rights of white
+        let mut position = Position::from_fen(
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        );
+        assert_eq!(perft(&mut position, 3, 1, 0), 97862);
+
+        // Hash sizes that aren't a power of two are rounded down, and too small ones disable it
+        assert_eq!(perft(&mut position, 4, 2, 3 * 1024 * 1024), 4085603);
+        assert_eq!(perft(&mut position, 4, 2, 1000), 4085603);
+    }
+
+    #[test]
+    fn divide() {
+        let fen = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+        for threads in [1, 4] {
+            let mut position = Position::from_fen(fen);
+            let divide = perft_divide(&mut position, 4, threads, 1024 * 1024);
+            assert_eq!(divide.len(), 48);
+            assert_eq!(divide.iter().map(|(_, count)| count).sum::<u64>(), 4085603);
+
+            let notation: Vec<String> = divide.iter().map(|(m, _)| m.to_string()).collect();
+            assert!(notation.windows(2).all(|pair| pair[0] < pair[1]));
+
+            let &(m, count) = divide
+                .iter()
+                .find(|(m, _)| m.to_string() == "e1g1")
+                .unwrap();
+            let mut position = Position::from_fen(fen);
+            position.make(m);
+            assert_eq!(count, perft_inner(&mut position, 3));
+        }
+
+        // Moves below which there are no nodes are still listed when the work is split
+        let fen = "6k1/5ppp/8/8/8/8/5PPP/R5K1 w - - 0 1";
+        for depth in 2..=4 {
+            let mut position = Position::from_fen(fen);
+            let divide = perft_divide(&mut position, depth, 4, 1024 * 1024);
+            let mut position = Position::from_fen(fen);
+            assert_eq!(divide, perft_divide(&mut position, depth, 1, 0));
+            assert!(divide
+                .iter()
+                .any(|(m, count)| m.to_string() == "a1a8" && *count == 0));
+        }
+
+        let mut position = Position::from_fen(STARTING_POSITION_FEN);
+        assert!(perft_divide(&mut position, 0, 4, 0).is_empty());
+        assert!(perft_divide(&mut position, 1, 1, 0)
+            .iter()
+            .all(|&(_, count)| count == 1));
+    }
+}
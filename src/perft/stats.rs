@@ -0,0 +1,198 @@
+use crate::{
+    board::Board,
+    move_list::{move_counter::MoveCounter, move_vec::MoveVec},
+    perft::{split_nodes, SPLIT_DEPTH},
+    r#move::Move,
+    square::Square,
+    Position,
+};
+use serde::Serialize;
+use std::{ops::AddAssign, sync::mpsc::channel};
+use threadpool::ThreadPool;
+
+/// Counts of the different kinds of moves that lead to the nodes at a depth, as listed in the
+/// perft results on the chess programming wiki
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+pub struct PerftStats {
+    pub nodes: u64,
+    pub captures: u64,
+    pub en_passant: u64,
+    pub castles: u64,
+    pub promotions: u64,
+    pub checks: u64,
+    /// Checks by a single piece other than the one that moved
+    pub discovered_checks: u64,
+    pub double_checks: u64,
+    pub checkmates: u64,
+}
+
+impl AddAssign for PerftStats {
+    fn add_assign(&mut self, other: PerftStats) {
+        self.nodes += other.nodes;
+        self.captures += other.captures;
+        self.en_passant += other.en_passant;
+        self.castles += other.castles;
+        self.promotions += other.promotions;
+        self.checks += other.checks;
+        self.discovered_checks += other.discovered_checks;
+        self.double_checks += other.double_checks;
+        self.checkmates += other.checkmates;
+    }
+}
+
+impl PerftStats {
+    /// Counts the move that was just made to reach the position
+    fn add_leaf(&mut self, position: &Position, m: Move) {
+        self.nodes += 1;
+        self.captures += m.is_capture() as u64;
+        self.en_passant += m.is_en_passant_capture() as u64;
+        self.castles += m.castle().is_some() as u64;
+        self.promotions += m.promote_to().is_some() as u64;
+
+        let checkers = position.checkers();
+        if !checkers.any() {
+            return;
+        }
+
+        // When castling, only the rook can give check
+        let moved_to = match m.castle() {
+            Some(_) if m.to().file_index() == 6 => Square(m.to().0 - 1),
+            Some(_) => Square(m.to().0 + 1),
+            None => m.to(),
+        };
+
+        self.checks += 1;
+        if checkers.occupied() > 1 {
+            self.double_checks += 1;
+        } else if checkers != Board::new(moved_to) {
+            self.discovered_checks += 1;
+        }
+
+        let mut replies = MoveCounter::new();
+        position.legal_moves(&mut replies);
+        self.checkmates += (replies.moves == 0) as u64;
+    }
+}
+
+/// Returns the statistics of the nodes at the provided depth, running concurrently if threads is
+/// more than 1
+pub fn perft_stats(position: &mut Position, depth: usize, threads: usize) -> PerftStats {
+    if depth <= 1 || threads <= 1 {
+        return perft_stats_inner(position, depth);
+    }
+
+    let split_depth = SPLIT_DEPTH.min(depth - 1);
+    let jobs = split_nodes(position, split_depth);
+    let pool = ThreadPool::new(threads);
+    let (tx, rx) = channel();
+
+    let job_count = jobs.len();
+    for (_, mut position_local) in jobs {
+        let tx = tx.clone();
+
+        pool.execute(move || {
+            tx.send(perft_stats_inner(&mut position_local, depth - split_depth))
+                .unwrap();
+        });
+    }
+
+    let mut total = PerftStats::default();
+    for stats in rx.iter().take(job_count) {
+        total += stats;
+    }
+    total
+}
+
+fn perft_stats_inner(position: &mut Position, depth: usize) -> PerftStats {
+    let mut stats = PerftStats::default();
+    if depth == 0 {
+        stats.nodes = 1;
+        return stats;
+    }
+
+    let mut moves = MoveVec::new();
+    position.legal_moves(&mut moves);
+
+    let state = position.state().clone();
+    let hash = position.hash();
+    for &m in moves.iter() {
+        let capture = position.make(m);
+        if depth == 1 {
+            stats.add_leaf(position, m);
+        } else {
+            stats += perft_stats_inner(position, depth - 1);
+        }
+        position.unmake(m, capture, &state, hash);
+    }
+
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        perft::stats::{perft_stats, PerftStats},
+        Position, STARTING_POSITION_FEN,
+    };
+
+    #[allow(clippy::too_many_arguments)]
+    fn stats(
+        nodes: u64,
+        captures: u64,
+        en_passant: u64,
+        castles: u64,
+        promotions: u64,
+        checks: u64,
+        discovered_checks: u64,
+        double_checks: u64,
+        checkmates: u64,
+    ) -> PerftStats {
+        PerftStats {
+            nodes,
+            captures,
+            en_passant,
+            castles,
+            promotions,
+            checks,
+            discovered_checks,
+            double_checks,
+            checkmates,
+        }
+    }
+
+    #[test]
+    fn matches_published_results() {
+        for (fen, depth, expected) in [
+            (
+                STARTING_POSITION_FEN,
+                4,
+                stats(197281, 1576, 0, 0, 0, 469, 0, 0, 8),
+            ),
+            (
+                "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+                3,
+                stats(97862, 17102, 45, 3162, 0, 993, 0, 0, 1),
+            ),
+            (
+                "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+                5,
+                stats(674624, 52051, 1165, 0, 0, 52950, 1292, 3, 0),
+            ),
+            (
+                "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+                4,
+                stats(422333, 131393, 0, 7795, 60032, 15492, 19, 0, 5),
+            ),
+        ] {
+            let mut position = Position::from_fen(fen);
+            assert_eq!(perft_stats(&mut position, depth, 4), expected, "{fen}");
+        }
+    }
+
+    #[test]
+    fn depth_zero() {
+        let mut position = Position::from_fen(STARTING_POSITION_FEN);
+        assert_eq!(perft_stats(&mut position, 0, 1).nodes, 1);
+        assert_eq!(perft_stats(&mut position, 1, 1).nodes, 20);
+    }
+}
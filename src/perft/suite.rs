@@ -0,0 +1,176 @@
+use crate::{perft::perft, Position};
+use std::time::{Duration, Instant};
+
+/// A position of a perft suite with the expected node counts, written as an EPD line like
+/// `<FEN> ;D1 20 ;D2 400`
+#[derive(Debug, PartialEq)]
+pub struct SuiteEntry {
+    pub fen: String,
+    pub depths: Vec<(usize, u64)>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DepthResult {
+    pub depth: usize,
+    pub expected: u64,
+    pub actual: u64,
+}
+
+impl DepthResult {
+    pub fn passed(&self) -> bool {
+        self.expected == self.actual
+    }
+}
+
+#[derive(Debug)]
+pub struct SuiteResult {
+    pub fen: String,
+    pub results: Vec<DepthResult>,
+    /// Number of depths that weren't run because of the depth or time limit
+    pub skipped: usize,
+    pub elapsed: Duration,
+}
+
+impl SuiteResult {
+    /// Whether at least one depth was run. An entry whose depths were all skipped proves nothing
+    pub fn ran(&self) -> bool {
+        !self.results.is_empty()
+    }
+
+    pub fn passed(&self) -> bool {
+        self.ran() && self.results.iter().all(DepthResult::passed)
+    }
+}
+
+/// Parses a perft suite, ignoring empty lines and comments starting with `#`
+pub fn parse_suite(contents: &str) -> Result<Vec<SuiteEntry>, String> {
+    contents
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(line_number, line)| {
+            parse_entry(line).map_err(|err| format!("Line {line_number}: {err}"))
+        })
+        .collect()
+}
+
+fn parse_entry(line: &str) -> Result<SuiteEntry, String> {
+    let mut fields = line.split(';');
+    let fen = fields.next().unwrap_or_default().trim().to_string();
+    Position::try_from_fen(&fen)?;
+
+    let mut depths = vec![];
+    for field in fields {
+        let field = field.trim();
+        let (depth, count) = field
+            .strip_prefix('D')
+            .and_then(|field| field.split_once(char::is_whitespace))
+            .ok_or_else(|| format!("Invalid depth \"{field}\""))?;
+        let depth = depth
+            .parse()
+            .map_err(|_| format!("Invalid depth \"{field}\""))?;
+        let count = count
+            .trim()
+            .parse()
+            .map_err(|_| format!("Invalid node count \"{field}\""))?;
+        depths.push((depth, count));
+    }
+    depths.sort();
+
+    Ok(SuiteEntry { fen, depths })
+}
+
+/// Runs perft for every depth of the entry up to `max_depth`. Once running the position took
+/// longer than the time limit, the remaining depths are skipped.
+pub fn run_entry(
+    entry: &SuiteEntry,
+    max_depth: usize,
+    time_limit: Option<Duration>,
+    threads: usize,
+) -> SuiteResult {
+    let start = Instant::now();
+    let mut results = vec![];
+    let mut skipped = 0;
+
+    for &(depth, expected) in entry.depths.iter() {
+        let out_of_time = time_limit.is_some_and(|limit| start.elapsed() > limit);
+        if depth > max_depth || out_of_time {
+            skipped += 1;
+            continue;
+        }
+
+        let mut position = Position::from_fen(&entry.fen);
+        let actual = perft(&mut position, depth, threads, 1024 * 1024 * 4);
+        results.push(DepthResult {
+            depth,
+            expected,
+            actual,
+        });
+    }
+
+    SuiteResult {
+        fen: entry.fen.clone(),
+        results,
+        skipped,
+        elapsed: start.elapsed(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::perft::suite::{parse_suite, run_entry, SuiteEntry};
+
+    const KIWIPETE: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+
+    #[test]
+    fn parses_suite() {
+        let suite = parse_suite(&format!(
+            "# Kiwipete\n{KIWIPETE} ;D2 2039 ;D1 48\n\n4k3/8/8/8/8/8/8/4K2R w K - 0 1 ;D1 15\n"
+        ))
+        .unwrap();
+        assert_eq!(
+            suite,
+            vec![
+                SuiteEntry {
+                    fen: KIWIPETE.to_string(),
+                    depths: vec![(1, 48), (2, 2039)],
+                },
+                SuiteEntry {
+                    fen: "4k3/8/8/8/8/8/8/4K2R w K - 0 1".to_string(),
+                    depths: vec![(1, 15)],
+                },
+            ]
+        );
+
+        assert!(parse_suite(&format!("{KIWIPETE} ;D1")).is_err());
+        assert!(parse_suite(&format!("{KIWIPETE} ;X1 48")).is_err());
+        assert!(parse_suite("not a fen ;D1 20").is_err());
+    }
+
+    #[test]
+    fn runs_entry() {
+        let entry = SuiteEntry {
+            fen: KIWIPETE.to_string(),
+            depths: vec![(1, 48), (2, 2039), (3, 97863), (4, 4085603)],
+        };
+        let result = run_entry(&entry, 3, None, 1);
+        assert_eq!(result.results.len(), 3);
+        assert_eq!(result.skipped, 1);
+        assert!(result.results[0].passed() && result.results[1].passed());
+        assert_eq!(result.results[2].actual, 97862);
+        assert!(!result.passed());
+    }
+
+    #[test]
+    fn skipped_entry_does_not_pass() {
+        let entry = SuiteEntry {
+            fen: KIWIPETE.to_string(),
+            depths: vec![(5, 193690690)],
+        };
+        let result = run_entry(&entry, 4, None, 1);
+        assert_eq!(result.skipped, 1);
+        assert!(!result.ran());
+        assert!(!result.passed());
+    }
+}
@@ -0,0 +1,252 @@
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GameResult {
+    WhiteWins,
+    BlackWins,
+    Draw,
+    Unknown,
+}
+
+impl GameResult {
+    pub fn try_from_str(s: &str) -> Option<GameResult> {
+        match s {
+            "1-0" => Some(Self::WhiteWins),
+            "0-1" => Some(Self::BlackWins),
+            "1/2-1/2" => Some(Self::Draw),
+            "*" => Some(Self::Unknown),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    pub tags: Vec<(String, String)>,
+    /// The moves of the main line in standard algebraic notation
+    pub moves: Vec<String>,
+    pub result: GameResult,
+}
+
+impl Game {
+    fn new() -> Game {
+        Game {
+            tags: vec![],
+            moves: vec![],
+            result: GameResult::Unknown,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.tags.is_empty() && self.moves.is_empty()
+    }
+
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Reads games from PGN data one at a time. Comments, variations and numeric annotation glyphs are
+/// skipped, so that only the tags and the moves of the main line remain.
+pub struct PgnReader<R: BufRead> {
+    reader: R,
+    game: Game,
+    games: VecDeque<Game>,
+    is_in_comment: bool,
+    variation_depth: u32,
+}
+
+impl<R: BufRead> PgnReader<R> {
+    pub fn new(reader: R) -> PgnReader<R> {
+        PgnReader {
+            reader,
+            game: Game::new(),
+            games: VecDeque::new(),
+            is_in_comment: false,
+            variation_depth: 0,
+        }
+    }
+
+    fn finish_game(&mut self) {
+        let game = std::mem::replace(&mut self.game, Game::new());
+        if !game.is_empty() {
+            self.games.push_back(game);
+        }
+    }
+
+    fn read_line(&mut self, line: &str) {
+        let trimmed = line.trim();
+        let is_in_movetext = self.is_in_comment || self.variation_depth > 0;
+
+        if !is_in_movetext && trimmed.starts_with('[') {
+            // A tag after some moves means that the previous game had no result
+            if !self.game.moves.is_empty() {
+                self.finish_game();
+            }
+            if let Some(tag) = parse_tag(trimmed) {
+                self.game.tags.push(tag);
+            }
+            return;
+        }
+
+        // Escaped lines
+        if !is_in_movetext && trimmed.starts_with('%') {
+            return;
+        }
+
+        let mut token = String::new();
+        for c in trimmed.chars() {
+            if self.is_in_comment {
+                self.is_in_comment = c != '}';
+                continue;
+            }
+
+            match c {
+                '{' | ';' | '(' | ')' => {
+                    self.read_token(&token);
+                    token.clear();
+                    match c {
+                        '{' => self.is_in_comment = true,
+                        // Comments until the end of the line
+                        ';' => return,
+                        '(' => self.variation_depth += 1,
+                        _ => self.variation_depth = self.variation_depth.saturating_sub(1),
+                    }
+                }
+                c if c.is_whitespace() => {
+                    self.read_token(&token);
+                    token.clear();
+                }
+                c => token.push(c),
+            }
+        }
+        self.read_token(&token);
+    }
+
+    fn read_token(&mut self, token: &str) {
+        if self.variation_depth > 0 || token.is_empty() || token.starts_with('$') {
+            return;
+        }
+
+        if let Some(result) = GameResult::try_from_str(token) {
+            self.game.result = result;
+            self.finish_game();
+            return;
+        }
+
+        // Strip move numbers like "12." or "12..." that might be glued to the move itself
+        let san = match token.rfind('.') {
+            Some(index) => &token[index + 1..],
+            None => token,
+        };
+        if !san.is_empty() {
+            self.game.moves.push(String::from(san));
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for PgnReader<R> {
+    type Item = io::Result<Game>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut line = String::new();
+        while self.games.is_empty() {
+            line.clear();
+            match self.reader.read_line(&mut line) {
+                Ok(0) => {
+                    self.finish_game();
+                    break;
+                }
+                Ok(_) => self.read_line(&line),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+
+        self.games.pop_front().map(Ok)
+    }
+}
+
+fn parse_tag(s: &str) -> Option<(String, String)> {
+    let inner = s.strip_prefix('[')?.trim_end().strip_suffix(']')?;
+    let (name, value) = inner.split_once(char::is_whitespace)?;
+
+    let value = value.trim().strip_prefix('"')?.strip_suffix('"')?;
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(escaped) = chars.next() {
+                unescaped.push(escaped);
+            }
+        } else {
+            unescaped.push(c);
+        }
+    }
+
+    Some((String::from(name), unescaped))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pgn::{GameResult, PgnReader};
+
+    #[test]
+    fn reads_tags_and_moves() {
+        let pgn = r#"[Event "Casual \"blitz\""]
+[White "Alice"]
+[Black "Bob"]
+[WhiteElo "2100"]
+
+1. e4 e5 2. Nf3 Nc6 3.Bb5 a6 1-0
+"#;
+        let games = PgnReader::new(pgn.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].tag("Event"), Some("Casual \"blitz\""));
+        assert_eq!(games[0].tag("WhiteElo"), Some("2100"));
+        assert_eq!(games[0].tag("BlackElo"), None);
+        assert_eq!(games[0].moves, vec!["e4", "e5", "Nf3", "Nc6", "Bb5", "a6"]);
+        assert_eq!(games[0].result, GameResult::WhiteWins);
+    }
+
+    #[test]
+    fn skips_comments_variations_and_annotations() {
+        let pgn = r#"[Event "?"]
+
+1. d4 {A comment
+spanning two lines} d5 (1... Nf6 2. c4 (2. Nf3) e6) 2. c4 $1 e6 ; rest of the line
+3. Nc3 Nf6 1/2-1/2
+
+[Event "?"]
+
+1. e4 c5 0-1
+"#;
+        let games = PgnReader::new(pgn.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(games.len(), 2);
+        assert_eq!(games[0].moves, vec!["d4", "d5", "c4", "e6", "Nc3", "Nf6"]);
+        assert_eq!(games[0].result, GameResult::Draw);
+        assert_eq!(games[1].moves, vec!["e4", "c5"]);
+        assert_eq!(games[1].result, GameResult::BlackWins);
+    }
+
+    #[test]
+    fn reads_games_without_result() {
+        let pgn = "[Event \"?\"]\n\n1. e4 e5\n\n[Event \"?\"]\n\n1. d4 *\n";
+        let games = PgnReader::new(pgn.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(games.len(), 2);
+        assert_eq!(games[0].moves, vec!["e4", "e5"]);
+        assert_eq!(games[0].result, GameResult::Unknown);
+        assert_eq!(games[1].moves, vec!["d4"]);
+    }
+}
@@ -0,0 +1,210 @@
+use crate::{
+    side::{Side, BLACK, WHITE},
+    square::Square,
+};
+use std::sync::OnceLock;
+
+/// White king, black king, side to move, and the pawn on one of the 24 squares a2-d7
+const MAX_INDEX: usize = 2 * 24 * 64 * 64;
+
+const INVALID: u8 = 0;
+const UNKNOWN: u8 = 1;
+const DRAW: u8 = 2;
+const WIN: u8 = 4;
+
+fn index(side_to_move: Side, black_king: usize, white_king: usize, pawn: usize) -> usize {
+    white_king
+        | (black_king << 6)
+        | ((side_to_move.0 as usize) << 12)
+        | ((pawn & 7) << 13)
+        | ((6 - (pawn >> 3)) << 15)
+}
+
+fn distance(a: usize, b: usize) -> usize {
+    let files = ((a & 7) as i32 - (b & 7) as i32).unsigned_abs();
+    let ranks = ((a >> 3) as i32 - (b >> 3) as i32).unsigned_abs();
+    files.max(ranks) as usize
+}
+
+fn king_moves(sq: usize) -> u64 {
+    Square(sq as u8).king_moves().0
+}
+
+fn pawn_attacks(sq: usize) -> u64 {
+    let pawn = 1u64 << sq;
+    ((pawn << 7) & !0x8080_8080_8080_8080) | ((pawn << 9) & !0x0101_0101_0101_0101)
+}
+
+struct KpkPosition {
+    side_to_move: Side,
+    white_king: usize,
+    black_king: usize,
+    pawn: usize,
+    result: u8,
+}
+
+impl KpkPosition {
+    fn new(idx: usize) -> KpkPosition {
+        let white_king = idx & 0x3F;
+        let black_king = (idx >> 6) & 0x3F;
+        let side_to_move = Side(((idx >> 12) & 1) as u8);
+        let pawn = (6 - ((idx >> 15) & 7)) * 8 + ((idx >> 13) & 3);
+
+        let push = pawn + 8;
+        let result = if distance(white_king, black_king) <= 1
+            || white_king == pawn
+            || black_king == pawn
+            || (side_to_move == WHITE && pawn_attacks(pawn) & (1 << black_king) != 0)
+        {
+            // Two pieces on the same square, or a king can be captured
+            INVALID
+        } else if side_to_move == WHITE
+            && pawn >> 3 == 6
+            && white_king != push
+            && (distance(black_king, push) > 1 || distance(white_king, push) == 1)
+        {
+            // The pawn promotes without getting captured
+            WIN
+        } else if side_to_move == BLACK
+            && (king_moves(black_king) & !(king_moves(white_king) | pawn_attacks(pawn)) == 0
+                || king_moves(black_king) & !king_moves(white_king) & (1 << pawn) != 0)
+        {
+            // Stalemate, or the black king captures the pawn
+            DRAW
+        } else {
+            UNKNOWN
+        };
+
+        KpkPosition {
+            side_to_move,
+            white_king,
+            black_king,
+            pawn,
+            result,
+        }
+    }
+
+    /// White wins if any move leads to a won position, black draws if any move leads to a drawn
+    /// position
+    fn classify(&self, db: &[KpkPosition]) -> u8 {
+        let (good, bad) = if self.side_to_move == WHITE {
+            (WIN, DRAW)
+        } else {
+            (DRAW, WIN)
+        };
+
+        let mut r = INVALID;
+        let mut moves = if self.side_to_move == WHITE {
+            king_moves(self.white_king)
+        } else {
+            king_moves(self.black_king)
+        };
+        while moves != 0 {
+            let sq = moves.trailing_zeros() as usize;
+            moves &= moves - 1;
+
+            r |= if self.side_to_move == WHITE {
+                db[index(BLACK, self.black_king, sq, self.pawn)].result
+            } else {
+                db[index(WHITE, sq, self.white_king, self.pawn)].result
+            };
+        }
+
+        if self.side_to_move == WHITE {
+            let push = self.pawn + 8;
+            if self.pawn >> 3 < 6 {
+                r |= db[index(BLACK, self.black_king, self.white_king, push)].result;
+            }
+            if self.pawn >> 3 == 1 && push != self.white_king && push != self.black_king {
+                r |= db[index(BLACK, self.black_king, self.white_king, push + 8)].result;
+            }
+        }
+
+        if r & good != 0 {
+            good
+        } else if r & UNKNOWN != 0 {
+            UNKNOWN
+        } else {
+            bad
+        }
+    }
+}
+
+/// Generates the bitbase by retrograde analysis, starting with the positions that can be
+/// classified right away and iterating until no more positions change
+fn generate() -> Vec<u64> {
+    let mut db: Vec<KpkPosition> = (0..MAX_INDEX).map(KpkPosition::new).collect();
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for idx in 0..MAX_INDEX {
+            if db[idx].result == UNKNOWN {
+                let result = db[idx].classify(&db);
+                if result != UNKNOWN {
+                    db[idx].result = result;
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    let mut bitbase = vec![0; MAX_INDEX / 64];
+    for (idx, position) in db.iter().enumerate() {
+        if position.result == WIN {
+            bitbase[idx / 64] |= 1 << (idx % 64);
+        }
+    }
+    bitbase
+}
+
+/// Returns whether white wins a king and pawn versus king endgame, where the pawn is on one of the
+/// files a to d
+pub fn probe(white_king: Square, pawn: Square, black_king: Square, side_to_move: Side) -> bool {
+    static BITBASE: OnceLock<Vec<u64>> = OnceLock::new();
+    let bitbase = BITBASE.get_or_init(generate);
+
+    let idx = index(
+        side_to_move,
+        black_king.0 as usize,
+        white_king.0 as usize,
+        pawn.0 as usize,
+    );
+    bitbase[idx / 64] & (1 << (idx % 64)) != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        position::endgame::kpk::probe,
+        side::{BLACK, WHITE},
+        square::Square,
+    };
+
+    fn sq(s: &str) -> Square {
+        Square::try_from_str(s).unwrap().unwrap()
+    }
+
+    #[test]
+    fn wins() {
+        // The pawn runs
+        assert!(probe(sq("h1"), sq("a7"), sq("h8"), WHITE));
+        // The king in front of the pawn on the sixth rank wins no matter who moves
+        assert!(probe(sq("d6"), sq("d5"), sq("d8"), WHITE));
+        assert!(probe(sq("d6"), sq("d5"), sq("d8"), BLACK));
+        // Key squares
+        assert!(probe(sq("c6"), sq("c4"), sq("c8"), BLACK));
+    }
+
+    #[test]
+    fn draws() {
+        // Rook pawn with the defending king in the corner
+        assert!(!probe(sq("a1"), sq("a2"), sq("a8"), WHITE));
+        // The pawn gets captured
+        assert!(!probe(sq("h1"), sq("c2"), sq("d2"), BLACK));
+        // The defending king is in front of the pawn and has the opposition
+        assert!(!probe(sq("d4"), sq("d3"), sq("d6"), WHITE));
+        // Stalemate
+        assert!(!probe(sq("b6"), sq("a7"), sq("a8"), BLACK));
+    }
+}
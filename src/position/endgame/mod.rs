@@ -0,0 +1,201 @@
+mod kpk;
+
+use crate::{
+    piece::{PieceKind, BISHOP, KING, KNIGHT, PAWN, QUEEN, ROOK},
+    side::{Side, BLACK, WHITE},
+    square::Square,
+    Position,
+};
+
+/// Score for endgames that are known to be won, which is higher than any regular evaluation
+pub const KNOWN_WIN: i32 = 10_000;
+
+const DARK_SQUARES: u64 = 0xAA55_AA55_AA55_AA55;
+
+fn edge_distance(index: u8) -> i32 {
+    index.min(7 - index) as i32
+}
+
+fn distance(a: Square, b: Square) -> i32 {
+    let files = (a.file_index() as i32 - b.file_index() as i32).abs();
+    let ranks = (a.rank_index() as i32 - b.rank_index() as i32).abs();
+    files.max(ranks)
+}
+
+/// Bonus for driving the king towards the edge of the board
+fn push_to_edge(sq: Square) -> i32 {
+    let file_distance = edge_distance(sq.file_index());
+    let rank_distance = edge_distance(sq.rank_index());
+    90 - (7 * file_distance * file_distance / 2 + 7 * rank_distance * rank_distance / 2)
+}
+
+/// Bonus for driving the king towards the corners a1 and h8
+fn push_to_corner(sq: Square) -> i32 {
+    (7 - sq.rank_index() as i32 - sq.file_index() as i32).abs()
+}
+
+/// Bonus for keeping two pieces close to each other
+fn push_close(a: Square, b: Square) -> i32 {
+    140 - 20 * distance(a, b)
+}
+
+impl Position {
+    fn count(&self, side: Side, kind: PieceKind) -> u32 {
+        self.piece(kind.to_piece(side)).occupied()
+    }
+
+    fn king_square(&self, side: Side) -> Square {
+        self.piece(KING.to_piece(side)).to_square()
+    }
+
+    /// Evaluates endgames where one side only has its king left, in which the general evaluation
+    /// doesn't know how to make progress. Returns the score from white's perspective, or `None` if
+    /// the position is not a known endgame.
+    pub fn evaluate_endgame(&self) -> Option<i32> {
+        let (strong_side, weak_side) = if self.side(BLACK).occupied() == 1 {
+            (WHITE, BLACK)
+        } else if self.side(WHITE).occupied() == 1 {
+            (BLACK, WHITE)
+        } else {
+            return None;
+        };
+
+        let queens = self.count(strong_side, QUEEN);
+        let rooks = self.count(strong_side, ROOK);
+        let bishops = self.count(strong_side, BISHOP);
+        let knights = self.count(strong_side, KNIGHT);
+        let pawns = self.count(strong_side, PAWN);
+
+        let score = if queens == 0 && rooks == 0 && bishops == 0 && knights == 0 && pawns == 1 {
+            self.evaluate_kpk(strong_side)
+        } else if queens == 0 && rooks == 0 && bishops == 1 && knights == 1 && pawns == 0 {
+            self.evaluate_kbnk(strong_side, weak_side)
+        } else if queens == 0 && rooks == 0 && bishops == 0 && knights == 2 && pawns == 0 {
+            // Two knights can't force checkmate
+            0
+        } else if queens * 900 + rooks * 500 + (bishops + knights) * 300 >= 500 {
+            self.evaluate_kxk(strong_side, weak_side)
+        } else {
+            return None;
+        };
+
+        Some(if strong_side == WHITE { score } else { -score })
+    }
+
+    /// King and pawn versus king, which is either won or drawn according to the bitbase
+    fn evaluate_kpk(&self, strong_side: Side) -> i32 {
+        // Normalize the position so that the strong side is white with the pawn on files a to d
+        let pawn = self.piece(PAWN.to_piece(strong_side)).to_square();
+        let flip_files = if pawn.file_index() >= 4 { 7 } else { 0 };
+        let flip_ranks = if strong_side == WHITE { 0 } else { 56 };
+        let normalize = |sq: Square| Square(sq.0 ^ flip_files ^ flip_ranks);
+
+        let pawn = normalize(pawn);
+        let strong_king = normalize(self.king_square(strong_side));
+        let weak_king = normalize(self.king_square(!strong_side));
+        let side_to_move = if self.state.side_to_move == strong_side {
+            WHITE
+        } else {
+            BLACK
+        };
+
+        if !kpk::probe(strong_king, pawn, weak_king, side_to_move) {
+            return 0;
+        }
+
+        self.params.known_win + 100 + pawn.rank_index() as i32
+    }
+
+    /// King, bishop and knight versus king, where the king needs to be driven into a corner of the
+    /// same color as the bishop
+    fn evaluate_kbnk(&self, strong_side: Side, weak_side: Side) -> i32 {
+        let strong_king = self.king_square(strong_side);
+        let weak_king = self.king_square(weak_side);
+        let bishop = self.piece(BISHOP.to_piece(strong_side));
+
+        // Mirror the weak king if the bishop can't reach the corners a1 and h8
+        let corner_square = if bishop.0 & DARK_SQUARES == 0 {
+            Square(weak_king.0 ^ 7)
+        } else {
+            weak_king
+        };
+
+        self.params.known_win
+            + 3520
+            + push_close(strong_king, weak_king)
+            + 420 * push_to_corner(corner_square)
+    }
+
+    /// Any material versus a lone king, where the king needs to be driven to the edge of the board
+    fn evaluate_kxk(&self, strong_side: Side, weak_side: Side) -> i32 {
+        let strong_king = self.king_square(strong_side);
+        let weak_king = self.king_square(weak_side);
+
+        let bishops = self.piece(BISHOP.to_piece(strong_side)).0;
+        let material = self.count(strong_side, QUEEN) as i32 * 900
+            + self.count(strong_side, ROOK) as i32 * 500
+            + self.count(strong_side, BISHOP) as i32 * 300
+            + self.count(strong_side, KNIGHT) as i32 * 300
+            + self.count(strong_side, PAWN) as i32 * 100;
+
+        let mut score = material + push_to_edge(weak_king) + push_close(strong_king, weak_king);
+
+        let can_force_mate = self.count(strong_side, QUEEN) > 0
+            || self.count(strong_side, ROOK) > 0
+            || (self.count(strong_side, BISHOP) > 0 && self.count(strong_side, KNIGHT) > 0)
+            || (bishops & DARK_SQUARES != 0 && bishops & !DARK_SQUARES != 0);
+        if can_force_mate {
+            score += self.params.known_win;
+        }
+
+        score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{position::endgame::KNOWN_WIN, Position};
+
+    fn evaluate(fen: &str) -> Option<i32> {
+        Position::from_fen(fen).evaluate_endgame()
+    }
+
+    #[test]
+    fn kpk() {
+        assert!(evaluate("8/8/8/8/8/8/4P3/4K2k w - - 0 1").unwrap() > KNOWN_WIN);
+        assert_eq!(evaluate("k7/8/8/8/8/8/P7/K7 w - - 0 1"), Some(0));
+
+        // Black has the pawn on the king side
+        assert!(evaluate("k7/8/8/8/8/2K5/6p1/8 b - - 0 1").unwrap() < -KNOWN_WIN);
+        assert_eq!(evaluate("8/8/8/8/8/8/k5p1/6K1 w - - 0 1"), Some(0));
+    }
+
+    #[test]
+    fn kxk() {
+        // The weak king is better off in the center
+        let center = evaluate("8/8/8/3k4/8/3K4/8/1Q6 w - - 0 1").unwrap();
+        let edge = evaluate("3k4/8/3K4/8/8/8/8/1Q6 w - - 0 1").unwrap();
+        assert!(center > KNOWN_WIN);
+        assert!(edge > center);
+
+        let center = evaluate("8/8/8/3K4/8/3k4/8/1r6 b - - 0 1").unwrap();
+        let edge = evaluate("3K4/8/3k4/8/8/8/8/1r6 b - - 0 1").unwrap();
+        assert!(center < -KNOWN_WIN);
+        assert!(edge < center);
+
+        // Same colored bishops can't force checkmate
+        assert!(evaluate("8/8/8/3k4/8/8/8/KB1B4 w - - 0 1").unwrap() < KNOWN_WIN);
+        assert_eq!(evaluate("8/8/8/3k4/8/8/8/KNN5 w - - 0 1"), Some(0));
+        assert_eq!(evaluate("8/8/8/3k4/8/8/8/KN6 w - - 0 1"), None);
+        assert_eq!(evaluate("8/8/8/3k4/8/8/p7/KQ6 w - - 0 1"), None);
+    }
+
+    #[test]
+    fn kbnk() {
+        // The light squared bishop can only checkmate in the corners a8 and h1
+        let wrong_corner = evaluate("8/8/8/8/8/8/8/k1KBN3 w - - 0 1").unwrap();
+        let right_corner = evaluate("k7/8/8/8/8/8/8/2KBN3 w - - 0 1").unwrap();
+        assert!(wrong_corner > KNOWN_WIN);
+        assert!(right_corner > wrong_corner);
+    }
+}
@@ -1,17 +1,21 @@
 use crate::{
+    board::EMPTY,
+    move_list::move_vec::MoveVec,
     piece::{
-        BLACK_BISHOP, BLACK_KNIGHT, BLACK_PAWN, BLACK_QUEEN, BLACK_ROOK, WHITE_BISHOP,
+        BLACK_BISHOP, BLACK_KNIGHT, BLACK_PAWN, BLACK_QUEEN, BLACK_ROOK, KING, PAWN, WHITE_BISHOP,
         WHITE_KNIGHT, WHITE_PAWN, WHITE_QUEEN, WHITE_ROOK,
     },
+    position::{
+        psqt::{EvalState, MATERIAL},
+        transposition::{Bound, TranspositionTable},
+    },
     r#move::Move,
     side::{Side, WHITE},
+    square::Square,
+    syzygy::{Tablebases, Wdl, TABLEBASE_WIN},
     Position,
 };
-use std::{
-    cmp::{max, min},
-    fmt::Display,
-    time::Instant,
-};
+use std::{cmp::max, fmt::Display, time::Instant};
 
 #[derive(Debug, PartialEq)]
 pub enum DrawReason {
@@ -36,6 +40,7 @@ impl Display for DrawReason {
 pub enum Evaluation {
     Win(Side),
     Draw(DrawReason),
+    /// Score relative to the side to move
     None(i32),
 }
 
@@ -47,117 +52,739 @@ impl Evaluation {
         }
     }
 
-    pub fn to_score(&self) -> i32 {
+    /// Returns the score from the perspective of the given side
+    pub fn to_score(&self, side: Side) -> i32 {
         match self {
-            Self::Win(side) => (1 - 2 * side.0 as i32) * i32::MAX,
+            Self::Win(winner) if *winner == side => i32::MAX,
+            Self::Win(_) => -i32::MAX,
             Self::Draw(_) => 0,
             Self::None(score) => *score,
         }
     }
 }
 
-#[derive(Debug, Default)]
-struct Stats {
+/// Maximum number of plies from the root, including the quiescence search
+const MAX_PLY: usize = 128;
+
+/// Scores beyond this are mates or tablebase wins, which must not be combined with any margins
+const DECISIVE_SCORE: i32 = TABLEBASE_WIN / 2;
+
+/// Reverse futility pruning assumes the static evaluation can't drop by more than this per ply
+const REVERSE_FUTILITY_MARGIN: i32 = 80;
+const REVERSE_FUTILITY_DEPTH: u8 = 6;
+
+/// Quiet moves are pruned when even this much gain can't raise the score above alpha, indexed by
+/// the remaining depth
+const FUTILITY_MARGINS: [i32; 4] = [0, 120, 220, 320];
+
+/// Nodes this far below alpha are only checked with a quiescence search, indexed by the remaining
+/// depth
+const RAZORING_MARGINS: [i32; 3] = [0, 250, 400];
+
+/// Half-width of the first aspiration window around the score of the previous iteration, which
+/// doubles whenever the score falls outside of it
+const ASPIRATION_WINDOW: i32 = 25;
+const ASPIRATION_DEPTH: u8 = 4;
+
+/// A move is singular if all other moves score at least this much per ply below it
+const SINGULAR_MARGIN: i32 = 2;
+const SINGULAR_DEPTH: u8 = 6;
+
+/// Switches for the selective parts of the search, so that the contribution of each of them can
+/// be measured
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SearchOptions {
+    pub null_move: bool,
+    pub late_move_reductions: bool,
+    pub futility: bool,
+    pub reverse_futility: bool,
+    pub razoring: bool,
+    /// Extends moves that give check
+    pub check_extensions: bool,
+    /// Extends captures of the piece that just captured
+    pub recapture_extensions: bool,
+    /// Extends the move from the transposition table if all others are clearly worse
+    pub singular_extensions: bool,
+}
+
+impl Default for SearchOptions {
+    fn default() -> SearchOptions {
+        SearchOptions {
+            null_move: true,
+            late_move_reductions: true,
+            futility: true,
+            reverse_futility: true,
+            razoring: true,
+            check_extensions: true,
+            recapture_extensions: true,
+            singular_extensions: true,
+        }
+    }
+}
+
+struct Stats<'a> {
     nodes: u64,
+    /// The search is stopped once it visited more nodes than this
+    node_limit: Option<u64>,
+    options: SearchOptions,
+    /// Quiet moves that caused a beta cutoff, two for each ply
+    killers: [[Option<Move>; 2]; MAX_PLY],
+    /// Principal variation of the previous iteration, starting at the root
+    pv: Vec<Move>,
+    pv_table: PvTable,
+    transpositions: &'a mut TranspositionTable,
+    /// Depth of the current iteration, which is also the number of plies any path may be
+    /// extended by in total
+    root_depth: u8,
+    /// Number of plies the path to each ply was extended by
+    extensions: [u8; MAX_PLY],
+    /// Square of the piece captured by the move at each ply, for recapture extensions
+    captures: [Option<Square>; MAX_PLY],
+    /// Move skipped at each ply while checking whether the move from the transposition table is
+    /// singular
+    excluded: [Option<Move>; MAX_PLY],
 }
 
-impl Position {
-    pub fn alphabeta(&mut self, depth: u8, alpha: i32, beta: i32) -> Move {
-        let mut stats = Stats::default();
+/// Triangular table of the principal variations below each ply. The line at a ply starts with
+/// the best move at that ply, followed by the line of the next ply at the time it was chosen.
+#[derive(Debug)]
+struct PvTable {
+    lines: Vec<Vec<Move>>,
+}
+
+impl PvTable {
+    fn new() -> PvTable {
+        PvTable {
+            lines: (0..MAX_PLY)
+                .map(|ply| Vec::with_capacity(MAX_PLY - ply))
+                .collect(),
+        }
+    }
+
+    fn clear(&mut self, ply: usize) {
+        self.lines[ply].clear();
+    }
+
+    /// Replaces the line at the ply by the move and the line of the next ply
+    fn update(&mut self, ply: usize, m: Move) {
+        let (line, rest) = self.lines[ply..].split_first_mut().unwrap();
+        line.clear();
+        line.push(m);
+        if let Some(next) = rest.first() {
+            line.extend_from_slice(next);
+        }
+    }
+
+    fn line(&self, ply: usize) -> &[Move] {
+        &self.lines[ply]
+    }
+}
+
+impl Stats<'_> {
+    fn new(options: SearchOptions, transpositions: &mut TranspositionTable) -> Stats<'_> {
+        Stats {
+            nodes: 0,
+            node_limit: None,
+            options,
+            killers: [[None; 2]; MAX_PLY],
+            pv: vec![],
+            pv_table: PvTable::new(),
+            transpositions,
+            root_depth: 0,
+            extensions: [0; MAX_PLY],
+            captures: [None; MAX_PLY],
+            excluded: [None; MAX_PLY],
+        }
+    }
 
+    fn is_stopped(&self) -> bool {
+        self.node_limit.is_some_and(|limit| self.nodes > limit)
+    }
+
+    fn store_killer(&mut self, ply: usize, m: Move) {
+        let killers = &mut self.killers[ply];
+        if killers[0] != Some(m) {
+            killers[1] = killers[0];
+            killers[0] = Some(m);
+        }
+    }
+}
+
+/// Converts a mate score relative to the root into one relative to the node at the ply, so that
+/// it stays valid when the position is reached at another ply
+fn score_to_tt(score: i32, ply: u8) -> i32 {
+    if score >= DECISIVE_SCORE {
+        score + ply as i32
+    } else if score <= -DECISIVE_SCORE {
+        score - ply as i32
+    } else {
+        score
+    }
+}
+
+fn score_from_tt(score: i32, ply: u8) -> i32 {
+    if score >= DECISIVE_SCORE {
+        score - ply as i32
+    } else if score <= -DECISIVE_SCORE {
+        score + ply as i32
+    } else {
+        score
+    }
+}
+
+/// Reduction for quiet moves that are searched late, growing with both the depth and the number
+/// of moves searched before
+fn late_move_reduction(depth: u8, move_index: usize) -> u8 {
+    (0.75 + (depth as f64).ln() * (move_index as f64).ln() / 2.25) as u8
+}
+
+fn is_quiet(m: Move) -> bool {
+    !m.is_capture() && m.promote_to().is_none()
+}
+
+impl Position {
+    /// Searches with iterative deepening up to the given depth and prints the info for each
+    /// iteration
+    pub fn alphabeta(
+        &mut self,
+        depth: u8,
+        options: &SearchOptions,
+        tablebases: Option<&Tablebases>,
+        transpositions: &mut TranspositionTable,
+    ) -> Move {
         let start = Instant::now();
-        let (score, line) = self.alphabeta_with_stats(depth, alpha, beta, &mut stats);
-        let duration = start.elapsed().as_millis();
-
-        let nodes = stats.nodes;
-        let nps = (stats.nodes as f64 / (duration as f64 / 1000f64)) as u64;
-
-        println!(
-            "info depth {depth} time {duration} nodes {nodes} nps {nps} score cp {score} pv {}",
-            line.iter()
-                .rev()
-                .map(|m| format!("{}", m))
-                .collect::<Vec<String>>()
-                .join(" ")
+        let best = self.iterative_deepening(
+            depth,
+            None,
+            options,
+            tablebases,
+            transpositions,
+            |depth, score, nodes, line| {
+                let duration = start.elapsed().as_millis();
+                let nps = (nodes as f64 / (duration as f64 / 1000f64)) as u64;
+
+                println!(
+                    "info depth {depth} time {duration} nodes {nodes} nps {nps} score cp {score} pv {}",
+                    line.iter()
+                        .map(|m| format!("{}", m))
+                        .collect::<Vec<String>>()
+                        .join(" ")
+                );
+            },
         );
 
-        *line.last().unwrap()
+        best.unwrap().1
+    }
+
+    /// Searches with iterative deepening up to the given depth without printing anything. Once
+    /// the node limit is exceeded, the result of the last completed iteration is used. Returns the
+    /// score relative to the side to move and the best move, or `None` if there are no legal moves.
+    pub fn search(
+        &mut self,
+        depth: u8,
+        node_limit: Option<u64>,
+        transpositions: &mut TranspositionTable,
+    ) -> Option<(i32, Move)> {
+        self.iterative_deepening(
+            depth,
+            node_limit,
+            &SearchOptions::default(),
+            None,
+            transpositions,
+            |_, _, _, _| {},
+        )
     }
 
+    fn iterative_deepening(
+        &mut self,
+        depth: u8,
+        node_limit: Option<u64>,
+        options: &SearchOptions,
+        tablebases: Option<&Tablebases>,
+        transpositions: &mut TranspositionTable,
+        mut report: impl FnMut(u8, i32, u64, &[Move]),
+    ) -> Option<(i32, Move)> {
+        let mut stats = Stats::new(*options, transpositions);
+        let mut best = None;
+
+        for depth in 1..=depth.min(MAX_PLY as u8 - 1) {
+            let score =
+                self.aspiration_search(depth, best.map(|(score, _)| score), tablebases, &mut stats);
+            if stats.is_stopped() {
+                break;
+            }
+
+            let line = stats.pv_table.line(0).to_vec();
+            report(depth, score, stats.nodes, &line);
+            best = line.first().map(|&m| (score, m));
+            stats.pv = line;
+
+            // The first iteration always completes, so there is a move even for tiny limits
+            stats.node_limit = node_limit;
+            if stats.is_stopped() {
+                break;
+            }
+        }
+
+        best
+    }
+
+    /// Searches the root with a narrow window around the score of the previous iteration, as most
+    /// of the time the score doesn't change much. If the score falls outside of the window, the
+    /// window is widened on that side and the root is searched again.
+    fn aspiration_search(
+        &mut self,
+        depth: u8,
+        previous_score: Option<i32>,
+        tablebases: Option<&Tablebases>,
+        stats: &mut Stats,
+    ) -> i32 {
+        let (mut alpha, mut beta) = match previous_score {
+            Some(score) if depth >= ASPIRATION_DEPTH && score.abs() < DECISIVE_SCORE => {
+                (score - ASPIRATION_WINDOW, score + ASPIRATION_WINDOW)
+            }
+            _ => (-i32::MAX, i32::MAX),
+        };
+        let mut delta = ASPIRATION_WINDOW;
+        stats.root_depth = depth;
+
+        loop {
+            let score = self.alphabeta_with_stats(depth, 0, alpha, beta, true, tablebases, stats);
+            if stats.is_stopped() {
+                return score;
+            }
+
+            if score <= alpha && alpha > -i32::MAX {
+                delta *= 2;
+                alpha = if delta < DECISIVE_SCORE {
+                    max(score.saturating_sub(delta), -i32::MAX)
+                } else {
+                    -i32::MAX
+                };
+            } else if score >= beta && beta < i32::MAX {
+                delta *= 2;
+                beta = if delta < DECISIVE_SCORE {
+                    score.saturating_add(delta)
+                } else {
+                    i32::MAX
+                };
+            } else {
+                return score;
+            }
+        }
+    }
+
+    /// Returns the score relative to the side to move and stores the principal variation in the
+    /// PV table
+    #[allow(clippy::too_many_arguments)]
     fn alphabeta_with_stats(
         &mut self,
         depth: u8,
+        ply: u8,
         mut alpha: i32,
-        mut beta: i32,
+        beta: i32,
+        allow_null_move: bool,
+        tablebases: Option<&Tablebases>,
         stats: &mut Stats,
-    ) -> (i32, Vec<Move>) {
+    ) -> i32 {
+        stats.pv_table.clear(ply as usize);
+        if depth == 0 {
+            return self.quiescence(ply, alpha, beta, stats);
+        }
+
         stats.nodes += 1;
+        if stats.is_stopped() {
+            return 0;
+        }
 
+        let side_to_move = self.state.side_to_move;
         let (legal_moves, is_in_check) = self.legal_moves_vec();
 
         let evaluation = self.evaluate(legal_moves.len(), is_in_check);
-        if depth == 0 || evaluation.is_terminal() {
-            return (evaluation.to_score(), vec![]);
-        }
-
-        if self.state.side_to_move == WHITE {
-            let mut value = i32::MIN;
-            let mut best_line = vec![];
-
-            for m in legal_moves.iter() {
-                let state = self.state.clone();
-                let hash = self.hash;
-                let capture = self.make(*m);
-
-                let (move_value, mut line) =
-                    self.alphabeta_with_stats(depth - 1, alpha, beta, stats);
-                if move_value > value {
-                    value = move_value;
-                    best_line = {
-                        line.push(*m);
-                        line
-                    };
-                }
-                value = max(value, move_value);
-                alpha = max(alpha, value);
+        if let Evaluation::Win(_) = evaluation {
+            // Prefer checkmates that happen sooner
+            return evaluation.to_score(side_to_move) + ply as i32;
+        }
+        if evaluation.is_terminal() {
+            return evaluation.to_score(side_to_move);
+        }
 
-                self.unmake(*m, capture, &state, hash);
+        if let Some(score) = self.probe_tablebases(tablebases, ply) {
+            return score;
+        }
 
-                if value >= beta {
-                    break;
+        let static_eval = evaluation.to_score(side_to_move);
+        if ply as usize >= MAX_PLY - 1 {
+            return static_eval;
+        }
+
+        let options = stats.options;
+        let is_pv = alpha + 1 < beta;
+        let original_alpha = alpha;
+
+        // The result of an earlier search is only valid without the excluded move
+        let excluded = stats.excluded[ply as usize];
+        let tt_entry = match excluded {
+            Some(_) => None,
+            None => stats.transpositions.probe(self.hash),
+        };
+        if let Some(entry) = tt_entry {
+            let score = score_from_tt(entry.score, ply);
+            if !is_pv && entry.depth >= depth {
+                match entry.bound {
+                    Bound::Exact => return score,
+                    Bound::Lower if score >= beta => return score,
+                    Bound::Upper if score <= alpha => return score,
+                    _ => {}
                 }
             }
+        }
 
-            (value, best_line)
-        } else {
-            let mut value = i32::MAX;
-            let mut best_line = vec![];
-
-            for m in legal_moves.iter() {
-                let state = self.state.clone();
-                let hash = self.hash;
-                let capture = self.make(*m);
-
-                let (move_value, mut line) =
-                    self.alphabeta_with_stats(depth - 1, alpha, beta, stats);
-                if move_value < value {
-                    value = move_value;
-                    best_line = {
-                        line.push(*m);
-                        line
-                    };
+        // Only nodes that are expected to fail high or low are pruned, never the principal
+        // variation
+        let can_prune =
+            !is_pv && !is_in_check && excluded.is_none() && static_eval.abs() < DECISIVE_SCORE;
+
+        if can_prune
+            && options.reverse_futility
+            && depth <= REVERSE_FUTILITY_DEPTH
+            && static_eval - REVERSE_FUTILITY_MARGIN * depth as i32 >= beta
+        {
+            return static_eval;
+        }
+
+        if can_prune
+            && options.razoring
+            && (depth as usize) < RAZORING_MARGINS.len()
+            && static_eval + RAZORING_MARGINS[depth as usize] < alpha
+        {
+            let score = self.quiescence(ply, alpha, beta, stats);
+            if score <= alpha {
+                return score;
+            }
+        }
+
+        // Passing the turn is usually bad, so if the opponent can't even punish that, this node
+        // is going to fail high anyway. This isn't true in zugzwang, which mostly happens when a
+        // side has nothing but pawns left.
+        if can_prune
+            && options.null_move
+            && allow_null_move
+            && depth >= 3
+            && static_eval >= beta
+            && beta.abs() < DECISIVE_SCORE
+            && self.has_non_pawn_material(side_to_move)
+        {
+            let reduction = 3 + depth / 6;
+            let state = self.state.clone();
+            let hash = self.hash;
+            stats.extensions[ply as usize + 1] = stats.extensions[ply as usize];
+            stats.captures[ply as usize] = None;
+            self.make_null();
+            let score = self.alphabeta_with_stats(
+                depth.saturating_sub(reduction + 1),
+                ply + 1,
+                -beta,
+                -beta + 1,
+                false,
+                tablebases,
+                stats,
+            );
+            self.unmake_null(&state, hash);
+
+            if -score >= beta && !stats.is_stopped() {
+                return beta;
+            }
+        }
+
+        let futility_pruning = can_prune
+            && options.futility
+            && (depth as usize) < FUTILITY_MARGINS.len()
+            && static_eval + FUTILITY_MARGINS[depth as usize] <= alpha;
+
+        // The move from the transposition table is singular if searching all other moves with a
+        // reduced depth can't come close to its score, in which case it's the only good move and
+        // deserves a deeper search
+        let mut singular_move = None;
+        if let Some(entry) = tt_entry {
+            let score = score_from_tt(entry.score, ply);
+            if options.singular_extensions
+                && ply > 0
+                && depth >= SINGULAR_DEPTH
+                && entry.depth + 3 >= depth
+                && entry.bound != Bound::Upper
+                && score.abs() < DECISIVE_SCORE
+                && entry.best_move.is_some()
+            {
+                let singular_beta = score - SINGULAR_MARGIN * depth as i32;
+                stats.excluded[ply as usize] = entry.best_move;
+                let value = self.alphabeta_with_stats(
+                    (depth - 1) / 2,
+                    ply,
+                    singular_beta - 1,
+                    singular_beta,
+                    false,
+                    tablebases,
+                    stats,
+                );
+                stats.excluded[ply as usize] = None;
+
+                if value < singular_beta {
+                    singular_move = entry.best_move;
                 }
-                beta = min(beta, value);
+            }
+        }
 
-                self.unmake(*m, capture, &state, hash);
+        let tt_move = tt_entry.and_then(|entry| entry.best_move);
+        let mut value = -i32::MAX;
+        let mut best_move = None;
+        let mut searched = 0;
 
-                if value <= alpha {
-                    break;
+        for m in self.order_moves(&legal_moves, ply as usize, tt_move, stats) {
+            if Some(m) == excluded {
+                continue;
+            }
+
+            let state = self.state.clone();
+            let hash = self.hash;
+            let capture = self.make(m);
+            let gives_check = self.is_in_check();
+
+            if futility_pruning && searched > 0 && is_quiet(m) && !gives_check {
+                self.unmake(m, capture, &state, hash);
+                continue;
+            }
+
+            let reduction = if options.late_move_reductions
+                && depth >= 3
+                && searched >= 3
+                && is_quiet(m)
+                && !is_in_check
+                && !gives_check
+                && !stats.killers[ply as usize].contains(&Some(m))
+            {
+                late_move_reduction(depth, searched).min(depth - 2)
+            } else {
+                0
+            };
+
+            // Forcing moves are searched deeper, as long as the path wasn't extended too often
+            // already
+            let extensions = stats.extensions[ply as usize];
+            let is_recapture = ply > 0 && stats.captures[ply as usize - 1] == Some(m.to());
+            let extension = if extensions >= stats.root_depth {
+                0
+            } else if Some(m) == singular_move
+                || options.check_extensions && gives_check
+                || options.recapture_extensions && m.is_capture() && is_recapture
+            {
+                1
+            } else {
+                0
+            };
+            stats.extensions[ply as usize + 1] = extensions + extension;
+            stats.captures[ply as usize] = m.is_capture().then(|| m.to());
+            let new_depth = depth - 1 + extension;
+
+            // Only the first move is searched with the full window. All others are expected to be
+            // worse, which is cheaper to prove with a zero window, and only if that fails they
+            // need to be searched again.
+            let mut move_value = if searched == 0 {
+                -self.alphabeta_with_stats(
+                    new_depth,
+                    ply + 1,
+                    -beta,
+                    -alpha,
+                    true,
+                    tablebases,
+                    stats,
+                )
+            } else {
+                -self.alphabeta_with_stats(
+                    new_depth - reduction,
+                    ply + 1,
+                    -alpha - 1,
+                    -alpha,
+                    true,
+                    tablebases,
+                    stats,
+                )
+            };
+            if searched > 0 && move_value > alpha && reduction > 0 {
+                move_value = -self.alphabeta_with_stats(
+                    new_depth,
+                    ply + 1,
+                    -alpha - 1,
+                    -alpha,
+                    true,
+                    tablebases,
+                    stats,
+                );
+            }
+            if searched > 0 && move_value > alpha && move_value < beta {
+                move_value = -self.alphabeta_with_stats(
+                    new_depth,
+                    ply + 1,
+                    -beta,
+                    -alpha,
+                    true,
+                    tablebases,
+                    stats,
+                );
+            }
+            searched += 1;
+
+            if move_value > value {
+                value = move_value;
+                best_move = Some(m);
+                stats.pv_table.update(ply as usize, m);
+            }
+            alpha = max(alpha, value);
+
+            self.unmake(m, capture, &state, hash);
+
+            if value >= beta {
+                if is_quiet(m) {
+                    stats.store_killer(ply as usize, m);
                 }
+                break;
             }
+        }
 
-            (value, best_line)
+        if excluded.is_none() && !stats.is_stopped() {
+            let bound = if value >= beta {
+                Bound::Lower
+            } else if value > original_alpha {
+                Bound::Exact
+            } else {
+                Bound::Upper
+            };
+            stats
+                .transpositions
+                .store(self.hash, best_move, score_to_tt(value, ply), depth, bound);
         }
+
+        value
+    }
+
+    /// Only searches captures and promotions until the position is quiet, so that the static
+    /// evaluation isn't used in the middle of an exchange
+    fn quiescence(&mut self, ply: u8, mut alpha: i32, beta: i32, stats: &mut Stats) -> i32 {
+        stats.nodes += 1;
+        if stats.is_stopped() {
+            return 0;
+        }
+
+        let side_to_move = self.state.side_to_move;
+        let (legal_moves, is_in_check) = self.legal_moves_vec();
+
+        let evaluation = self.evaluate(legal_moves.len(), is_in_check);
+        if let Evaluation::Win(_) = evaluation {
+            return evaluation.to_score(side_to_move) + ply as i32;
+        }
+        let static_eval = evaluation.to_score(side_to_move);
+        if evaluation.is_terminal() || ply as usize >= MAX_PLY - 1 {
+            return static_eval;
+        }
+
+        // Unless in check, the side to move can decide not to capture anything
+        let mut value = -i32::MAX;
+        if !is_in_check {
+            if static_eval >= beta {
+                return static_eval;
+            }
+            alpha = max(alpha, static_eval);
+            value = static_eval;
+        }
+
+        for m in self.order_moves(&legal_moves, ply as usize, None, stats) {
+            if !is_in_check && is_quiet(m) {
+                continue;
+            }
+
+            let state = self.state.clone();
+            let hash = self.hash;
+            let capture = self.make(m);
+            let move_value = -self.quiescence(ply + 1, -beta, -alpha, stats);
+            self.unmake(m, capture, &state, hash);
+
+            value = max(value, move_value);
+            alpha = max(alpha, value);
+            if value >= beta {
+                break;
+            }
+        }
+
+        value
+    }
+
+    /// Sorts the moves so that the ones most likely to be best are searched first: the move from
+    /// the transposition table and the one from the previous principal variation, then captures
+    /// of the most valuable pieces with the least valuable pieces, promotions, killer moves and
+    /// finally all other quiet moves
+    fn order_moves(
+        &self,
+        moves: &MoveVec,
+        ply: usize,
+        tt_move: Option<Move>,
+        stats: &Stats,
+    ) -> Vec<Move> {
+        let pv_move = stats.pv.get(ply).copied();
+        let killers = stats.killers[ply];
+
+        let mut scored: Vec<(i32, Move)> = moves
+            .iter()
+            .map(|&m| {
+                let score = if Some(m) == tt_move {
+                    2_000_000
+                } else if Some(m) == pv_move {
+                    1_000_000
+                } else if m.is_capture() {
+                    let victim = if m.is_en_passant_capture() {
+                        PAWN
+                    } else {
+                        self.at(m.to()).kind()
+                    };
+                    let attacker = self.at(m.from()).kind();
+                    100_000 + 10 * MATERIAL[victim.0 as usize] - MATERIAL[attacker.0 as usize]
+                } else if m.promote_to().is_some() {
+                    90_000
+                } else if killers[0] == Some(m) {
+                    80_000
+                } else if killers[1] == Some(m) {
+                    79_000
+                } else {
+                    0
+                };
+                (score, m)
+            })
+            .collect();
+
+        scored.sort_by_key(|&(score, _)| -score);
+        scored.into_iter().map(|(_, m)| m).collect()
+    }
+
+    /// Returns whether the side has any pieces besides its king and pawns
+    fn has_non_pawn_material(&self, side: Side) -> bool {
+        let king_and_pawns = self.piece(KING.to_piece(side)) | self.piece(PAWN.to_piece(side));
+        self.side(side) & !king_and_pawns != EMPTY
+    }
+
+    /// Returns the score according to the WDL tables. Since these don't know about the halfmove
+    /// clock, they are only probed right after a capture or pawn move, and never at the root.
+    fn probe_tablebases(&mut self, tablebases: Option<&Tablebases>, ply: u8) -> Option<i32> {
+        let tablebases = tablebases?;
+        if ply == 0 || self.state.halfmove_clock != 0 || !tablebases.can_probe(self) {
+            return None;
+        }
+
+        // Prefer converting to a won endgame as soon as possible
+        let score = match tablebases.probe_wdl(self)? {
+            Wdl::Win => TABLEBASE_WIN - ply as i32,
+            Wdl::Loss => -TABLEBASE_WIN + ply as i32,
+            wdl => wdl.to_score(),
+        };
+        Some(score)
     }
 
     pub fn evaluate(&self, legal_move_count: usize, is_in_check: bool) -> Evaluation {
@@ -165,7 +792,7 @@ impl Position {
             // The side to move has no legal moves left
             if is_in_check {
                 // Checkmate
-                return Evaluation::Win(self.state.side_to_move);
+                return Evaluation::Win(!self.state.side_to_move);
             }
 
             // Stalemate
@@ -177,6 +804,65 @@ impl Position {
             return Evaluation::Draw(DrawReason::FiftyMoveRule);
         }
 
+        debug_assert_eq!(
+            self.eval_state,
+            EvalState::new(&self.params, &self.pieces),
+            "incrementally updated evaluation state differs from a full recompute"
+        );
+
+        // Material is insufficient only if both sides have at most a single minor piece
+        let [white_material, black_material] = self.eval_state.material;
+        if white_material <= 300 && black_material <= 300 && self.is_insufficient_material() {
+            return Evaluation::Draw(DrawReason::InsufficientMaterial);
+        }
+
+        // Threefold repetition
+        if let Some(prev_hashes) = &self.state.prev_hashes {
+            let num_hashes = prev_hashes.len();
+            if num_hashes >= 4 {
+                let mut count = 0;
+                let mut index = num_hashes - 2;
+                loop {
+                    if prev_hashes[index] == self.hash {
+                        count += 1;
+                    }
+                    if count >= 2 {
+                        return Evaluation::Draw(DrawReason::ThreefoldRepetition);
+                    }
+                    if index < 2 {
+                        break;
+                    }
+                    index -= 2;
+                }
+            }
+        }
+
+        // Known endgames are still handled by hand, as networks tend to be bad at converting them
+        let score = match (self.evaluate_endgame(), self.nnue_evaluate()) {
+            (Some(score), _) => score,
+            (None, Some(score)) => return Evaluation::None(score),
+            (None, None) => {
+                let score =
+                    self.eval_state.score + self.evaluate_pawns() + self.evaluate_activity();
+                score.taper(self.eval_state.phase)
+            }
+        };
+
+        Evaluation::None(if self.state.side_to_move == WHITE {
+            score
+        } else {
+            -score
+        })
+    }
+
+    /// Returns the score relative to the side to move according to the network, if there is one
+    pub fn nnue_evaluate(&self) -> Option<i32> {
+        self.nnue
+            .as_ref()
+            .map(|nnue| nnue.evaluate(self.state.side_to_move))
+    }
+
+    fn is_insufficient_material(&self) -> bool {
         let white_queens = self.piece(WHITE_QUEEN).occupied();
         let white_rooks = self.piece(WHITE_ROOK).occupied();
         let white_bishop_board = self.piece(WHITE_BISHOP);
@@ -191,34 +877,25 @@ impl Position {
         let black_knights = self.piece(BLACK_KNIGHT).occupied();
         let black_pawns = self.piece(BLACK_PAWN).occupied();
 
-        let score_white = (white_queens * 900
-            + white_rooks * 500
-            + white_bishops * 300
-            + white_knights * 300
-            + white_pawns * 100) as i32;
-        let score_black: i32 = (black_queens * 900
-            + black_rooks * 500
-            + black_bishops * 300
-            + black_knights * 300
-            + black_pawns * 100) as i32;
+        let [score_white, score_black] = self.eval_state.material;
 
         // Insufficient material (king vs. king)
         if score_white == 0 && score_black == 0 {
-            return Evaluation::Draw(DrawReason::InsufficientMaterial);
+            return true;
         }
 
         // Insufficient material (king vs. king & bishop)
         let black_has_only_bishops =
             black_pawns == 0 && black_knights == 0 && black_rooks == 0 && black_queens == 0;
         if score_white == 0 && black_has_only_bishops && black_bishops == 1 {
-            return Evaluation::Draw(DrawReason::InsufficientMaterial);
+            return true;
         }
 
         // Insufficient material (king & bishop vs. king)
         let white_has_only_bishops =
             white_pawns == 0 && white_knights == 0 && white_rooks == 0 && white_queens == 0;
         if score_black == 0 && white_has_only_bishops && white_bishops == 1 {
-            return Evaluation::Draw(DrawReason::InsufficientMaterial);
+            return true;
         }
 
         // Insufficient material (king vs. king & knight)
@@ -229,7 +906,7 @@ impl Position {
             && black_rooks == 0
             && black_queens == 0
         {
-            return Evaluation::Draw(DrawReason::InsufficientMaterial);
+            return true;
         }
 
         // Insufficient material (king & knight vs. king)
@@ -240,7 +917,7 @@ impl Position {
             && white_rooks == 0
             && white_queens == 0
         {
-            return Evaluation::Draw(DrawReason::InsufficientMaterial);
+            return true;
         }
 
         // Insufficient material (king & bishop vs. king & bishop on same colors)
@@ -256,30 +933,224 @@ impl Position {
                 is_white_bishop_on_white_square == is_black_bishop_on_white_square
             }
         {
-            return Evaluation::Draw(DrawReason::InsufficientMaterial);
+            return true;
         }
 
-        // Threefold repetition
-        if let Some(prev_hashes) = &self.state.prev_hashes {
-            let num_hashes = prev_hashes.len();
-            if num_hashes >= 4 {
-                let mut count = 0;
-                let mut index = num_hashes - 2;
-                loop {
-                    if prev_hashes[index] == self.hash {
-                        count += 1;
-                    }
-                    if count >= 2 {
-                        return Evaluation::Draw(DrawReason::ThreefoldRepetition);
-                    }
-                    if index < 2 {
-                        break;
-                    }
-                    index -= 2;
-                }
-            }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        position::{
+            evaluate::{
+                score_from_tt, score_to_tt, Evaluation, SearchOptions, Stats, DECISIVE_SCORE,
+            },
+            TranspositionTable,
+        },
+        side::{BLACK, WHITE},
+        syzygy::TABLEBASE_WIN,
+        Position, STARTING_POSITION_FEN,
+    };
+
+    fn evaluate(fen: &str) -> Evaluation {
+        let position = Position::from_fen(fen);
+        let (legal_moves, is_in_check) = position.legal_moves_vec();
+        position.evaluate(legal_moves.len(), is_in_check)
+    }
+
+    #[test]
+    fn side_relative() {
+        assert_eq!(evaluate(STARTING_POSITION_FEN), Evaluation::None(0));
+
+        // White is up a knight
+        let white =
+            evaluate("rnbqkb1r/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1").to_score(WHITE);
+        let black =
+            evaluate("rnbqkb1r/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 0 1").to_score(WHITE);
+        assert!(white > 250);
+        assert_eq!(white, -black);
+    }
+
+    #[test]
+    fn checkmate() {
+        let evaluation = evaluate("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3");
+        assert_eq!(evaluation, Evaluation::Win(BLACK));
+        assert_eq!(evaluation.to_score(WHITE), -i32::MAX);
+    }
+
+    #[test]
+    fn finds_mate() {
+        let mut position = Position::from_fen("6k1/5ppp/8/8/8/8/5PPP/R5K1 w - - 0 1");
+        let best_move = position.alphabeta(
+            3,
+            &SearchOptions::default(),
+            None,
+            &mut TranspositionTable::default(),
+        );
+        assert_eq!(best_move.to_string(), "a1a8");
+    }
+
+    #[test]
+    fn selective_search() {
+        let all = SearchOptions::default();
+        let none = SearchOptions {
+            null_move: false,
+            late_move_reductions: false,
+            futility: false,
+            reverse_futility: false,
+            razoring: false,
+            check_extensions: false,
+            recapture_extensions: false,
+            singular_extensions: false,
+        };
+        let variants = [
+            SearchOptions {
+                null_move: false,
+                ..all
+            },
+            SearchOptions {
+                late_move_reductions: false,
+                ..all
+            },
+            SearchOptions {
+                futility: false,
+                reverse_futility: false,
+                razoring: false,
+                ..all
+            },
+            none,
+            all,
+        ];
+
+        // Every combination still needs to find the mate
+        for options in variants {
+            let mut position = Position::from_fen("6k1/5ppp/8/8/8/8/5PPP/R5K1 w - - 0 1");
+            let best_move =
+                position.alphabeta(5, &options, None, &mut TranspositionTable::default());
+            assert_eq!(best_move.to_string(), "a1a8");
+        }
+
+        let fen = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+        let nodes = |options: SearchOptions| {
+            let mut transpositions = TranspositionTable::default();
+            let mut stats = Stats::new(options, &mut transpositions);
+            Position::from_fen(fen).alphabeta_with_stats(
+                5,
+                0,
+                -i32::MAX,
+                i32::MAX,
+                true,
+                None,
+                &mut stats,
+            );
+            stats.nodes
+        };
+        assert!(nodes(all) < nodes(none));
+    }
+
+    #[test]
+    fn aspiration_windows() {
+        let fen = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+        let options = SearchOptions {
+            null_move: false,
+            late_move_reductions: false,
+            futility: false,
+            reverse_futility: false,
+            razoring: false,
+            check_extensions: false,
+            recapture_extensions: false,
+            singular_extensions: false,
+        };
+
+        let mut position = Position::from_fen(fen);
+        let mut transpositions = TranspositionTable::default();
+        let mut stats = Stats::new(options, &mut transpositions);
+        let score = position.aspiration_search(4, None, None, &mut stats);
+
+        // Without pruning, the score doesn't depend on the window, even if it first falls outside
+        for previous_score in [score - 300, score - 20, score + 20, score + 300] {
+            let mut transpositions = TranspositionTable::default();
+            let mut stats = Stats::new(options, &mut transpositions);
+            assert_eq!(
+                position.aspiration_search(4, Some(previous_score), None, &mut stats),
+                score
+            );
         }
+    }
 
-        Evaluation::None(score_white - score_black)
+    #[test]
+    fn aspiration_fail_low_to_mate() {
+        // Black can only push the pawn, after which Qf8 mates
+        let mut position = Position::from_fen("7k/p4Q2/6K1/8/8/8/8/8 b - - 0 1");
+        let mut transpositions = TranspositionTable::default();
+        let mut stats = Stats::new(SearchOptions::default(), &mut transpositions);
+        let score = position.aspiration_search(4, Some(0), None, &mut stats);
+        assert!(score < -DECISIVE_SCORE, "{score}");
+    }
+
+    #[test]
+    fn principal_variation() {
+        let mut position = Position::from_fen(STARTING_POSITION_FEN);
+        let mut transpositions = TranspositionTable::default();
+        let mut stats = Stats::new(SearchOptions::default(), &mut transpositions);
+        position.aspiration_search(5, None, None, &mut stats);
+
+        let line = stats.pv_table.line(0).to_vec();
+        assert!(line.len() >= 5);
+        for m in line {
+            let (legal_moves, _) = position.legal_moves_vec();
+            assert!(legal_moves.iter().any(|&legal| legal == m), "{m}");
+            position.make(m);
+        }
+    }
+
+    #[test]
+    fn extensions() {
+        // Smothered mate in four, which is only found at this depth if the checks are extended
+        let fen = "r6k/6pp/8/6N1/2Q5/8/8/6K1 w - - 0 1";
+        let without = SearchOptions {
+            check_extensions: false,
+            recapture_extensions: false,
+            singular_extensions: false,
+            ..SearchOptions::default()
+        };
+
+        let search = |options: &SearchOptions| {
+            Position::from_fen(fen)
+                .iterative_deepening(
+                    6,
+                    None,
+                    options,
+                    None,
+                    &mut TranspositionTable::default(),
+                    |_, _, _, _| {},
+                )
+                .unwrap()
+        };
+        let (score, best_move) = search(&SearchOptions::default());
+        assert!(score >= DECISIVE_SCORE);
+        assert_eq!(best_move.to_string(), "g5f7");
+        assert!(search(&without).0 < DECISIVE_SCORE);
+    }
+
+    #[test]
+    fn mate_scores_in_transposition_table() {
+        for ply in [0, 3, 17] {
+            for score in [
+                -i32::MAX + 40,
+                -TABLEBASE_WIN + 5,
+                -300,
+                0,
+                250,
+                TABLEBASE_WIN - 9,
+            ] {
+                assert_eq!(score_from_tt(score_to_tt(score, ply), ply), score);
+            }
+        }
+        // A mate five plies from the root found at ply three is a mate in two plies when the same
+        // position is reached at the root
+        assert_eq!(score_from_tt(score_to_tt(i32::MAX - 5, 3), 0), i32::MAX - 2);
     }
 }
@@ -134,7 +134,10 @@ mod tests {
             NULL_PIECE, WHITE_BISHOP, WHITE_KING, WHITE_KNIGHT, WHITE_PAWN, WHITE_QUEEN,
             WHITE_ROOK,
         },
-        position::{Position, State},
+        position::{
+            psqt::{EvalState, Score},
+            EvalParams, Position, State,
+        },
         side::WHITE,
         STARTING_POSITION_FEN,
     };
@@ -235,7 +238,15 @@ mod tests {
                     fullmove_number: 1,
                     prev_hashes: None
                 },
-                hash: 1307476362392126559
+                hash: 1307476362392126559,
+                eval_state: EvalState {
+                    score: Score(0, 0),
+                    phase: 24,
+                    material: [3900, 3900],
+                    pawn_key: 17140193042564052451
+                },
+                params: EvalParams::shared_default(),
+                nnue: None
             }
         );
     }
@@ -1,15 +1,35 @@
+#[cfg(all(
+    target_arch = "x86_64",
+    not(any(feature = "pext", feature = "kogge-stone"))
+))]
+use crate::square::{pext_available, Pext};
 use crate::{
-    board::{Board, EMPTY, NOT_FILE_A, NOT_FILE_H, RANK_4, RANK_5},
+    board::{Board, EMPTY, END_RANKS, NOT_FILE_A, NOT_FILE_H, RANK_4, RANK_5},
     castle::{KING_SIDE, QUEEN_SIDE},
-    move_list::{move_vec::MoveVec, MoveAdder},
+    move_list::{move_counter::MoveCounter, move_vec::MoveVec, MoveAdder},
     piece::{BISHOP, KING, KNIGHT, PAWN, QUEEN, ROOK},
     side::{Side, WHITE},
-    square::{Square, C1, C8, E1, E8, G1, G8},
+    square::{DefaultAttacks, SliderAttacks, Square, C1, C8, E1, E8, G1, G8},
     Position,
 };
 
+/// Where the pieces of the side to move may go, taking checks and pins into account
+struct MoveMasks {
+    king_sq: Square,
+    checkers: Board,
+    pinned: Board,
+    pinners: Board,
+    /// Squares attacked by the opponent, with the king removed so that it can't step back along
+    /// the line of a slider
+    attacked: Board,
+    capture_mask: Board,
+    push_mask: Board,
+    king_capture_mask: Board,
+    king_push_mask: Board,
+}
+
 impl Position {
-    pub fn legal_moves<L: MoveAdder>(&self, list: &mut L) -> bool {
+    fn move_masks(&self) -> MoveMasks {
         let side_to_move = self.state.side_to_move;
         let kings = self.piece(KING.to_piece(side_to_move));
 
@@ -61,17 +81,11 @@ impl Position {
         // We always need legal king moves
         let occupied_without_king = occupied & !kings;
 
-        let mut attacked = opponent_kings.to_square().king_moves()
+        let attacked = opponent_kings.to_square().king_moves()
             | straight_attackers.straight_attacks(occupied_without_king)
             | diagonal_attackers.diagonal_attacks(occupied_without_king)
-            | opponent_knights.knight_attacks();
-
-        for &(shift, file_mask) in PAWN_CAPTURE_FILE_MASKS[attacker.0 as usize].iter() {
-            let targets = opponent_pawns.rotate_left(shift as u32) & file_mask;
-            attacked |= targets;
-        }
-
-        let king_attacks_count = checkers.occupied();
+            | opponent_knights.knight_attacks()
+            | self.pawn_attacks(attacker);
 
         // capture_mask and push_mask represent squares our pieces are allowed to move to or capture,
         // respectively. The difference between the two is only important for pawn EP captures
@@ -84,33 +98,72 @@ impl Position {
         let mut push_mask = empty_squares;
         let king_push_mask = empty_squares & !attacked;
 
-        match king_attacks_count {
-            0 => (),
-            1 => {
-                // if ony one attacker, we can try attacking the attacker with
-                // our other pieces.
-                capture_mask = checkers;
-                let checker_sq = checkers.to_square();
-                let checker = self.at(checker_sq);
-                if checker.is_slider() {
-                    // If the piece giving check is a slider, we can additionally attempt
-                    // to block the sliding piece;
-                    push_mask = king_sq.between(checker_sq);
-                } else {
-                    // If we are in check by a jumping piece (aka a knight) then
-                    // there are no valid non-captures to avoid check
-                    push_mask = EMPTY;
-                }
-            }
-            _ => {
-                // multiple attackers... only solutions are king moves
-                self.king_moves(king_capture_mask, king_push_mask, list);
-                return true;
+        if checkers.occupied() == 1 {
+            // if ony one attacker, we can try attacking the attacker with
+            // our other pieces.
+            capture_mask = checkers;
+            let checker_sq = checkers.to_square();
+            let checker = self.at(checker_sq);
+            if checker.is_slider() {
+                // If the piece giving check is a slider, we can additionally attempt
+                // to block the sliding piece;
+                push_mask = king_sq.between(checker_sq);
+            } else {
+                // If we are in check by a jumping piece (aka a knight) then
+                // there are no valid non-captures to avoid check
+                push_mask = EMPTY;
             }
         }
 
+        MoveMasks {
+            king_sq,
+            checkers,
+            pinned,
+            pinners,
+            attacked,
+            capture_mask,
+            push_mask,
+            king_capture_mask,
+            king_push_mask,
+        }
+    }
+
+    pub fn legal_moves<L: MoveAdder>(&self, list: &mut L) -> bool {
+        #[cfg(all(
+            target_arch = "x86_64",
+            not(any(feature = "pext", feature = "kogge-stone"))
+        ))]
+        if pext_available() {
+            return unsafe { self.legal_moves_bmi2(list) };
+        }
+
+        self.generate_legal_moves::<DefaultAttacks, L>(list)
+    }
+
+    #[inline(always)]
+    fn generate_legal_moves<A: SliderAttacks, L: MoveAdder>(&self, list: &mut L) -> bool {
+        let side_to_move = self.state.side_to_move;
+        let MoveMasks {
+            king_sq,
+            checkers,
+            pinned,
+            pinners,
+            attacked,
+            capture_mask,
+            push_mask,
+            king_capture_mask,
+            king_push_mask,
+        } = self.move_masks();
+
+        let king_attacks_count = checkers.occupied();
+        if king_attacks_count > 1 {
+            // multiple attackers... only solutions are king moves
+            self.king_moves(king_capture_mask, king_push_mask, list);
+            return true;
+        }
+
         // generate moves for pinned and unpinned sliders
-        self.slider_moves(capture_mask, push_mask, pinned, king_sq, list);
+        self.slider_moves::<A, L>(capture_mask, push_mask, pinned, king_sq, list);
 
         // generate moves for non-pinned knights (pinned knights can't move)
         self.knight_moves(capture_mask, push_mask, !pinned, list);
@@ -142,12 +195,160 @@ impl Position {
         king_attacks_count > 0
     }
 
+    /// Returns the number of legal moves, for counting the leaves in perft. This is faster than
+    /// passing a MoveCounter to legal_moves, as the targets are popcounted without going through
+    /// a MoveAdder, and those of all pawns at once.
+    pub fn count_legal_moves(&self) -> u64 {
+        #[cfg(all(
+            target_arch = "x86_64",
+            not(any(feature = "pext", feature = "kogge-stone"))
+        ))]
+        if pext_available() {
+            return unsafe { self.count_legal_moves_bmi2() };
+        }
+
+        self.count_legal_moves_with::<DefaultAttacks>()
+    }
+
+    #[inline(always)]
+    fn count_legal_moves_with<A: SliderAttacks>(&self) -> u64 {
+        // En-passant captures are rare but need care, so leave them to the full generator
+        if self.state.en_passant_target.is_some() {
+            let mut counter = MoveCounter::new();
+            self.generate_legal_moves::<A, _>(&mut counter);
+            return counter.moves;
+        }
+
+        let side_to_move = self.state.side_to_move;
+        let masks = self.move_masks();
+        let king_sq = masks.king_sq;
+
+        let king_targets = masks.king_capture_mask | masks.king_push_mask;
+        let mut count = (king_sq.king_moves() & king_targets).occupied() as u64;
+        if masks.checkers.occupied() > 1 {
+            return count;
+        }
+
+        let occupied = self.occupied();
+        let targets = masks.capture_mask | masks.push_mask;
+        let unpinned = !masks.pinned;
+
+        let queens = self.piece(QUEEN.to_piece(side_to_move));
+        let straight_movers = queens | self.piece(ROOK.to_piece(side_to_move));
+        let diagonal_movers = queens | self.piece(BISHOP.to_piece(side_to_move));
+
+        for (from, from_bb) in straight_movers.iter() {
+            let mut moves = A::straight(from, occupied) & targets;
+            if (from_bb & masks.pinned).any() {
+                moves = moves & from.lines_along(king_sq);
+            }
+            count += moves.occupied() as u64;
+        }
+
+        for (from, from_bb) in diagonal_movers.iter() {
+            let mut moves = A::diagonal(from, occupied) & targets;
+            if (from_bb & masks.pinned).any() {
+                moves = moves & from.lines_along(king_sq);
+            }
+            count += moves.occupied() as u64;
+        }
+
+        let knights = self.piece(KNIGHT.to_piece(side_to_move)) & unpinned;
+        for (from, _) in knights.iter() {
+            count += (from.knight_moves() & targets).occupied() as u64;
+        }
+
+        // Pinned pawns can still push along the file of the king, and capture their pinner along
+        // its diagonal
+        let pawns = self.piece(PAWN.to_piece(side_to_move));
+        let pushers = pawns & (unpinned | king_sq.file_mask());
+        let (shift, double_push_rank) = if side_to_move == WHITE {
+            (8, RANK_4)
+        } else {
+            (64 - 8, RANK_5)
+        };
+
+        let single_pushes = pushers.rotate_left(shift) & self.empty();
+        let double_pushes = single_pushes.rotate_left(shift) & double_push_rank & masks.push_mask;
+        count += count_pawn_targets(single_pushes & masks.push_mask);
+        count += double_pushes.occupied() as u64;
+
+        let king_diagonals = king_sq.diagonal_rays();
+        let pinned_capturers = pawns & masks.pinned & king_diagonals;
+        let pinned_targets = masks.capture_mask & masks.pinners & king_diagonals;
+        for &(shift, file_mask) in PAWN_CAPTURE_FILE_MASKS[side_to_move.0 as usize].iter() {
+            let captures = (pawns & unpinned).rotate_left(shift as u32) & file_mask;
+            let pinned_captures = pinned_capturers.rotate_left(shift as u32) & file_mask;
+            count += count_pawn_targets(captures & masks.capture_mask);
+            count += count_pawn_targets(pinned_captures & pinned_targets);
+        }
+
+        if !masks.checkers.any() {
+            let mut counter = MoveCounter::new();
+            self.castles(masks.attacked, &mut counter);
+            count += counter.moves;
+        }
+
+        count
+    }
+
     pub fn legal_moves_vec(&self) -> (MoveVec, bool) {
         let mut list = MoveVec::new();
         let is_in_check = self.legal_moves(&mut list);
         (list, is_in_check)
     }
 
+    /// Returns whether the king of the side to move is attacked, which is cheaper than generating
+    /// all legal moves
+    pub fn is_in_check(&self) -> bool {
+        let side = self.state.side_to_move;
+        let them = !side;
+        let king = self.piece(KING.to_piece(side));
+        let king_sq = king.to_square();
+        let occupied = self.occupied();
+
+        let queens = self.piece(QUEEN.to_piece(them));
+        let straight = queens | self.piece(ROOK.to_piece(them));
+        let diagonal = queens | self.piece(BISHOP.to_piece(them));
+
+        king_sq.knight_moves() & self.piece(KNIGHT.to_piece(them)) != EMPTY
+            || self.pawn_attacks(them) & king != EMPTY
+            || king_sq.straight_attacks(occupied) & straight != EMPTY
+            || king_sq.diagonal_attacks(occupied) & diagonal != EMPTY
+    }
+
+    /// Returns the pieces that give check to the king of the side to move
+    pub fn checkers(&self) -> Board {
+        let side = self.state.side_to_move;
+        let them = !side;
+        let king = self.piece(KING.to_piece(side));
+        let king_sq = king.to_square();
+        let occupied = self.occupied();
+
+        let queens = self.piece(QUEEN.to_piece(them));
+        let straight = queens | self.piece(ROOK.to_piece(them));
+        let diagonal = queens | self.piece(BISHOP.to_piece(them));
+
+        let mut checkers = king_sq.knight_moves() & self.piece(KNIGHT.to_piece(them));
+        for &(shift, file_mask) in PAWN_CAPTURE_FILE_MASKS[side.0 as usize].iter() {
+            checkers |=
+                king.rotate_left(shift as u32) & file_mask & self.piece(PAWN.to_piece(them));
+        }
+        checkers
+            | king_sq.straight_attacks(occupied) & straight
+            | king_sq.diagonal_attacks(occupied) & diagonal
+    }
+
+    /// Returns all squares that are attacked by the pawns of the given side
+    pub fn pawn_attacks(&self, side: Side) -> Board {
+        let pawns = self.piece(PAWN.to_piece(side));
+        let mut attacks = EMPTY;
+        for &(shift, file_mask) in PAWN_CAPTURE_FILE_MASKS[side.0 as usize].iter() {
+            attacks |= pawns.rotate_left(shift as u32) & file_mask;
+        }
+        attacks
+    }
+
     fn castles<L: MoveAdder>(&self, attacked: Board, list: &mut L) {
         let side_to_move = self.state.side_to_move;
         let rights = self.state.castling_rights;
@@ -172,8 +373,14 @@ impl Position {
         }
     }
 
-    fn en_passant_move_discovers_check(&self, from: Board, to: Board, side: Side) -> bool {
-        let occupied = self.occupied() ^ from ^ to;
+    fn en_passant_move_discovers_check(
+        &self,
+        from: Board,
+        to: Board,
+        captured: Board,
+        side: Side,
+    ) -> bool {
+        let occupied = (self.occupied() ^ from ^ captured) | to;
         let attacker = !side;
         let queens = self.piece(QUEEN.to_piece(attacker));
         let rooks = self.piece(ROOK.to_piece(attacker));
@@ -261,6 +468,7 @@ impl Position {
                         // This is expensive but very infrequent
                         if !self.en_passant_move_discovers_check(
                             from_bb,
+                            to_bb,
                             capture_sq_bb,
                             side_to_move,
                         ) {
@@ -370,7 +578,8 @@ impl Position {
         list.add_double_pawn_pushes(double_push_shift, double_pushes & to_mask);
     }
 
-    fn slider_moves<L: MoveAdder>(
+    #[inline(always)]
+    fn slider_moves<A: SliderAttacks, L: MoveAdder>(
         &self,
         capture_mask: Board,
         push_mask: Board,
@@ -387,33 +596,56 @@ impl Position {
         let straight_attackers = queens | rooks;
 
         for (from, _) in (straight_attackers & !pinned_mask).iter() {
-            let targets = from.straight_attacks(occupied);
+            let targets = A::straight(from, occupied);
             list.add_captures(from, targets & capture_mask);
             list.add_pushes(from, targets & push_mask);
         }
 
         for (from, _) in (straight_attackers & pinned_mask).iter() {
             let ray_mask = from.lines_along(king_sq);
-            let targets = from.straight_attacks(occupied) & ray_mask;
+            let targets = A::straight(from, occupied) & ray_mask;
             list.add_captures(from, targets & capture_mask);
             list.add_pushes(from, targets & push_mask);
         }
 
         for (from, _) in (diagonal_attackers & !pinned_mask).iter() {
-            let targets = from.diagonal_attacks(occupied);
+            let targets = A::diagonal(from, occupied);
             list.add_captures(from, targets & capture_mask);
             list.add_pushes(from, targets & push_mask);
         }
 
         for (from, _) in (diagonal_attackers & pinned_mask).iter() {
             let ray_mask = from.lines_along(king_sq);
-            let targets = from.diagonal_attacks(occupied) & ray_mask;
+            let targets = A::diagonal(from, occupied) & ray_mask;
             list.add_captures(from, targets & capture_mask);
             list.add_pushes(from, targets & push_mask);
         }
     }
 }
 
+/// The move generator compiled a second time with BMI2, so that the PEXT lookups are inlined.
+/// Callers must check that the CPU supports it.
+#[cfg(all(
+    target_arch = "x86_64",
+    not(any(feature = "pext", feature = "kogge-stone"))
+))]
+impl Position {
+    #[target_feature(enable = "bmi2")]
+    unsafe fn legal_moves_bmi2<L: MoveAdder>(&self, list: &mut L) -> bool {
+        self.generate_legal_moves::<Pext, L>(list)
+    }
+
+    #[target_feature(enable = "bmi2")]
+    unsafe fn count_legal_moves_bmi2(&self) -> u64 {
+        self.count_legal_moves_with::<Pext>()
+    }
+}
+
+/// Counts the moves of pawns to the targets, where each promotion counts as four moves
+fn count_pawn_targets(targets: Board) -> u64 {
+    ((targets & !END_RANKS).occupied() + (targets & END_RANKS).occupied() * 4) as u64
+}
+
 const CASTLE_BLOCKING_SQUARES: [[Board; 2]; 2] = [
     [
         Board((1u64 << 5) + (1u64 << 6)),               // WHITE KS = F1 + G1
@@ -663,12 +895,30 @@ mod tests {
         assert_eq!(moves.moves, 2 + 3);
     }
 
+    #[test]
+    fn is_in_check() {
+        for (fen, in_check) in [
+            ("k3q3/8/b7/8/8/7R/3PK3/5N2 w - - 0 1", true),
+            ("k6q/8/8/8/8/8/8/K7 w - - 0 1", true),
+            ("k6q/8/8/8/8/8/1R6/K7 w - - 0 1", false),
+            ("4k3/8/8/8/8/8/3p4/4K3 w - - 0 1", true),
+            ("4k3/8/8/8/8/5n2/8/4K3 w - - 0 1", true),
+            ("4k3/8/8/8/8/8/4p3/4K3 w - - 0 1", false),
+        ] {
+            let position = Position::from_fen(fen);
+            assert_eq!(position.is_in_check(), in_check, "{fen}");
+            assert_eq!(position.checkers().any(), in_check, "{fen}");
+            assert_eq!(position.legal_moves_vec().1, in_check, "{fen}");
+        }
+    }
+
     #[test]
     fn double_check() {
         let position = Position::from_fen("k3q3/8/b7/8/8/7R/3PK3/5N2 w - - 0 1");
         let mut moves = MoveCounter::new();
         position.legal_moves(&mut moves);
         assert_eq!(moves.moves, 3);
+        assert_eq!(position.checkers().occupied(), 2);
     }
 
     #[test]
@@ -756,6 +1006,50 @@ mod tests {
         assert_eq!(moves.moves, 3 + 1);
     }
 
+    #[test]
+    fn count_legal_moves() {
+        fn check(position: &mut Position, depth: usize) {
+            let mut counter = MoveCounter::new();
+            position.legal_moves(&mut counter);
+            assert_eq!(
+                position.count_legal_moves(),
+                counter.moves,
+                "{}",
+                position.to_fen()
+            );
+            if depth == 0 {
+                return;
+            }
+
+            let (moves, _) = position.legal_moves_vec();
+            let state = position.state().clone();
+            let hash = position.hash();
+            for &m in moves.iter() {
+                let capture = position.make(m);
+                check(position, depth - 1);
+                position.unmake(m, capture, &state, hash);
+            }
+        }
+
+        for (fen, depth) in [
+            (
+                "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+                2,
+            ),
+            ("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1", 4),
+            (
+                "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+                2,
+            ),
+            (
+                "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+                2,
+            ),
+        ] {
+            check(&mut Position::from_fen(fen), depth);
+        }
+    }
+
     #[test]
     fn en_passant_discovered_check() {
         let position = Position::from_fen("7k/8/8/K2Pp2q/8/8/8/8 w - e6 0 1");
@@ -764,6 +1058,14 @@ mod tests {
         assert_eq!(moves.moves, 5 + 1);
     }
 
+    #[test]
+    fn en_passant_onto_attacked_file() {
+        // The capturing pawn lands between the king and the rook
+        let position = Position::from_fen("8/8/8/6k1/5pP1/8/8/K5R1 b - g3 0 1");
+        let (moves, _) = position.legal_moves_vec();
+        assert!(moves.iter().any(|m| m.is_en_passant_capture()));
+    }
+
     #[test]
     fn en_passant_when_in_check() {
         // Capturing the checker not possible
@@ -778,4 +1080,57 @@ mod tests {
         position.legal_moves(&mut moves);
         assert_eq!(moves.moves, 8 + 1);
     }
+
+    /// The move generator compiled for BMI2 finds the same moves as the one with magics, in
+    /// every position a few moves into some of the perft positions
+    #[cfg(all(
+        target_arch = "x86_64",
+        not(any(feature = "pext", feature = "kogge-stone"))
+    ))]
+    #[test]
+    fn pext_matches_magics() {
+        use crate::{
+            move_list::move_vec::MoveVec,
+            square::{pext_available, Magics, Pext},
+        };
+
+        fn compare(position: &mut Position, depth: usize) {
+            let mut magic_moves = MoveVec::new();
+            let mut pext_moves = MoveVec::new();
+            let magic_check = position.generate_legal_moves::<Magics, _>(&mut magic_moves);
+            let pext_check = unsafe { position.legal_moves_bmi2(&mut pext_moves) };
+            assert_eq!(magic_check, pext_check);
+            assert!(magic_moves.iter().eq(pext_moves.iter()));
+            assert_eq!(position.count_legal_moves_with::<Magics>(), unsafe {
+                position.count_legal_moves_bmi2()
+            });
+            assert_eq!(
+                position.count_legal_moves_with::<Pext>(),
+                magic_moves.len() as u64
+            );
+
+            if depth == 0 {
+                return;
+            }
+            let state = position.state().clone();
+            let hash = position.hash();
+            for &m in magic_moves.iter() {
+                let capture = position.make(m);
+                compare(position, depth - 1);
+                position.unmake(m, capture, &state, hash);
+            }
+        }
+
+        if !pext_available() {
+            return;
+        }
+
+        for fen in [
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+            "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+        ] {
+            compare(&mut Position::from_fen(fen), 2);
+        }
+    }
 }
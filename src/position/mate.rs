@@ -0,0 +1,175 @@
+use crate::{r#move::Move, Position};
+
+/// Result of a search for a forced mate
+#[derive(Debug, PartialEq)]
+pub struct MateResult {
+    /// The shortest forced mate, starting with the move of the attacker and ending with the
+    /// mating move. The defender always plays the move that delays the mate the longest.
+    pub line: Option<Vec<Move>>,
+    pub nodes: u64,
+}
+
+impl MateResult {
+    /// Number of moves of the attacker until mate
+    pub fn moves(&self) -> Option<usize> {
+        self.line.as_ref().map(|line| line.len().div_ceil(2))
+    }
+}
+
+impl Position {
+    /// Proves that the side to move can force mate within the given number of moves, or that
+    /// there is no such mate. Only the attacker's moves are pruned: the mating move must give
+    /// check, and with `checks_only` every move of the attacker must, which is much faster but
+    /// misses mates that start with a quiet move.
+    pub fn solve_mate(&mut self, moves: u8, checks_only: bool) -> MateResult {
+        let mut nodes = 0;
+        for n in 1..=moves {
+            if let Some(mut line) = self.attack(n, checks_only, &mut nodes) {
+                line.reverse();
+                return MateResult {
+                    line: Some(line),
+                    nodes,
+                };
+            }
+        }
+
+        MateResult { line: None, nodes }
+    }
+
+    /// Returns the mating line in reverse if the side to move can mate within `n` moves
+    fn attack(&mut self, n: u8, checks_only: bool, nodes: &mut u64) -> Option<Vec<Move>> {
+        *nodes += 1;
+        let (legal_moves, _) = self.legal_moves_vec();
+
+        // Trying checks and captures first finds most mates sooner
+        let mut moves: Vec<Move> = legal_moves.iter().copied().collect();
+        moves.sort_by_key(|m| !m.is_capture());
+
+        let mut quiet_moves = vec![];
+        for pass in 0..2 {
+            let candidates = if pass == 0 {
+                std::mem::take(&mut moves)
+            } else {
+                std::mem::take(&mut quiet_moves)
+            };
+
+            for m in candidates {
+                let state = self.state.clone();
+                let hash = self.hash;
+                let capture = self.make(m);
+
+                let gives_check = self.is_in_check();
+                if !gives_check && pass == 0 {
+                    // Moves that don't give check can't mate, and are only tried afterwards
+                    self.unmake(m, capture, &state, hash);
+                    if n > 1 && !checks_only {
+                        quiet_moves.push(m);
+                    }
+                    continue;
+                }
+
+                let line = self.defend(n, checks_only, nodes);
+                self.unmake(m, capture, &state, hash);
+
+                if let Some(mut line) = line {
+                    line.push(m);
+                    return Some(line);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns the longest line in reverse if every defence of the side to move gets mated before
+    /// the attacker used up `n` moves, including the one that was just played. The mate after
+    /// each defence is the shortest one, so the line is the longest the defender can delay it.
+    fn defend(&mut self, n: u8, checks_only: bool, nodes: &mut u64) -> Option<Vec<Move>> {
+        *nodes += 1;
+        let (legal_moves, is_in_check) = self.legal_moves_vec();
+
+        if legal_moves.is_empty() {
+            return is_in_check.then(Vec::new);
+        }
+        if n <= 1 || self.state.halfmove_clock >= 100 {
+            return None;
+        }
+
+        let mut longest: Option<Vec<Move>> = None;
+        for &m in legal_moves.iter() {
+            let state = self.state.clone();
+            let hash = self.hash;
+            let capture = self.make(m);
+            let line = (1..n).find_map(|k| self.attack(k, checks_only, nodes));
+            self.unmake(m, capture, &state, hash);
+
+            let mut line = line?;
+            if longest
+                .as_ref()
+                .is_none_or(|longest| line.len() >= longest.len())
+            {
+                line.push(m);
+                longest = Some(line);
+            }
+        }
+
+        longest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Position;
+
+    fn solve(fen: &str, moves: u8, checks_only: bool) -> Option<String> {
+        let result = Position::from_fen(fen).solve_mate(moves, checks_only);
+        result.line.map(|line| {
+            line.iter()
+                .map(|m| m.to_string())
+                .collect::<Vec<String>>()
+                .join(" ")
+        })
+    }
+
+    #[test]
+    fn mate_in_one() {
+        let fen = "6k1/5ppp/8/8/8/8/5PPP/R5K1 w - - 0 1";
+        assert_eq!(solve(fen, 1, false), Some("a1a8".to_string()));
+        assert_eq!(solve(fen, 3, true), Some("a1a8".to_string()));
+    }
+
+    #[test]
+    fn smothered_mate() {
+        let fen = "r6k/6pp/8/6N1/2Q5/8/8/6K1 w - - 0 1";
+        assert_eq!(solve(fen, 3, true), None);
+        assert_eq!(
+            solve(fen, 4, true),
+            Some("g5f7 h8g8 f7h6 g8h8 c4g8 a8g8 h6f7".to_string())
+        );
+    }
+
+    #[test]
+    fn quiet_first_move() {
+        // Kf7 takes away g8, after which black can't escape the rook on the h-file
+        let fen = "7k/8/5K2/8/8/8/8/R7 w - - 0 1";
+        let result = Position::from_fen(fen).solve_mate(2, false);
+        assert_eq!(result.moves(), Some(2));
+        assert!(result.nodes > 0);
+        assert_eq!(solve(fen, 2, true), None);
+    }
+
+    #[test]
+    fn longest_defence() {
+        // After each defence in the line, the shortest mate is one move shorter
+        let mut position = Position::from_fen("2K5/8/8/8/4R3/Q7/2k5/8 w - - 0 1");
+        let line = position.solve_mate(3, false).line.unwrap();
+        assert_eq!(line.len(), 5);
+        for (i, pair) in line.chunks(2).enumerate() {
+            assert_eq!(position.clone().solve_mate(3, false).moves(), Some(3 - i));
+            for &m in pair {
+                position.make(m);
+            }
+        }
+        assert!(position.legal_moves_vec().0.is_empty());
+    }
+}
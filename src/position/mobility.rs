@@ -0,0 +1,234 @@
+use crate::{
+    board::{Board, EMPTY, FILE_A},
+    piece::{BISHOP, KING, KNIGHT, PAWN, QUEEN, ROOK},
+    position::psqt::Score,
+    side::{Side, BLACK, WHITE},
+    square::Square,
+    Position,
+};
+
+/// Bonus for each square a piece can move to, indexed by `PieceKind`
+pub(super) const MOBILITY: [Score; 6] = [
+    Score(0, 0),
+    Score(1, 2),
+    Score(2, 4),
+    Score(5, 5),
+    Score(4, 4),
+    Score(0, 0),
+];
+
+/// Number of squares a piece typically attacks, mobility below this is penalized
+pub(super) const MOBILITY_OFFSET: [i32; 6] = [0, 14, 7, 7, 4, 0];
+
+/// Weight of each piece kind that attacks squares next to the enemy king, indexed by `PieceKind`
+pub(super) const KING_ATTACK_WEIGHTS: [i32; 6] = [0, 5, 3, 2, 2, 0];
+/// More attack units than this don't make the king any less safe
+pub(super) const MAX_KING_ATTACK_UNITS: i32 = 40;
+/// The middlegame penalty is the square of the attack units divided by this
+pub(super) const KING_DANGER_DIVISOR: i32 = 2;
+/// Endgame penalty for each attack unit
+pub(super) const KING_DANGER_ENDGAME: i32 = 1;
+
+/// Bonus for a pawn in front of the king, one and two ranks ahead of it
+pub(super) const PAWN_SHIELD: [Score; 2] = [Score(14, 0), Score(7, 0)];
+pub(super) const SEMI_OPEN_FILE_NEAR_KING: Score = Score(-16, 0);
+pub(super) const OPEN_FILE_NEAR_KING: Score = Score(-28, 0);
+
+/// Pieces other than pawns that are attacked by enemy pawns
+pub(super) const THREAT_BY_PAWN: Score = Score(48, 36);
+/// Pieces that are attacked and not defended at all
+pub(super) const HANGING: Score = Score(32, 18);
+
+/// Squares attacked by one side, along with the terms that can be collected while finding them
+struct Activity {
+    attacks: Board,
+    pawn_attacks: Board,
+    mobility: Score,
+    /// Sum of the weights of the pieces attacking the enemy king zone, for every attacked square
+    king_attack_units: i32,
+    king_attackers: i32,
+}
+
+impl Position {
+    /// Evaluates mobility, king safety and threats from white's perspective
+    pub fn evaluate_activity(&self) -> Score {
+        let white = self.activity(WHITE);
+        let black = self.activity(BLACK);
+
+        white.mobility - black.mobility + self.king_safety(WHITE, &black)
+            - self.king_safety(BLACK, &white)
+            + self.threats(BLACK, &white, &black)
+            - self.threats(WHITE, &black, &white)
+    }
+
+    fn activity(&self, side: Side) -> Activity {
+        let occupied = self.occupied();
+        let their_king = self.piece(KING.to_piece(!side)).to_square();
+        let king_zone = their_king.king_moves();
+
+        // Squares occupied by our own pawns or king and squares defended by enemy pawns don't
+        // count towards mobility
+        let mobility_area = !(self.piece(PAWN.to_piece(side))
+            | self.piece(KING.to_piece(side))
+            | self.pawn_attacks(!side));
+
+        let pawn_attacks = self.pawn_attacks(side);
+        let mut activity = Activity {
+            attacks: pawn_attacks | self.piece(KING.to_piece(side)).to_square().king_moves(),
+            pawn_attacks,
+            mobility: Score::default(),
+            king_attack_units: 0,
+            king_attackers: 0,
+        };
+
+        for kind in [QUEEN, ROOK, BISHOP, KNIGHT] {
+            for (sq, _) in self.piece(kind.to_piece(side)).iter() {
+                let attacks = match kind {
+                    QUEEN => sq.straight_attacks(occupied) | sq.diagonal_attacks(occupied),
+                    ROOK => sq.straight_attacks(occupied),
+                    BISHOP => sq.diagonal_attacks(occupied),
+                    _ => sq.knight_moves(),
+                };
+                activity.attacks |= attacks;
+
+                let k = kind.0 as usize;
+                let squares =
+                    (attacks & mobility_area).occupied() as i32 - self.params.mobility_offset[k];
+                let mobility = self.params.mobility[k];
+                activity.mobility += Score(mobility.0 * squares, mobility.1 * squares);
+
+                let zone_attacks = (attacks & king_zone).occupied() as i32;
+                if zone_attacks > 0 {
+                    activity.king_attackers += 1;
+                    activity.king_attack_units += self.params.king_attack_weights[k] * zone_attacks;
+                }
+            }
+        }
+
+        activity
+    }
+
+    /// Evaluates the pawn shield and open files in front of the king as well as the pieces that
+    /// attack the squares around it
+    fn king_safety(&self, side: Side, their_activity: &Activity) -> Score {
+        let king = self.piece(KING.to_piece(side)).to_square();
+        let our_pawns = self.piece(PAWN.to_piece(side));
+        let their_pawns = self.piece(PAWN.to_piece(!side));
+
+        let rank = king.rank_index() as u32;
+        let in_front = if side == WHITE {
+            Board(u64::MAX.checked_shl(8 * (rank + 1)).unwrap_or(0))
+        } else {
+            Board((1u64 << (8 * rank)) - 1)
+        };
+
+        let mut score = Score::default();
+
+        let file = king.file_index();
+        for f in file.saturating_sub(1)..=(file + 1).min(7) {
+            let file_mask = Board(FILE_A.0 << f);
+            let shield = our_pawns & file_mask & in_front;
+
+            if shield == EMPTY {
+                score += if their_pawns & file_mask == EMPTY {
+                    self.params.open_file_near_king
+                } else {
+                    self.params.semi_open_file_near_king
+                };
+                continue;
+            }
+
+            let closest = if side == WHITE {
+                shield.to_square()
+            } else {
+                Square(63 - shield.0.leading_zeros() as u8)
+            };
+            let distance = (closest.rank_index() as i32 - rank as i32).abs();
+            if distance <= 2 {
+                score += self.params.pawn_shield[distance as usize - 1];
+            }
+        }
+
+        // A single piece can't do much damage, but the danger grows quickly with every attacker
+        if their_activity.king_attackers >= 2 {
+            let units = their_activity
+                .king_attack_units
+                .min(self.params.max_king_attack_units);
+            score -= Score(
+                units * units / self.params.king_danger_divisor,
+                units * self.params.king_danger_endgame,
+            );
+        }
+
+        score
+    }
+
+    /// Evaluates threats against the pieces of the given side
+    fn threats(&self, side: Side, their_activity: &Activity, our_activity: &Activity) -> Score {
+        let pieces = self.side(side) & !self.piece(KING.to_piece(side));
+        let non_pawns = pieces & !self.piece(PAWN.to_piece(side));
+
+        let by_pawns = (non_pawns & their_activity.pawn_attacks).occupied() as i32;
+        let hanging = (pieces & their_activity.attacks & !our_activity.attacks).occupied() as i32;
+
+        let (threat_by_pawn, hanging_piece) = (self.params.threat_by_pawn, self.params.hanging);
+        Score(
+            threat_by_pawn.0 * by_pawns + hanging_piece.0 * hanging,
+            threat_by_pawn.1 * by_pawns + hanging_piece.1 * hanging,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Position, STARTING_POSITION_FEN};
+
+    fn activity(fen: &str) -> i32 {
+        let score = Position::from_fen(fen).evaluate_activity();
+        score.0 + score.1
+    }
+
+    #[test]
+    fn symmetric() {
+        assert_eq!(activity(STARTING_POSITION_FEN), 0);
+        assert_eq!(
+            activity("r1bqkb1r/pppp1ppp/2n2n2/4p3/4P3/2N2N2/PPPP1PPP/R1BQKB1R w KQkq - 4 4"),
+            0
+        );
+    }
+
+    #[test]
+    fn mobility() {
+        // A knight in the center is better than a knight in the corner
+        let center = activity("4k3/pppppppp/8/8/3N4/8/PPPPPPPP/4K3 w - - 0 1");
+        let corner = activity("4k3/pppppppp/8/8/8/8/PPPPPPPP/N3K3 w - - 0 1");
+        assert!(center > corner);
+    }
+
+    #[test]
+    fn king_safety() {
+        let shielded = activity("6k1/5ppp/8/8/8/8/5PPP/6K1 w - - 0 1");
+        let exposed = activity("6k1/5ppp/8/8/8/5PPP/8/6K1 w - - 0 1");
+        let open = activity("6k1/5ppp/8/8/8/8/PPP5/6K1 w - - 0 1");
+        assert_eq!(shielded, 0);
+        assert!(exposed < shielded);
+        assert!(open < exposed);
+
+        // Queen and rook attacking the squares next to the king
+        let far = activity("3qk3/5ppp/8/8/8/8/5PPP/r5K1 w - - 0 1");
+        let close = activity("4k3/5ppp/8/8/8/8/4qPPP/3r2K1 w - - 0 1");
+        assert!(close < far - 50);
+    }
+
+    #[test]
+    fn threats() {
+        // The knight on d5 is hanging, the knight on c3 is attacked by a pawn
+        let hanging = activity("4k3/8/8/3n4/8/8/8/3RK3 w - - 0 1");
+        let defended = activity("4k3/4p3/8/3n4/8/8/8/3RK3 w - - 0 1");
+        assert!(hanging > defended);
+
+        let by_pawn = activity("4k3/8/8/8/1P6/2n5/8/4K3 w - - 0 1");
+        let safe = activity("4k3/8/8/8/P7/2n5/8/4K3 w - - 0 1");
+        assert!(by_pawn > safe);
+    }
+}
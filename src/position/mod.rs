@@ -1,20 +1,31 @@
+mod endgame;
 mod evaluate;
 mod fen;
 mod legal_moves;
+mod mate;
+mod mobility;
 mod r#move;
+mod params;
+mod pawns;
+pub mod psqt;
+mod transposition;
 
 use crate::{
     board::{Board, EMPTY},
     castle::CastlingRights,
     hash::DEFAULT_ZOBRISH_HASH,
+    nnue::{Network, NnueState},
     piece::Piece,
     side::{Side, BLACK, WHITE},
     square::Square,
     utils::grid_to_string_with_props,
 };
-use std::fmt::Display;
+use psqt::EvalState;
+use std::{fmt::Display, sync::Arc};
 
-pub use evaluate::Evaluation;
+pub use evaluate::{Evaluation, SearchOptions};
+pub use params::EvalParams;
+pub use transposition::TranspositionTable;
 
 pub const STARTING_POSITION_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
 
@@ -45,6 +56,9 @@ pub struct Position {
     side_boards: [Board; 2],
     state: State,
     hash: u64,
+    eval_state: EvalState,
+    params: Arc<EvalParams>,
+    nnue: Option<NnueState>,
 }
 
 impl Position {
@@ -71,6 +85,8 @@ impl Position {
         }
 
         let hash = DEFAULT_ZOBRISH_HASH.position(&pieces, &state);
+        let params = EvalParams::shared_default();
+        let eval_state = EvalState::new(&params, &pieces);
 
         Position {
             pieces,
@@ -78,6 +94,9 @@ impl Position {
             side_boards,
             state,
             hash,
+            eval_state,
+            params,
+            nnue: None,
         }
     }
 
@@ -93,6 +112,17 @@ impl Position {
         unsafe { return *self.side_boards.get_unchecked(side.0 as usize & 1) }
     }
 
+    /// Uses the given weights for the hand-crafted evaluation
+    pub fn set_params(&mut self, params: Arc<EvalParams>) {
+        self.eval_state = EvalState::new(&params, &self.pieces);
+        self.params = params;
+    }
+
+    /// Uses the given network instead of the hand-crafted evaluation
+    pub fn set_network(&mut self, network: Option<Arc<Network>>) {
+        self.nnue = network.map(|network| NnueState::new(network, &self.pieces));
+    }
+
     pub fn state(&self) -> &State {
         &self.state
     }
@@ -15,6 +15,10 @@ impl Position {
         let initial_state = self.state.clone();
         let mut move_resets_half_move_clock = false;
 
+        if let Some(nnue) = &mut self.nnue {
+            nnue.push();
+        }
+
         if self.state.side_to_move == BLACK {
             self.state.fullmove_number += 1;
         }
@@ -98,9 +102,38 @@ impl Position {
 
         self.hash ^= xor_key;
 
+        if let Some(nnue) = &mut self.nnue {
+            nnue.refresh(&self.pieces);
+        }
+
         captured
     }
 
+    /// Passes the turn to the opponent without moving a piece, as used by null move pruning
+    pub fn make_null(&mut self) {
+        let initial_state = self.state.clone();
+
+        if self.state.side_to_move == BLACK {
+            self.state.fullmove_number += 1;
+        }
+        self.state.side_to_move = !self.state.side_to_move;
+        self.state.en_passant_target = None;
+        self.state.halfmove_clock += 1;
+
+        // Positions before and after the null move are not connected by legal moves, so they
+        // shouldn't count as repetitions of each other
+        if let Some(ref mut prev_hashes) = &mut self.state.prev_hashes {
+            prev_hashes.clear();
+        }
+
+        self.hash ^= DEFAULT_ZOBRISH_HASH.state(&initial_state, &self.state);
+    }
+
+    pub fn unmake_null(&mut self, original_state: &State, original_hash: u64) {
+        self.state = original_state.clone();
+        self.hash = original_hash;
+    }
+
     pub fn unmake(
         &mut self,
         mv: Move,
@@ -111,25 +144,32 @@ impl Position {
         self.state = original_state.clone();
         self.hash = original_hash;
 
+        // The accumulator from before the move is still there, so the network doesn't need to
+        // know about the pieces moving back
+        let mut nnue = self.nnue.take();
+
         if let Some(castle) = mv.castle() {
             let (king_to, king_from, rook_to, rook_from) =
                 castle_squares(original_state.side_to_move, castle);
             self.move_piece(king_from, king_to);
             self.move_piece(rook_from, rook_to);
+        } else {
+            if mv.promote_to().is_some() {
+                let mover = PAWN.to_piece(original_state.side_to_move);
+                self.promote_piece(mv.to(), mover);
+            }
 
-            return;
-        }
+            self.move_piece(mv.to(), mv.from());
 
-        if mv.promote_to().is_some() {
-            let mover = PAWN.to_piece(original_state.side_to_move);
-            self.promote_piece(mv.to(), mover);
+            if let Some((captured_piece, capture_sq)) = capture {
+                self.put(captured_piece, capture_sq);
+            }
         }
 
-        self.move_piece(mv.to(), mv.from());
-
-        if let Some((captured_piece, capture_sq)) = capture {
-            self.put(captured_piece, capture_sq);
+        if let Some(nnue) = &mut nnue {
+            nnue.pop();
         }
+        self.nnue = nnue;
     }
 
     fn move_piece(&mut self, from: Square, to: Square) -> Board {
@@ -138,6 +178,11 @@ impl Position {
 
         self.update_grid(from, NULL_PIECE);
         self.update_grid(to, piece);
+        self.eval_state.move_piece(&self.params, piece, from, to);
+        if let Some(nnue) = &mut self.nnue {
+            nnue.remove(piece, from);
+            nnue.add(piece, to);
+        }
 
         unsafe {
             *self.piece_boards.get_unchecked_mut(piece.0 as usize) ^= mask;
@@ -152,6 +197,12 @@ impl Position {
         let mask = Board::new(square);
 
         self.update_grid(square, new_piece);
+        self.eval_state.remove(&self.params, old_piece, square);
+        self.eval_state.add(&self.params, new_piece, square);
+        if let Some(nnue) = &mut self.nnue {
+            nnue.remove(old_piece, square);
+            nnue.add(new_piece, square);
+        }
 
         unsafe {
             *(self.piece_boards.get_unchecked_mut(old_piece.0 as usize)) ^= mask;
@@ -163,6 +214,10 @@ impl Position {
         let mask = Board::new(square);
 
         self.update_grid(square, piece);
+        self.eval_state.add(&self.params, piece, square);
+        if let Some(nnue) = &mut self.nnue {
+            nnue.add(piece, square);
+        }
 
         unsafe {
             *self.piece_boards.get_unchecked_mut(piece.0 as usize) ^= mask;
@@ -175,6 +230,10 @@ impl Position {
         let mask = Board::new(square);
 
         self.update_grid(square, NULL_PIECE);
+        self.eval_state.remove(&self.params, piece, square);
+        if let Some(nnue) = &mut self.nnue {
+            nnue.remove(piece, square);
+        }
 
         unsafe {
             *self.piece_boards.get_unchecked_mut(piece.0 as usize) ^= mask;
@@ -214,6 +273,7 @@ mod tests {
             BLACK_PAWN, NULL_PIECE, QUEEN, WHITE_BISHOP, WHITE_KING, WHITE_KNIGHT, WHITE_PAWN,
             WHITE_QUEEN, WHITE_ROOK,
         },
+        position::psqt::EvalState,
         r#move::Move,
         side::{BLACK, WHITE},
         square::Square,
@@ -716,4 +776,48 @@ mod tests {
         p2.unmake(m, capture, &p2_state, p2_hash);
         assert_eq!(p1, p2);
     }
+
+    #[test]
+    fn incremental_eval_state() {
+        // Covers castling, promotions and captures
+        let mut p =
+            Position::from_fen("r2q1rk1/pP1p2pp/Q4n2/bbp1p3/Np6/1B3NBn/pPPP1PPP/R3K2R b KQ - 0 1");
+        let original = p.clone();
+
+        for m in p.legal_moves_vec().0.iter() {
+            let state = p.state.clone();
+            let hash = p.hash;
+            let capture = p.make(*m);
+            assert_eq!(p.eval_state, EvalState::new(&p.params, &p.pieces));
+
+            for m2 in p.legal_moves_vec().0.iter() {
+                let state2 = p.state.clone();
+                let hash2 = p.hash;
+                let capture2 = p.make(*m2);
+                assert_eq!(p.eval_state, EvalState::new(&p.params, &p.pieces));
+                p.unmake(*m2, capture2, &state2, hash2);
+            }
+
+            p.unmake(*m, capture, &state, hash);
+        }
+
+        assert_eq!(p, original);
+    }
+
+    #[test]
+    fn null_move() {
+        let mut p = Position::from_fen(
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        );
+        let original = p.clone();
+
+        let state = p.state.clone();
+        let hash = p.hash;
+        p.make_null();
+        assert_eq!(p.state.side_to_move, BLACK);
+        assert_ne!(p.hash, hash);
+
+        p.unmake_null(&state, hash);
+        assert_eq!(p, original);
+    }
 }
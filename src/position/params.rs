@@ -0,0 +1,329 @@
+use crate::{
+    piece::Piece,
+    position::{endgame, mobility, pawns, psqt, psqt::Score},
+    square::Square,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{fs, path::Path, sync::Arc, sync::OnceLock};
+
+/// A piece-square table from white's perspective, starting with rank 8
+pub type Table = [[i32; 8]; 8];
+
+const fn to_table(values: [i32; 64]) -> Table {
+    let mut table = [[0; 8]; 8];
+    let mut sq = 0;
+    while sq < 64 {
+        table[sq / 8][sq % 8] = values[sq];
+        sq += 1;
+    }
+    table
+}
+
+/// All weights of the hand-crafted evaluation, so they can be loaded from a file at runtime
+/// instead of being compiled in. Weights that are missing from a file keep their default value.
+///
+/// The piece values and tables are only reachable through setters, as they are combined into a
+/// cache that must be rebuilt whenever they change. Deserializing rebuilds it as well.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", default)]
+pub struct EvalParams {
+    /// Middlegame and endgame values, indexed by `PieceKind`
+    piece_values: [Score; 6],
+    /// Middlegame piece-square tables, indexed by `PieceKind`
+    mg_tables: [Table; 6],
+    /// Endgame piece-square tables, indexed by `PieceKind`
+    eg_tables: [Table; 6],
+
+    pub doubled: Score,
+    pub isolated: Score,
+    pub backward: Score,
+    /// Bonus for pawns that are defended by or standing next to a friendly pawn, indexed by rank
+    pub connected: [i32; 8],
+    /// Bonus for pawns that can't be stopped by enemy pawns, indexed by rank
+    pub passed: [Score; 8],
+    /// Endgame bonus in quarters for each square between the enemy king and the square in front
+    /// of a passed pawn, and penalty for each square between the own king and it
+    pub passed_king_distance: [i32; 2],
+
+    /// Bonus for each square a piece can move to, indexed by `PieceKind`
+    pub mobility: [Score; 6],
+    /// Number of squares a piece typically moves to, indexed by `PieceKind`. Mobility below this
+    /// is penalized.
+    pub mobility_offset: [i32; 6],
+    /// Weight of each piece kind that attacks squares next to the enemy king, indexed by
+    /// `PieceKind`
+    pub king_attack_weights: [i32; 6],
+    /// More attack units than this don't make the king any less safe
+    pub max_king_attack_units: i32,
+    /// The middlegame king danger penalty is the square of the attack units divided by this
+    pub king_danger_divisor: i32,
+    /// Endgame king danger penalty for each attack unit
+    pub king_danger_endgame: i32,
+    /// Bonus for a pawn in front of the king, one and two ranks ahead of it
+    pub pawn_shield: [Score; 2],
+    pub semi_open_file_near_king: Score,
+    pub open_file_near_king: Score,
+    pub threat_by_pawn: Score,
+    pub hanging: Score,
+
+    /// Score for endgames that are known to be won, which is higher than any regular evaluation
+    pub known_win: i32,
+
+    /// Combined piece values and tables, indexed by `Piece` and `Square`. Scores for black pieces
+    /// are mirrored vertically and negated.
+    #[serde(skip)]
+    piece_square: Vec<[Score; 64]>,
+}
+
+impl Default for EvalParams {
+    fn default() -> EvalParams {
+        let mut params = EvalParams {
+            piece_values: psqt::PIECE_VALUES,
+            mg_tables: psqt::MG_TABLES.map(to_table),
+            eg_tables: psqt::EG_TABLES.map(to_table),
+            doubled: pawns::DOUBLED,
+            isolated: pawns::ISOLATED,
+            backward: pawns::BACKWARD,
+            connected: pawns::CONNECTED,
+            passed: pawns::PASSED,
+            passed_king_distance: pawns::PASSED_KING_DISTANCE,
+            mobility: mobility::MOBILITY,
+            mobility_offset: mobility::MOBILITY_OFFSET,
+            king_attack_weights: mobility::KING_ATTACK_WEIGHTS,
+            max_king_attack_units: mobility::MAX_KING_ATTACK_UNITS,
+            king_danger_divisor: mobility::KING_DANGER_DIVISOR,
+            king_danger_endgame: mobility::KING_DANGER_ENDGAME,
+            pawn_shield: mobility::PAWN_SHIELD,
+            semi_open_file_near_king: mobility::SEMI_OPEN_FILE_NEAR_KING,
+            open_file_near_king: mobility::OPEN_FILE_NEAR_KING,
+            threat_by_pawn: mobility::THREAT_BY_PAWN,
+            hanging: mobility::HANGING,
+            known_win: endgame::KNOWN_WIN,
+            piece_square: vec![],
+        };
+        params.update_piece_square();
+        params
+    }
+}
+
+impl Serialize for EvalParams {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        EvalParams::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for EvalParams {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<EvalParams, D::Error> {
+        let mut params = EvalParams::deserialize(deserializer)?;
+        params.update_piece_square();
+        Ok(params)
+    }
+}
+
+impl EvalParams {
+    /// Returns the compiled in weights, shared by all positions that don't use other weights
+    pub fn shared_default() -> Arc<EvalParams> {
+        static DEFAULT: OnceLock<Arc<EvalParams>> = OnceLock::new();
+        DEFAULT
+            .get_or_init(|| Arc::new(EvalParams::default()))
+            .clone()
+    }
+
+    /// Loads the weights from a JSON file if the extension is `json`, and from TOML otherwise
+    pub fn load(path: impl AsRef<Path>) -> Result<EvalParams, String> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read {}: {}", path.display(), err))?;
+
+        if path.extension().is_some_and(|ext| ext == "json") {
+            EvalParams::from_json(&contents)
+        } else {
+            EvalParams::from_toml(&contents)
+        }
+    }
+
+    pub fn from_json(s: &str) -> Result<EvalParams, String> {
+        serde_json::from_str(s).map_err(|err| format!("Invalid parameters: {err}"))
+    }
+
+    pub fn from_toml(s: &str) -> Result<EvalParams, String> {
+        toml::from_str(s).map_err(|err| format!("Invalid parameters: {err}"))
+    }
+
+    pub fn to_toml(&self) -> String {
+        toml::to_string(self).unwrap()
+    }
+
+    /// Writes the weights as JSON if the extension is `json`, and as TOML otherwise
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let path = path.as_ref();
+        let contents = if path.extension().is_some_and(|ext| ext == "json") {
+            serde_json::to_string_pretty(self).unwrap()
+        } else {
+            self.to_toml()
+        };
+        fs::write(path, contents)
+            .map_err(|err| format!("Failed to write {}: {}", path.display(), err))
+    }
+
+    /// Returns the material and positional score of a piece on a square from white's perspective
+    #[inline]
+    pub fn piece_square(&self, pc: Piece, sq: Square) -> Score {
+        unsafe {
+            *self
+                .piece_square
+                .get_unchecked(pc.0 as usize)
+                .get_unchecked(sq.0 as usize)
+        }
+    }
+
+    pub fn piece_values(&self) -> &[Score; 6] {
+        &self.piece_values
+    }
+
+    pub fn mg_tables(&self) -> &[Table; 6] {
+        &self.mg_tables
+    }
+
+    pub fn eg_tables(&self) -> &[Table; 6] {
+        &self.eg_tables
+    }
+
+    pub fn set_piece_values(&mut self, piece_values: [Score; 6]) {
+        self.piece_values = piece_values;
+        self.update_piece_square();
+    }
+
+    pub fn set_tables(&mut self, mg_tables: [Table; 6], eg_tables: [Table; 6]) {
+        self.mg_tables = mg_tables;
+        self.eg_tables = eg_tables;
+        self.update_piece_square();
+    }
+
+    /// Combines the piece values and tables, which needs to happen whenever any of them changed
+    fn update_piece_square(&mut self) {
+        self.piece_square = vec![[Score(0, 0); 64]; 12];
+
+        for kind in 0..6 {
+            let value = self.piece_values[kind];
+            for sq in 0..64 {
+                // The tables start with a8, so white needs to flip the rank to look up a square
+                let flipped = sq ^ 56;
+                let mg = value.0 + self.mg_tables[kind][flipped / 8][flipped % 8];
+                let eg = value.1 + self.eg_tables[kind][flipped / 8][flipped % 8];
+                self.piece_square[kind << 1][sq] = Score(mg, eg);
+
+                let mg = value.0 + self.mg_tables[kind][sq / 8][sq % 8];
+                let eg = value.1 + self.eg_tables[kind][sq / 8][sq % 8];
+                self.piece_square[(kind << 1) | 1][sq] = Score(-mg, -eg);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        piece::{WHITE_KNIGHT, WHITE_PAWN},
+        position::{endgame, params::EvalParams, psqt::Score},
+        square::Square,
+        Position,
+    };
+    use std::sync::Arc;
+
+    fn sq(s: &str) -> Square {
+        Square::try_from_str(s).unwrap().unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let params = EvalParams::default();
+        assert_eq!(EvalParams::from_toml(&params.to_toml()).unwrap(), params);
+        assert_eq!(
+            EvalParams::from_json(&serde_json::to_string(&params).unwrap()).unwrap(),
+            params
+        );
+    }
+
+    #[test]
+    fn round_trip_changed() {
+        let mut params = EvalParams {
+            known_win: 5_000,
+            max_king_attack_units: 30,
+            passed_king_distance: [16, 4],
+            ..EvalParams::default()
+        };
+        params.mobility_offset[1] = 10;
+
+        let from_toml = EvalParams::from_toml(&params.to_toml()).unwrap();
+        assert_eq!(from_toml.known_win, 5_000);
+        assert_eq!(from_toml, params);
+        assert_eq!(
+            EvalParams::from_json(&serde_json::to_string(&params).unwrap()).unwrap(),
+            params
+        );
+
+        // The evaluation of known wins uses the loaded score
+        let mut position = Position::from_fen("8/8/8/8/8/8/4P3/4K2k w - - 0 1");
+        let default = position.evaluate_endgame().unwrap();
+        position.set_params(Arc::new(from_toml));
+        assert_eq!(
+            position.evaluate_endgame().unwrap(),
+            default - endgame::KNOWN_WIN + 5_000
+        );
+    }
+
+    #[test]
+    fn plain_serde() {
+        // Going through serde directly instead of from_json and from_toml builds the cache too
+        let params = EvalParams::default();
+        let from_json: EvalParams =
+            serde_json::from_str(&serde_json::to_string(&params).unwrap()).unwrap();
+        assert_eq!(from_json, params);
+        let from_toml: EvalParams = toml::from_str("hanging = [0, 0]").unwrap();
+        assert_eq!(
+            from_toml.piece_square(WHITE_KNIGHT, sq("d4")),
+            params.piece_square(WHITE_KNIGHT, sq("d4"))
+        );
+    }
+
+    #[test]
+    fn partial() {
+        let params = EvalParams::from_toml(
+            "
+            piece_values = [[0, 0], [900, 900], [500, 500], [300, 300], [300, 300], [100, 100]]
+            hanging = [0, 0]
+            ",
+        )
+        .unwrap();
+        assert_eq!(params.hanging, Score(0, 0));
+        assert_eq!(params.doubled, EvalParams::default().doubled);
+        assert_eq!(
+            params.piece_square(WHITE_KNIGHT, sq("d4")) - params.piece_square(WHITE_PAWN, sq("d4")),
+            EvalParams::default().piece_square(WHITE_KNIGHT, sq("d4"))
+                - EvalParams::default().piece_square(WHITE_PAWN, sq("d4"))
+                + Score(300 - 100 - 337 + 82, 300 - 100 - 281 + 94)
+        );
+
+        assert!(EvalParams::from_json("{\"hanging\": 3}").is_err());
+    }
+
+    #[test]
+    fn used_by_evaluation() {
+        let fen = "rnbqkb1r/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        let mut params = EvalParams::default();
+        let mut piece_values = *params.piece_values();
+        piece_values[4] = Score(1000, 1000);
+        params.set_piece_values(piece_values);
+
+        let mut position = Position::from_fen(fen);
+        let default = position
+            .evaluate(20, false)
+            .to_score(position.state().side_to_move);
+        position.set_params(Arc::new(params));
+        let changed = position
+            .evaluate(20, false)
+            .to_score(position.state().side_to_move);
+        assert!(changed > default + 600);
+    }
+}
@@ -0,0 +1,326 @@
+use crate::{
+    board::{Board, EMPTY, FILE_A, FILE_H},
+    piece::{KING, PAWN},
+    position::{psqt::Score, EvalParams},
+    side::{Side, BLACK, WHITE},
+    square::Square,
+    Position,
+};
+use std::{
+    cell::RefCell,
+    sync::{Arc, Weak},
+};
+
+pub(super) const DOUBLED: Score = Score(-11, -51);
+pub(super) const ISOLATED: Score = Score(-5, -15);
+pub(super) const BACKWARD: Score = Score(-9, -24);
+
+/// Bonus for pawns that are defended by or standing next to a friendly pawn, indexed by rank
+pub(super) const CONNECTED: [i32; 8] = [0, 7, 8, 12, 29, 48, 86, 0];
+
+/// Bonus for pawns that can't be stopped by enemy pawns, indexed by rank
+pub(super) const PASSED: [Score; 8] = [
+    Score(0, 0),
+    Score(2, 38),
+    Score(15, 36),
+    Score(22, 50),
+    Score(64, 81),
+    Score(166, 184),
+    Score(284, 269),
+    Score(0, 0),
+];
+
+/// Endgame bonus in quarters for each square between the enemy king and the square in front of a
+/// passed pawn, and penalty for each square between the own king and it, up to five squares
+pub(super) const PASSED_KING_DISTANCE: [i32; 2] = [19, 8];
+
+/// Number of entries in the pawn hash table of each thread
+const PAWN_TABLE_SIZE: usize = 1 << 14;
+
+#[derive(Clone, Copy)]
+struct Entry {
+    key: u64,
+    score: Score,
+    passed: [Board; 2],
+}
+
+/// Caches the pawn structure evaluation, which rarely changes between nodes of the search
+struct PawnTable {
+    entries: Box<[Entry]>,
+    /// The weights the entries were evaluated with. As long as this is kept, no other weights can
+    /// be allocated at the same address.
+    params: Weak<EvalParams>,
+}
+
+impl PawnTable {
+    fn new() -> PawnTable {
+        // Empty entries are valid for positions without pawns, which have the key zero
+        PawnTable {
+            entries: vec![
+                Entry {
+                    key: 0,
+                    score: Score(0, 0),
+                    passed: [EMPTY; 2],
+                };
+                PAWN_TABLE_SIZE
+            ]
+            .into_boxed_slice(),
+            params: Weak::new(),
+        }
+    }
+
+    fn get(
+        &mut self,
+        params: &Arc<EvalParams>,
+        key: u64,
+        white_pawns: Board,
+        black_pawns: Board,
+    ) -> Entry {
+        if self.params.as_ptr() != Arc::as_ptr(params) {
+            *self = PawnTable::new();
+            self.params = Arc::downgrade(params);
+        }
+
+        let entry = &mut self.entries[key as usize & (PAWN_TABLE_SIZE - 1)];
+        if entry.key != key {
+            let (score, passed) = evaluate_pawn_structure(params, white_pawns, black_pawns);
+            *entry = Entry { key, score, passed };
+        }
+        *entry
+    }
+}
+
+thread_local! {
+    static PAWN_TABLE: RefCell<PawnTable> = RefCell::new(PawnTable::new());
+}
+
+fn north_fill(b: u64) -> u64 {
+    let b = b | (b << 8);
+    let b = b | (b << 16);
+    b | (b << 32)
+}
+
+fn south_fill(b: u64) -> u64 {
+    let b = b | (b >> 8);
+    let b = b | (b >> 16);
+    b | (b >> 32)
+}
+
+fn west(b: u64) -> u64 {
+    (b >> 1) & !FILE_H.0
+}
+
+fn east(b: u64) -> u64 {
+    (b << 1) & !FILE_A.0
+}
+
+/// Squares attacked by white pawns
+fn pawn_attacks(b: u64) -> u64 {
+    west(b << 8) | east(b << 8)
+}
+
+/// Mirrors a board vertically, so that black's pawns can be evaluated as if they were white
+fn flip(b: Board) -> Board {
+    Board(b.0.swap_bytes())
+}
+
+/// Evaluates the pawns of one side as if they were white, returning the score and the passed pawns
+fn evaluate_side(params: &EvalParams, us: Board, them: Board) -> (Score, Board) {
+    let mut score = Score::default();
+    let mut passed = 0;
+
+    let our_attacks = pawn_attacks(us.0);
+    let their_pawn_attacks = west(them.0 >> 8) | east(them.0 >> 8);
+
+    for (sq, b) in us.iter() {
+        let b = b.0;
+        let rank = sq.rank_index() as usize;
+        let file = FILE_A.0 << sq.file_index();
+        let adjacent_files = west(file) | east(file);
+
+        let front_span = north_fill(b << 8);
+        let attack_span = north_fill(west(b << 8) | east(b << 8));
+
+        let supported = our_attacks & b != 0;
+        let phalanx = (west(b) | east(b)) & us.0 != 0;
+
+        if front_span & us.0 != 0 {
+            score += params.doubled;
+        }
+
+        if adjacent_files & us.0 == 0 {
+            score += params.isolated;
+        } else if !supported
+            && !phalanx
+            && south_fill(west(b) | east(b)) & us.0 == 0
+            && their_pawn_attacks & (b << 8) != 0
+        {
+            // No friendly pawn can ever defend this pawn, and it can't advance safely
+            score += params.backward;
+        }
+
+        if supported || phalanx {
+            let bonus = params.connected[rank] * if phalanx { 2 } else { 1 };
+            score += Score(bonus, bonus * (rank as i32 - 2).max(0) / 4);
+        }
+
+        // Only the most advanced of doubled pawns can be passed
+        if (front_span | attack_span) & them.0 == 0 && front_span & us.0 == 0 {
+            score += params.passed[rank];
+            passed |= b;
+        }
+    }
+
+    (score, Board(passed))
+}
+
+/// Evaluates the pawn structure from white's perspective and finds the passed pawns of both sides
+fn evaluate_pawn_structure(
+    params: &EvalParams,
+    white_pawns: Board,
+    black_pawns: Board,
+) -> (Score, [Board; 2]) {
+    let (white_score, white_passed) = evaluate_side(params, white_pawns, black_pawns);
+    let (black_score, black_passed) = evaluate_side(params, flip(black_pawns), flip(white_pawns));
+    (
+        white_score - black_score,
+        [white_passed, flip(black_passed)],
+    )
+}
+
+fn distance(a: Square, b: Square) -> i32 {
+    let files = (a.file_index() as i32 - b.file_index() as i32).abs();
+    let ranks = (a.rank_index() as i32 - b.rank_index() as i32).abs();
+    files.max(ranks)
+}
+
+impl Position {
+    /// Evaluates the pawn structure from white's perspective, using the pawn hash table of the
+    /// current thread
+    pub fn evaluate_pawns(&self) -> Score {
+        let white_pawns = self.piece(PAWN.to_piece(WHITE));
+        let black_pawns = self.piece(PAWN.to_piece(BLACK));
+        let entry = PAWN_TABLE.with(|table| {
+            table.borrow_mut().get(
+                &self.params,
+                self.eval_state.pawn_key,
+                white_pawns,
+                black_pawns,
+            )
+        });
+
+        entry.score + self.passed_pawn_king_proximity(WHITE, entry.passed[0])
+            - self.passed_pawn_king_proximity(BLACK, entry.passed[1])
+    }
+
+    /// In the endgame, passed pawns are stronger when the own king is close to the square in front
+    /// of the pawn and the enemy king is far away from it
+    fn passed_pawn_king_proximity(&self, side: Side, passed: Board) -> Score {
+        let our_king = self.piece(KING.to_piece(side)).to_square();
+        let their_king = self.piece(KING.to_piece(!side)).to_square();
+
+        let mut bonus = 0;
+        for (sq, _) in passed.iter() {
+            let (rank, block_square) = if side == WHITE {
+                (sq.rank_index() as i32, Square(sq.0 + 8))
+            } else {
+                (7 - sq.rank_index() as i32, Square(sq.0 - 8))
+            };
+            if rank < 3 {
+                continue;
+            }
+
+            let weight = 5 * rank - 13;
+            let [their_weight, our_weight] = self.params.passed_king_distance;
+            bonus += (distance(their_king, block_square).min(5) * their_weight / 4
+                - distance(our_king, block_square).min(5) * our_weight / 4)
+                * weight;
+        }
+
+        Score(0, bonus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::Board,
+        piece::{BLACK_PAWN, WHITE_PAWN},
+        position::{
+            pawns::{evaluate_pawn_structure, BACKWARD, CONNECTED, DOUBLED, ISOLATED, PASSED},
+            psqt::Score,
+            EvalParams,
+        },
+        Position,
+    };
+    use std::sync::Arc;
+
+    fn pawns(fen: &str) -> (Board, Board) {
+        let position = Position::from_fen(fen);
+        (position.piece(WHITE_PAWN), position.piece(BLACK_PAWN))
+    }
+
+    #[test]
+    fn new_weights() {
+        // Weights that are loaded after the previous ones were dropped might get the same address
+        let evaluate = |params: EvalParams| {
+            let mut position = Position::from_fen("4k3/8/8/8/8/4P3/4P3/4K3 w - - 0 1");
+            position.set_params(Arc::new(params));
+            position.evaluate_pawns()
+        };
+        let default = evaluate(EvalParams::default());
+
+        let mut params = EvalParams::default();
+        params.doubled = Score(-100, -100);
+        assert_ne!(evaluate(params), default);
+    }
+
+    #[test]
+    fn symmetric() {
+        let (white, black) = pawns("4k3/pp3ppp/2p5/8/8/2P5/PP3PPP/4K3 w - - 0 1");
+        let (score, passed) = evaluate_pawn_structure(&EvalParams::default(), white, black);
+        assert_eq!(score.0, 0);
+        assert_eq!(score.1, 0);
+        assert_eq!(passed, [Board(0), Board(0)]);
+    }
+
+    #[test]
+    fn weaknesses() {
+        // Doubled and isolated pawns on the e-file, only the front one is passed
+        let (white, black) = pawns("4k3/8/8/8/8/4P3/4P3/4K3 w - - 0 1");
+        let (score, passed) = evaluate_pawn_structure(&EvalParams::default(), white, black);
+        assert_eq!(score, DOUBLED + ISOLATED + ISOLATED + PASSED[2]);
+        assert_eq!(passed, [Board(1 << 20), Board(0)]);
+
+        // The pawn on d3 can't be defended and is attacked when advancing
+        let (white, black) = pawns("4k3/8/8/2p5/4P3/3P4/8/4K3 w - - 0 1");
+        let (score, _) = evaluate_pawn_structure(&EvalParams::default(), white, black);
+        let e4 = Score(CONNECTED[3], CONNECTED[3] / 4) + PASSED[3];
+        assert_eq!(score, BACKWARD + e4 - ISOLATED);
+    }
+
+    #[test]
+    fn passed_pawns() {
+        // The pawn on d5 is passed, the pawns on h2 and g7 block each other
+        let (white, black) = pawns("4k3/6p1/8/3P4/8/8/7P/4K3 w - - 0 1");
+        let (_, passed) = evaluate_pawn_structure(&EvalParams::default(), white, black);
+        assert_eq!(passed, [Board(1 << 35), Board(0)]);
+
+        // A far advanced passed pawn is worth a lot more in the endgame
+        let (advanced, _) =
+            evaluate_pawn_structure(&EvalParams::default(), Board(1 << 51), Board(0));
+        let (behind, _) = evaluate_pawn_structure(&EvalParams::default(), Board(1 << 11), Board(0));
+        assert!(advanced.1 > behind.1 + 200);
+    }
+
+    #[test]
+    fn king_proximity() {
+        let close = Position::from_fen("8/8/3K4/3P4/8/8/8/7k w - - 0 1").evaluate_pawns();
+        let far = Position::from_fen("8/8/8/3P4/8/8/8/K6k w - - 0 1").evaluate_pawns();
+        assert!(close.1 > far.1);
+
+        let close = Position::from_fen("7K/8/8/8/3p4/3k4/8/8 w - - 0 1").evaluate_pawns();
+        let far = Position::from_fen("k6K/8/8/8/3p4/8/8/8 w - - 0 1").evaluate_pawns();
+        assert!(close.1 < far.1);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::r#move::Move;
+
+/// How the stored score relates to the real score of the position
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Bound {
+    Exact,
+    /// The search failed high, so the real score is at least as high
+    Lower,
+    /// The search failed low, so the real score is at most as high
+    Upper,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Entry {
+    key: u64,
+    pub best_move: Option<Move>,
+    pub score: i32,
+    pub depth: u8,
+    pub bound: Bound,
+}
+
+/// Remembers the results of earlier searches by the hash of the position, so that positions which
+/// are reached again by a different move order don't need to be searched again
+pub struct TranspositionTable {
+    entries: Box<[Option<Entry>]>,
+    mask: usize,
+}
+
+/// Number of entries of a table that is created with `default`, which takes 6 MB
+const DEFAULT_ENTRIES: usize = 1 << 18;
+
+impl Default for TranspositionTable {
+    fn default() -> TranspositionTable {
+        TranspositionTable::new(DEFAULT_ENTRIES)
+    }
+}
+
+impl TranspositionTable {
+    /// The number of entries must be 2^N
+    pub fn new(entries: usize) -> TranspositionTable {
+        debug_assert_eq!(entries.count_ones(), 1);
+        TranspositionTable {
+            entries: vec![None; entries].into_boxed_slice(),
+            mask: entries - 1,
+        }
+    }
+
+    /// Forgets all entries, e.g. before starting a new game
+    pub fn clear(&mut self) {
+        self.entries.fill(None);
+    }
+
+    pub fn probe(&self, key: u64) -> Option<Entry> {
+        let entry = unsafe { self.entries.get_unchecked(key as usize & self.mask) };
+        entry.filter(|entry| entry.key == key)
+    }
+
+    /// Stores the result of a search, replacing the existing entry unless it was searched deeper
+    /// for the same position
+    pub fn store(
+        &mut self,
+        key: u64,
+        best_move: Option<Move>,
+        score: i32,
+        depth: u8,
+        bound: Bound,
+    ) {
+        let entry = unsafe { self.entries.get_unchecked_mut(key as usize & self.mask) };
+        if let Some(existing) = entry {
+            if existing.key == key && existing.depth > depth {
+                return;
+            }
+        }
+
+        *entry = Some(Entry {
+            key,
+            best_move,
+            score,
+            depth,
+            bound,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        position::transposition::{Bound, TranspositionTable},
+        r#move::Move,
+        square::Square,
+    };
+
+    #[test]
+    fn store_and_probe() {
+        let mut table = TranspositionTable::new(1 << 4);
+        let m = Move::new_push(Square(12), Square(28));
+
+        assert!(table.probe(3).is_none());
+        table.store(3, Some(m), 25, 4, Bound::Exact);
+        let entry = table.probe(3).unwrap();
+        assert_eq!(entry.best_move, Some(m));
+        assert_eq!(
+            (entry.score, entry.depth, entry.bound),
+            (25, 4, Bound::Exact)
+        );
+
+        // Shallower results of the same position don't replace deeper ones
+        table.store(3, None, -10, 2, Bound::Upper);
+        assert_eq!(table.probe(3).unwrap().depth, 4);
+
+        // Other positions always do
+        table.store(3 + (1 << 4), None, -10, 1, Bound::Lower);
+        assert!(table.probe(3).is_none());
+        assert_eq!(table.probe(3 + (1 << 4)).unwrap().bound, Bound::Lower);
+
+        table.clear();
+        assert!(table.probe(3 + (1 << 4)).is_none());
+    }
+}
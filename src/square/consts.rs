@@ -1,8851 +1,4 @@
 use crate::board::Board;
 
-pub static DIAGONAL_RAYS: [Board; 64] = [
-    Board(0x8040_2010_0804_0200), // a1
-    Board(0x0080_4020_1008_0500), // b1
-    Board(0x0000_8040_2011_0a00), // c1
-    Board(0x0000_0080_4122_1400), // d1
-    Board(0x0000_0001_8244_2800), // e1
-    Board(0x0000_0102_0488_5000), // f1
-    Board(0x0001_0204_0810_a000), // g1
-    Board(0x0102_0408_1020_4000), // h1
-    Board(0x4020_1008_0402_0002), // a2
-    Board(0x8040_2010_0805_0005), // b2
-    Board(0x0080_4020_110a_000a), // c2
-    Board(0x0000_8041_2214_0014), // d2
-    Board(0x0000_0182_4428_0028), // e2
-    Board(0x0001_0204_8850_0050), // f2
-    Board(0x0102_0408_10a0_00a0), // g2
-    Board(0x0204_0810_2040_0040), // h2
-    Board(0x2010_0804_0200_0204), // a3
-    Board(0x4020_1008_0500_0508), // b3
-    Board(0x8040_2011_0a00_0a11), // c3
-    Board(0x0080_4122_1400_1422), // d3
-    Board(0x0001_8244_2800_2844), // e3
-    Board(0x0102_0488_5000_5088), // f3
-    Board(0x0204_0810_a000_a010), // g3
-    Board(0x0408_1020_4000_4020), // h3
-    Board(0x1008_0402_0002_0408), // a4
-    Board(0x2010_0805_0005_0810), // b4
-    Board(0x4020_110a_000a_1120), // c4
-    Board(0x8041_2214_0014_2241), // d4
-    Board(0x0182_4428_0028_4482), // e4
-    Board(0x0204_8850_0050_8804), // f4
-    Board(0x0408_10a0_00a0_1008), // g4
-    Board(0x0810_2040_0040_2010), // h4
-    Board(0x0804_0200_0204_0810), // a5
-    Board(0x1008_0500_0508_1020), // b5
-    Board(0x2011_0a00_0a11_2040), // c5
-    Board(0x4122_1400_1422_4180), // d5
-    Board(0x8244_2800_2844_8201), // e5
-    Board(0x0488_5000_5088_0402), // f5
-    Board(0x0810_a000_a010_0804), // g5
-    Board(0x1020_4000_4020_1008), // h5
-    Board(0x0402_0002_0408_1020), // a6
-    Board(0x0805_0005_0810_2040), // b6
-    Board(0x110a_000a_1120_4080), // c6
-    Board(0x2214_0014_2241_8000), // d6
-    Board(0x4428_0028_4482_0100), // e6
-    Board(0x8850_0050_8804_0201), // f6
-    Board(0x10a0_00a0_1008_0402), // g6
-    Board(0x2040_0040_2010_0804), // h6
-    Board(0x0200_0204_0810_2040), // a7
-    Board(0x0500_0508_1020_4080), // b7
-    Board(0x0a00_0a11_2040_8000), // c7
-    Board(0x1400_1422_4180_0000), // d7
-    Board(0x2800_2844_8201_0000), // e7
-    Board(0x5000_5088_0402_0100), // f7
-    Board(0xa000_a010_0804_0201), // g7
-    Board(0x4000_4020_1008_0402), // h7
-    Board(0x0002_0408_1020_4080), // a8
-    Board(0x0005_0810_2040_8000), // b8
-    Board(0x000a_1120_4080_0000), // c8
-    Board(0x0014_2241_8000_0000), // d8
-    Board(0x0028_4482_0100_0000), // e8
-    Board(0x0050_8804_0201_0000), // f8
-    Board(0x00a0_1008_0402_0100), // g8
-    Board(0x0040_2010_0804_0201), // h8
-];
-
-pub static STRAIGHT_RAYS: [Board; 64] = [
-    Board(0x0101_0101_0101_01FE), // a1
-    Board(0x0202_0202_0202_02FD), // b1
-    Board(0x0404_0404_0404_04FB), // c1
-    Board(0x0808_0808_0808_08F7), // d1
-    Board(0x1010_1010_1010_10EF), // e1
-    Board(0x2020_2020_2020_20DF), // f1
-    Board(0x4040_4040_4040_40BF), // g1
-    Board(0x8080_8080_8080_807F), // h1
-    Board(0x0101_0101_0101_FE01), // a2
-    Board(0x0202_0202_0202_FD02), // b2
-    Board(0x0404_0404_0404_FB04), // c2
-    Board(0x0808_0808_0808_F708), // d2
-    Board(0x1010_1010_1010_EF10), // e2
-    Board(0x2020_2020_2020_DF20), // f2
-    Board(0x4040_4040_4040_BF40), // g2
-    Board(0x8080_8080_8080_7F80), // h2
-    Board(0x0101_0101_01FE_0101), // a3
-    Board(0x0202_0202_02FD_0202), // b3
-    Board(0x0404_0404_04FB_0404), // c3
-    Board(0x0808_0808_08F7_0808), // d3
-    Board(0x1010_1010_10EF_1010), // e3
-    Board(0x2020_2020_20DF_2020), // f3
-    Board(0x4040_4040_40BF_4040), // g3
-    Board(0x8080_8080_807F_8080), // h3
-    Board(0x0101_0101_FE01_0101), // a4
-    Board(0x0202_0202_FD02_0202), // b4
-    Board(0x0404_0404_FB04_0404), // c4
-    Board(0x0808_0808_F708_0808), // d4
-    Board(0x1010_1010_EF10_1010), // e4
-    Board(0x2020_2020_DF20_2020), // f4
-    Board(0x4040_4040_BF40_4040), // g4
-    Board(0x8080_8080_7F80_8080), // h4
-    Board(0x0101_01FE_0101_0101), // a5
-    Board(0x0202_02FD_0202_0202), // b5
-    Board(0x0404_04FB_0404_0404), // c5
-    Board(0x0808_08F7_0808_0808), // d5
-    Board(0x1010_10EF_1010_1010), // e5
-    Board(0x2020_20DF_2020_2020), // f5
-    Board(0x4040_40BF_4040_4040), // g5
-    Board(0x8080_807F_8080_8080), // h5
-    Board(0x0101_FE01_0101_0101), // a6
-    Board(0x0202_FD02_0202_0202), // b6
-    Board(0x0404_FB04_0404_0404), // c6
-    Board(0x0808_F708_0808_0808), // d6
-    Board(0x1010_EF10_1010_1010), // e6
-    Board(0x2020_DF20_2020_2020), // f6
-    Board(0x4040_BF40_4040_4040), // g6
-    Board(0x8080_7F80_8080_8080), // h6
-    Board(0x01FE_0101_0101_0101), // a7
-    Board(0x02FD_0202_0202_0202), // b7
-    Board(0x04FB_0404_0404_0404), // c7
-    Board(0x08F7_0808_0808_0808), // d7
-    Board(0x10EF_1010_1010_1010), // e7
-    Board(0x20DF_2020_2020_2020), // f7
-    Board(0x40BF_4040_4040_4040), // g7
-    Board(0x807F_8080_8080_8080), // h7
-    Board(0xFE01_0101_0101_0101), // a8
-    Board(0xFD02_0202_0202_0202), // b8
-    Board(0xFB04_0404_0404_0404), // c8
-    Board(0xF708_0808_0808_0808), // d8
-    Board(0xEF10_1010_1010_1010), // e8
-    Board(0xDF20_2020_2020_2020), // f8
-    Board(0xBF40_4040_4040_4040), // g8
-    Board(0x7F80_8080_8080_8080), // h8
-];
-
-pub static KING_MOVES: [Board; 64] = [
-    Board(0x0000_0000_0000_0302), // a1
-    Board(0x0000_0000_0000_0705), // b1
-    Board(0x0000_0000_0000_0E0A), // c1
-    Board(0x0000_0000_0000_1C14), // d1
-    Board(0x0000_0000_0000_3828), // e1
-    Board(0x0000_0000_0000_7050), // f1
-    Board(0x0000_0000_0000_E0A0), // g1
-    Board(0x0000_0000_0000_C040), // h1
-    Board(0x0000_0000_0003_0203), // a2
-    Board(0x0000_0000_0007_0507), // b2
-    Board(0x0000_0000_000E_0A0E), // c2
-    Board(0x0000_0000_001C_141C), // d2
-    Board(0x0000_0000_0038_2838), // e2
-    Board(0x0000_0000_0070_5070), // f2
-    Board(0x0000_0000_00E0_A0E0), // g2
-    Board(0x0000_0000_00C0_40C0), // h2
-    Board(0x0000_0000_0302_0300), // a3
-    Board(0x0000_0000_0705_0700), // b3
-    Board(0x0000_0000_0E0A_0E00), // c3
-    Board(0x0000_0000_1C14_1C00), // d3
-    Board(0x0000_0000_3828_3800), // e3
-    Board(0x0000_0000_7050_7000), // f3
-    Board(0x0000_0000_E0A0_E000), // g3
-    Board(0x0000_0000_C040_C000), // h3
-    Board(0x0000_0003_0203_0000), // a4
-    Board(0x0000_0007_0507_0000), // b4
-    Board(0x0000_000E_0A0E_0000), // c4
-    Board(0x0000_001C_141C_0000), // d4
-    Board(0x0000_0038_2838_0000), // e4
-    Board(0x0000_0070_5070_0000), // f4
-    Board(0x0000_00E0_A0E0_0000), // g4
-    Board(0x0000_00C0_40C0_0000), // h4
-    Board(0x0000_0302_0300_0000), // a5
-    Board(0x0000_0705_0700_0000), // b5
-    Board(0x0000_0E0A_0E00_0000), // c5
-    Board(0x0000_1C14_1C00_0000), // d5
-    Board(0x0000_3828_3800_0000), // e5
-    Board(0x0000_7050_7000_0000), // f5
-    Board(0x0000_E0A0_E000_0000), // g5
-    Board(0x0000_C040_C000_0000), // h5
-    Board(0x0003_0203_0000_0000), // a6
-    Board(0x0007_0507_0000_0000), // b6
-    Board(0x000E_0A0E_0000_0000), // c6
-    Board(0x001C_141C_0000_0000), // d6
-    Board(0x0038_2838_0000_0000), // e6
-    Board(0x0070_5070_0000_0000), // f6
-    Board(0x00E0_A0E0_0000_0000), // g6
-    Board(0x00C0_40C0_0000_0000), // h6
-    Board(0x0302_0300_0000_0000), // a7
-    Board(0x0705_0700_0000_0000), // b7
-    Board(0x0E0A_0E00_0000_0000), // c7
-    Board(0x1C14_1C00_0000_0000), // d7
-    Board(0x3828_3800_0000_0000), // e7
-    Board(0x7050_7000_0000_0000), // f7
-    Board(0xE0A0_E000_0000_0000), // g7
-    Board(0xC040_C000_0000_0000), // h7
-    Board(0x0203_0000_0000_0000), // a8
-    Board(0x0507_0000_0000_0000), // b8
-    Board(0x0A0E_0000_0000_0000), // c8
-    Board(0x141C_0000_0000_0000), // d8
-    Board(0x2838_0000_0000_0000), // e8
-    Board(0x5070_0000_0000_0000), // f8
-    Board(0xA0E0_0000_0000_0000), // g8
-    Board(0x40C0_0000_0000_0000), // h8
-];
-
-pub static KNIGHT_MOVES: [Board; 64] = [
-    Board(0x0000_0000_0002_0400), // a1
-    Board(0x0000_0000_0005_0800), // b1
-    Board(0x0000_0000_000A_1100), // c1
-    Board(0x0000_0000_0014_2200), // d1
-    Board(0x0000_0000_0028_4400), // e1
-    Board(0x0000_0000_0050_8800), // f1
-    Board(0x0000_0000_00A0_1000), // g1
-    Board(0x0000_0000_0040_2000), // h1
-    Board(0x0000_0000_0204_0004), // a2
-    Board(0x0000_0000_0508_0008), // b2
-    Board(0x0000_0000_0A11_0011), // c2
-    Board(0x0000_0000_1422_0022), // d2
-    Board(0x0000_0000_2844_0044), // e2
-    Board(0x0000_0000_5088_0088), // f2
-    Board(0x0000_0000_A010_0010), // g2
-    Board(0x0000_0000_4020_0020), // h2
-    Board(0x0000_0002_0400_0402), // a3
-    Board(0x0000_0005_0800_0805), // b3
-    Board(0x0000_000A_1100_110A), // c3
-    Board(0x0000_0014_2200_2214), // d3
-    Board(0x0000_0028_4400_4428), // e3
-    Board(0x0000_0050_8800_8850), // f3
-    Board(0x0000_00A0_1000_10A0), // g3
-    Board(0x0000_0040_2000_2040), // h3
-    Board(0x0000_0204_0004_0200), // a4
-    Board(0x0000_0508_0008_0500), // b4
-    Board(0x0000_0A11_0011_0A00), // c4
-    Board(0x0000_1422_0022_1400), // d4
-    Board(0x0000_2844_0044_2800), // e4
-    Board(0x0000_5088_0088_5000), // f4
-    Board(0x0000_A010_0010_A000), // g4
-    Board(0x0000_4020_0020_4000), // h4
-    Board(0x0002_0400_0402_0000), // a5
-    Board(0x0005_0800_0805_0000), // b5
-    Board(0x000A_1100_110A_0000), // c5
-    Board(0x0014_2200_2214_0000), // d5
-    Board(0x0028_4400_4428_0000), // e5
-    Board(0x0050_8800_8850_0000), // f5
-    Board(0x00A0_1000_10A0_0000), // g5
-    Board(0x0040_2000_2040_0000), // h5
-    Board(0x0204_0004_0200_0000), // a6
-    Board(0x0508_0008_0500_0000), // b6
-    Board(0x0A11_0011_0A00_0000), // c6
-    Board(0x1422_0022_1400_0000), // d6
-    Board(0x2844_0044_2800_0000), // e6
-    Board(0x5088_0088_5000_0000), // f6
-    Board(0xA010_0010_A000_0000), // g6
-    Board(0x4020_0020_4000_0000), // h6
-    Board(0x0400_0402_0000_0000), // a7
-    Board(0x0800_0805_0000_0000), // b7
-    Board(0x1100_110A_0000_0000), // c7
-    Board(0x2200_2214_0000_0000), // d7
-    Board(0x4400_4428_0000_0000), // e7
-    Board(0x8800_8850_0000_0000), // f7
-    Board(0x1000_10A0_0000_0000), // g7
-    Board(0x2000_2040_0000_0000), // h7
-    Board(0x0004_0200_0000_0000), // a8
-    Board(0x0008_0500_0000_0000), // b8
-    Board(0x0011_0A00_0000_0000), // c8
-    Board(0x0022_1400_0000_0000), // d8
-    Board(0x0044_2800_0000_0000), // e8
-    Board(0x0088_5000_0000_0000), // f8
-    Board(0x0010_A000_0000_0000), // g8
-    Board(0x0020_4000_0000_0000), // h8
-];
-
-pub static SQUARES_BETWEEN: [[Board; 64]; 64] = [
-    // a1
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0002), // c1
-        Board(0x0000_0000_0000_0006), // d1
-        Board(0x0000_0000_0000_000e), // e1
-        Board(0x0000_0000_0000_001e), // f1
-        Board(0x0000_0000_0000_003e), // g1
-        Board(0x0000_0000_0000_007e), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0100), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0200), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0001_0100), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0004_0200), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0101_0100), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0804_0200), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0001_0101_0100), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0010_0804_0200), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0101_0101_0100), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_2010_0804_0200), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0001_0101_0101_0100), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0040_2010_0804_0200), // h8
-    ],
-    // b1
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0004), // d1
-        Board(0x0000_0000_0000_000c), // e1
-        Board(0x0000_0000_0000_001c), // f1
-        Board(0x0000_0000_0000_003c), // g1
-        Board(0x0000_0000_0000_007c), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0200), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0400), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0002_0200), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0008_0400), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0202_0200), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_1008_0400), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0002_0202_0200), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0020_1008_0400), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0202_0202_0200), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_4020_1008_0400), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0002_0202_0202_0200), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // c1
-    [
-        Board(0x0000_0000_0000_0002), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0008), // e1
-        Board(0x0000_0000_0000_0018), // f1
-        Board(0x0000_0000_0000_0038), // g1
-        Board(0x0000_0000_0000_0078), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0200), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0400), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0800), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0004_0400), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0010_0800), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0404_0400), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_2010_0800), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0004_0404_0400), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0040_2010_0800), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0404_0404_0400), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0004_0404_0404_0400), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // d1
-    [
-        Board(0x0000_0000_0000_0006), // a1
-        Board(0x0000_0000_0000_0004), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0010), // f1
-        Board(0x0000_0000_0000_0030), // g1
-        Board(0x0000_0000_0000_0070), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0400), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0800), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_1000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0002_0400), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0008_0800), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0020_1000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0808_0800), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_4020_1000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0008_0808_0800), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0808_0808_0800), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0008_0808_0808_0800), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // e1
-    [
-        Board(0x0000_0000_0000_000e), // a1
-        Board(0x0000_0000_0000_000c), // b1
-        Board(0x0000_0000_0000_0008), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0020), // g1
-        Board(0x0000_0000_0000_0060), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0800), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_1000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_2000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0004_0800), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0010_1000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0040_2000), // h4
-        Board(0x0000_0000_0204_0800), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_1010_1000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0010_1010_1000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_1010_1010_1000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0010_1010_1010_1000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // f1
-    [
-        Board(0x0000_0000_0000_001e), // a1
-        Board(0x0000_0000_0000_001c), // b1
-        Board(0x0000_0000_0000_0018), // c1
-        Board(0x0000_0000_0000_0010), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0040), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_1000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_2000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_4000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0008_1000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0020_2000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0408_1000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_2020_2000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0002_0408_1000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0020_2020_2000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_2020_2020_2000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0020_2020_2020_2000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // g1
-    [
-        Board(0x0000_0000_0000_003e), // a1
-        Board(0x0000_0000_0000_003c), // b1
-        Board(0x0000_0000_0000_0038), // c1
-        Board(0x0000_0000_0000_0030), // d1
-        Board(0x0000_0000_0000_0020), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_2000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_4000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0010_2000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0040_4000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0810_2000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_4040_4000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0004_0810_2000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0040_4040_4000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0204_0810_2000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_4040_4040_4000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0040_4040_4040_4000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // h1
-    [
-        Board(0x0000_0000_0000_007e), // a1
-        Board(0x0000_0000_0000_007c), // b1
-        Board(0x0000_0000_0000_0078), // c1
-        Board(0x0000_0000_0000_0070), // d1
-        Board(0x0000_0000_0000_0060), // e1
-        Board(0x0000_0000_0000_0040), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_4000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_8000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0020_4000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0080_8000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_1020_4000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_8080_8000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0008_1020_4000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0080_8080_8000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0408_1020_4000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_8080_8080_8000), // h7
-        Board(0x0002_0408_1020_4000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0080_8080_8080_8000), // h8
-    ],
-    // a2
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0200), // c2
-        Board(0x0000_0000_0000_0600), // d2
-        Board(0x0000_0000_0000_0e00), // e2
-        Board(0x0000_0000_0000_1e00), // f2
-        Board(0x0000_0000_0000_3e00), // g2
-        Board(0x0000_0000_0000_7e00), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0001_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0002_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0101_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0402_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0001_0101_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0008_0402_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0101_0101_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_1008_0402_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0001_0101_0101_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0020_1008_0402_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // b2
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0400), // d2
-        Board(0x0000_0000_0000_0c00), // e2
-        Board(0x0000_0000_0000_1c00), // f2
-        Board(0x0000_0000_0000_3c00), // g2
-        Board(0x0000_0000_0000_7c00), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0002_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0004_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0202_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0804_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0002_0202_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0010_0804_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0202_0202_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_2010_0804_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0002_0202_0202_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0040_2010_0804_0000), // h8
-    ],
-    // c2
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0200), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0800), // e2
-        Board(0x0000_0000_0000_1800), // f2
-        Board(0x0000_0000_0000_3800), // g2
-        Board(0x0000_0000_0000_7800), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0002_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0004_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0008_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0404_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_1008_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0004_0404_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0020_1008_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0404_0404_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_4020_1008_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0004_0404_0404_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // d2
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0600), // a2
-        Board(0x0000_0000_0000_0400), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_1000), // f2
-        Board(0x0000_0000_0000_3000), // g2
-        Board(0x0000_0000_0000_7000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0004_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0008_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0010_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0204_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0808_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_2010_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0008_0808_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0040_2010_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0808_0808_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0008_0808_0808_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // e2
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0e00), // a2
-        Board(0x0000_0000_0000_0c00), // b2
-        Board(0x0000_0000_0000_0800), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_2000), // g2
-        Board(0x0000_0000_0000_6000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0008_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0010_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0020_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0408_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_1010_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_4020_0000), // h5
-        Board(0x0000_0002_0408_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0010_1010_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_1010_1010_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0010_1010_1010_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // f2
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_1e00), // a2
-        Board(0x0000_0000_0000_1c00), // b2
-        Board(0x0000_0000_0000_1800), // c2
-        Board(0x0000_0000_0000_1000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_4000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0010_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0020_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0040_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0810_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_2020_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0004_0810_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0020_2020_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0204_0810_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_2020_2020_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0020_2020_2020_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // g2
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_3e00), // a2
-        Board(0x0000_0000_0000_3c00), // b2
-        Board(0x0000_0000_0000_3800), // c2
-        Board(0x0000_0000_0000_3000), // d2
-        Board(0x0000_0000_0000_2000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0020_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0040_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_1020_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_4040_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0008_1020_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0040_4040_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0408_1020_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_4040_4040_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0002_0408_1020_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0040_4040_4040_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // h2
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_7e00), // a2
-        Board(0x0000_0000_0000_7c00), // b2
-        Board(0x0000_0000_0000_7800), // c2
-        Board(0x0000_0000_0000_7000), // d2
-        Board(0x0000_0000_0000_6000), // e2
-        Board(0x0000_0000_0000_4000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0040_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0080_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_2040_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_8080_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0010_2040_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0080_8080_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0810_2040_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_8080_8080_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0004_0810_2040_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0080_8080_8080_0000), // h8
-    ],
-    // a3
-    [
-        Board(0x0000_0000_0000_0100), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0200), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0002_0000), // c3
-        Board(0x0000_0000_0006_0000), // d3
-        Board(0x0000_0000_000e_0000), // e3
-        Board(0x0000_0000_001e_0000), // f3
-        Board(0x0000_0000_003e_0000), // g3
-        Board(0x0000_0000_007e_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0100_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0200_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0001_0100_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0004_0200_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0101_0100_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0804_0200_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0001_0101_0100_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0010_0804_0200_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // b3
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0200), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0400), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0004_0000), // d3
-        Board(0x0000_0000_000c_0000), // e3
-        Board(0x0000_0000_001c_0000), // f3
-        Board(0x0000_0000_003c_0000), // g3
-        Board(0x0000_0000_007c_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0200_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0400_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0002_0200_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0008_0400_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0202_0200_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_1008_0400_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0002_0202_0200_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0020_1008_0400_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // c3
-    [
-        Board(0x0000_0000_0000_0200), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0400), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0800), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0002_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0008_0000), // e3
-        Board(0x0000_0000_0018_0000), // f3
-        Board(0x0000_0000_0038_0000), // g3
-        Board(0x0000_0000_0078_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0200_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0400_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0800_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0004_0400_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0010_0800_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0404_0400_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_2010_0800_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0004_0404_0400_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0040_2010_0800_0000), // h8
-    ],
-    // d3
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0400), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0800), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_1000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0006_0000), // a3
-        Board(0x0000_0000_0004_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0010_0000), // f3
-        Board(0x0000_0000_0030_0000), // g3
-        Board(0x0000_0000_0070_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0400_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0800_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_1000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0002_0400_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0008_0800_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0020_1000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0808_0800_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_4020_1000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0008_0808_0800_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // e3
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0800), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_1000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_2000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_000e_0000), // a3
-        Board(0x0000_0000_000c_0000), // b3
-        Board(0x0000_0000_0008_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0020_0000), // g3
-        Board(0x0000_0000_0060_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0800_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_1000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_2000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0004_0800_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0010_1000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0040_2000_0000), // h6
-        Board(0x0000_0204_0800_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_1010_1000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0010_1010_1000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // f3
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_1000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_2000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_4000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_001e_0000), // a3
-        Board(0x0000_0000_001c_0000), // b3
-        Board(0x0000_0000_0018_0000), // c3
-        Board(0x0000_0000_0010_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0040_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_1000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_2000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_4000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0008_1000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0020_2000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0408_1000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_2020_2000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0002_0408_1000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0020_2020_2000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // g3
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_2000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_4000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_003e_0000), // a3
-        Board(0x0000_0000_003c_0000), // b3
-        Board(0x0000_0000_0038_0000), // c3
-        Board(0x0000_0000_0030_0000), // d3
-        Board(0x0000_0000_0020_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_2000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_4000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0010_2000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0040_4000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0810_2000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_4040_4000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0004_0810_2000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0040_4040_4000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // h3
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_4000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_8000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_007e_0000), // a3
-        Board(0x0000_0000_007c_0000), // b3
-        Board(0x0000_0000_0078_0000), // c3
-        Board(0x0000_0000_0070_0000), // d3
-        Board(0x0000_0000_0060_0000), // e3
-        Board(0x0000_0000_0040_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_4000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_8000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0020_4000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0080_8000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_1020_4000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_8080_8000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0008_1020_4000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0080_8080_8000_0000), // h8
-    ],
-    // a4
-    [
-        Board(0x0000_0000_0001_0100), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0002_0400), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0001_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0002_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0200_0000), // c4
-        Board(0x0000_0000_0600_0000), // d4
-        Board(0x0000_0000_0e00_0000), // e4
-        Board(0x0000_0000_1e00_0000), // f4
-        Board(0x0000_0000_3e00_0000), // g4
-        Board(0x0000_0000_7e00_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0001_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0002_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0101_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0402_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0001_0101_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0008_0402_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // b4
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0002_0200), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0004_0800), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0002_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0004_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0400_0000), // d4
-        Board(0x0000_0000_0c00_0000), // e4
-        Board(0x0000_0000_1c00_0000), // f4
-        Board(0x0000_0000_3c00_0000), // g4
-        Board(0x0000_0000_7c00_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0002_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0004_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0202_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0804_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0002_0202_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0010_0804_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // c4
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0004_0400), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0008_1000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0002_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0004_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0008_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0200_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0800_0000), // e4
-        Board(0x0000_0000_1800_0000), // f4
-        Board(0x0000_0000_3800_0000), // g4
-        Board(0x0000_0000_7800_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0002_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0004_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0008_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0404_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_1008_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0004_0404_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0020_1008_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // d4
-    [
-        Board(0x0000_0000_0004_0200), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0008_0800), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0010_2000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0004_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0008_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0010_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0600_0000), // a4
-        Board(0x0000_0000_0400_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_1000_0000), // f4
-        Board(0x0000_0000_3000_0000), // g4
-        Board(0x0000_0000_7000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0004_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0008_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0010_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0204_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0808_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_2010_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0008_0808_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0040_2010_0000_0000), // h8
-    ],
-    // e4
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0008_0400), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0010_1000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0020_4000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0008_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0010_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0020_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0e00_0000), // a4
-        Board(0x0000_0000_0c00_0000), // b4
-        Board(0x0000_0000_0800_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_2000_0000), // g4
-        Board(0x0000_0000_6000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0008_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0010_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0020_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0408_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_1010_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_4020_0000_0000), // h7
-        Board(0x0002_0408_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0010_1010_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // f4
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0010_0800), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0020_2000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0010_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0020_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0040_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_1e00_0000), // a4
-        Board(0x0000_0000_1c00_0000), // b4
-        Board(0x0000_0000_1800_0000), // c4
-        Board(0x0000_0000_1000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_4000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0010_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0020_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0040_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0810_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_2020_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0004_0810_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0020_2020_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // g4
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0020_1000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0040_4000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0020_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0040_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_3e00_0000), // a4
-        Board(0x0000_0000_3c00_0000), // b4
-        Board(0x0000_0000_3800_0000), // c4
-        Board(0x0000_0000_3000_0000), // d4
-        Board(0x0000_0000_2000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0020_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0040_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_1020_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_4040_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0008_1020_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0040_4040_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // h4
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0040_2000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0080_8000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0040_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0080_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_7e00_0000), // a4
-        Board(0x0000_0000_7c00_0000), // b4
-        Board(0x0000_0000_7800_0000), // c4
-        Board(0x0000_0000_7000_0000), // d4
-        Board(0x0000_0000_6000_0000), // e4
-        Board(0x0000_0000_4000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0040_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0080_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_2040_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_8080_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0010_2040_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0080_8080_0000_0000), // h8
-    ],
-    // a5
-    [
-        Board(0x0000_0000_0101_0100), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0204_0800), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0101_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0204_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0100_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0200_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0002_0000_0000), // c5
-        Board(0x0000_0006_0000_0000), // d5
-        Board(0x0000_000e_0000_0000), // e5
-        Board(0x0000_001e_0000_0000), // f5
-        Board(0x0000_003e_0000_0000), // g5
-        Board(0x0000_007e_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0100_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0200_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0001_0100_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0004_0200_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // b5
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0202_0200), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0408_1000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0202_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0408_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0200_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0400_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0004_0000_0000), // d5
-        Board(0x0000_000c_0000_0000), // e5
-        Board(0x0000_001c_0000_0000), // f5
-        Board(0x0000_003c_0000_0000), // g5
-        Board(0x0000_007c_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0200_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0400_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0002_0200_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0008_0400_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // c5
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0404_0400), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0810_2000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0404_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0810_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0200_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0400_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0800_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0002_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0008_0000_0000), // e5
-        Board(0x0000_0018_0000_0000), // f5
-        Board(0x0000_0038_0000_0000), // g5
-        Board(0x0000_0078_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0200_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0400_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0800_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0004_0400_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0010_0800_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // d5
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0808_0800), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_1020_4000), // h1
-        Board(0x0000_0000_0402_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0808_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_1020_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0400_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0800_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_1000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0006_0000_0000), // a5
-        Board(0x0000_0004_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0010_0000_0000), // f5
-        Board(0x0000_0030_0000_0000), // g5
-        Board(0x0000_0070_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0400_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0800_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_1000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0002_0400_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0008_0800_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0020_1000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // e5
-    [
-        Board(0x0000_0000_0804_0200), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_1010_1000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0804_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_1010_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_2040_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0800_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_1000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_2000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_000e_0000_0000), // a5
-        Board(0x0000_000c_0000_0000), // b5
-        Board(0x0000_0008_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0020_0000_0000), // g5
-        Board(0x0000_0060_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0800_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_1000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_2000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0004_0800_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0010_1000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0040_2000_0000_0000), // h8
-    ],
-    // f5
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_1008_0400), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_2020_2000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_1008_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_2020_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_1000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_2000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_4000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_001e_0000_0000), // a5
-        Board(0x0000_001c_0000_0000), // b5
-        Board(0x0000_0018_0000_0000), // c5
-        Board(0x0000_0010_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0040_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_1000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_2000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_4000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0008_1000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0020_2000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // g5
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_2010_0800), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_4040_4000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_2010_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_4040_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_2000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_4000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_003e_0000_0000), // a5
-        Board(0x0000_003c_0000_0000), // b5
-        Board(0x0000_0038_0000_0000), // c5
-        Board(0x0000_0030_0000_0000), // d5
-        Board(0x0000_0020_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_2000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_4000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0010_2000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0040_4000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // h5
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_4020_1000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_8080_8000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_4020_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_8080_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_4000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_8000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_007e_0000_0000), // a5
-        Board(0x0000_007c_0000_0000), // b5
-        Board(0x0000_0078_0000_0000), // c5
-        Board(0x0000_0070_0000_0000), // d5
-        Board(0x0000_0060_0000_0000), // e5
-        Board(0x0000_0040_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_4000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_8000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0020_4000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0080_8000_0000_0000), // h8
-    ],
-    // a6
-    [
-        Board(0x0000_0001_0101_0100), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0002_0408_1000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0001_0101_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0002_0408_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0001_0100_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0002_0400_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0001_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0002_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0200_0000_0000), // c6
-        Board(0x0000_0600_0000_0000), // d6
-        Board(0x0000_0e00_0000_0000), // e6
-        Board(0x0000_1e00_0000_0000), // f6
-        Board(0x0000_3e00_0000_0000), // g6
-        Board(0x0000_7e00_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0001_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0002_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // b6
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0002_0202_0200), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0004_0810_2000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0002_0202_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0004_0810_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0002_0200_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0004_0800_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0002_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0004_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0400_0000_0000), // d6
-        Board(0x0000_0c00_0000_0000), // e6
-        Board(0x0000_1c00_0000_0000), // f6
-        Board(0x0000_3c00_0000_0000), // g6
-        Board(0x0000_7c00_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0002_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0004_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // c6
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0004_0404_0400), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0008_1020_4000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0004_0404_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0008_1020_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0004_0400_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0008_1000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0002_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0004_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0008_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0200_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0800_0000_0000), // e6
-        Board(0x0000_1800_0000_0000), // f6
-        Board(0x0000_3800_0000_0000), // g6
-        Board(0x0000_7800_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0002_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0004_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0008_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // d6
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0008_0808_0800), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0008_0808_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0010_2040_0000), // h2
-        Board(0x0000_0004_0200_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0008_0800_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0010_2000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0004_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0008_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0010_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0600_0000_0000), // a6
-        Board(0x0000_0400_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_1000_0000_0000), // f6
-        Board(0x0000_3000_0000_0000), // g6
-        Board(0x0000_7000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0004_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0008_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0010_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // e6
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0010_1010_1000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0008_0402_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0010_1010_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0008_0400_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0010_1000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0020_4000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0008_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0010_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0020_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0e00_0000_0000), // a6
-        Board(0x0000_0c00_0000_0000), // b6
-        Board(0x0000_0800_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_2000_0000_0000), // g6
-        Board(0x0000_6000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0008_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0010_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0020_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // f6
-    [
-        Board(0x0000_0010_0804_0200), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0020_2020_2000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0010_0804_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0020_2020_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0010_0800_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0020_2000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0010_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0020_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0040_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_1e00_0000_0000), // a6
-        Board(0x0000_1c00_0000_0000), // b6
-        Board(0x0000_1800_0000_0000), // c6
-        Board(0x0000_1000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_4000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0010_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0020_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0040_0000_0000_0000), // h8
-    ],
-    // g6
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0020_1008_0400), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0040_4040_4000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0020_1008_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0040_4040_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0020_1000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0040_4000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0020_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0040_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_3e00_0000_0000), // a6
-        Board(0x0000_3c00_0000_0000), // b6
-        Board(0x0000_3800_0000_0000), // c6
-        Board(0x0000_3000_0000_0000), // d6
-        Board(0x0000_2000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0020_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0040_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // h6
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0040_2010_0800), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0080_8080_8000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0040_2010_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0080_8080_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0040_2000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0080_8000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0040_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0080_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_7e00_0000_0000), // a6
-        Board(0x0000_7c00_0000_0000), // b6
-        Board(0x0000_7800_0000_0000), // c6
-        Board(0x0000_7000_0000_0000), // d6
-        Board(0x0000_6000_0000_0000), // e6
-        Board(0x0000_4000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0040_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0080_0000_0000_0000), // h8
-    ],
-    // a7
-    [
-        Board(0x0000_0101_0101_0100), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0204_0810_2000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0101_0101_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0204_0810_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0101_0100_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0204_0800_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0101_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0204_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0100_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0200_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0002_0000_0000_0000), // c7
-        Board(0x0006_0000_0000_0000), // d7
-        Board(0x000e_0000_0000_0000), // e7
-        Board(0x001e_0000_0000_0000), // f7
-        Board(0x003e_0000_0000_0000), // g7
-        Board(0x007e_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // b7
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0202_0202_0200), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0408_1020_4000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0202_0202_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0408_1020_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0202_0200_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0408_1000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0202_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0408_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0200_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0400_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0004_0000_0000_0000), // d7
-        Board(0x000c_0000_0000_0000), // e7
-        Board(0x001c_0000_0000_0000), // f7
-        Board(0x003c_0000_0000_0000), // g7
-        Board(0x007c_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // c7
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0404_0404_0400), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0404_0404_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0810_2040_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0404_0400_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0810_2000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0404_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0810_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0200_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0400_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0800_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0002_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0008_0000_0000_0000), // e7
-        Board(0x0018_0000_0000_0000), // f7
-        Board(0x0038_0000_0000_0000), // g7
-        Board(0x0078_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // d7
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0808_0808_0800), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0808_0808_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0808_0800_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_1020_4000_0000), // h3
-        Board(0x0000_0402_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0808_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_1020_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0400_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0800_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_1000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0006_0000_0000_0000), // a7
-        Board(0x0004_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0010_0000_0000_0000), // f7
-        Board(0x0030_0000_0000_0000), // g7
-        Board(0x0070_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // e7
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_1010_1010_1000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_1010_1010_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0804_0200_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_1010_1000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0804_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_1010_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_2040_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0800_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_1000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_2000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x000e_0000_0000_0000), // a7
-        Board(0x000c_0000_0000_0000), // b7
-        Board(0x0008_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0020_0000_0000_0000), // g7
-        Board(0x0060_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // f7
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_2020_2020_2000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_1008_0402_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_2020_2020_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_1008_0400_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_2020_2000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_1008_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_2020_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_1000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_2000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_4000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x001e_0000_0000_0000), // a7
-        Board(0x001c_0000_0000_0000), // b7
-        Board(0x0018_0000_0000_0000), // c7
-        Board(0x0010_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0040_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // g7
-    [
-        Board(0x0000_2010_0804_0200), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_4040_4040_4000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_2010_0804_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_4040_4040_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_2010_0800_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_4040_4000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_2010_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_4040_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_2000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_4000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x003e_0000_0000_0000), // a7
-        Board(0x003c_0000_0000_0000), // b7
-        Board(0x0038_0000_0000_0000), // c7
-        Board(0x0030_0000_0000_0000), // d7
-        Board(0x0020_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // h7
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_4020_1008_0400), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_8080_8080_8000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_4020_1008_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_8080_8080_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_4020_1000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_8080_8000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_4020_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_8080_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_4000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_8000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x007e_0000_0000_0000), // a7
-        Board(0x007c_0000_0000_0000), // b7
-        Board(0x0078_0000_0000_0000), // c7
-        Board(0x0070_0000_0000_0000), // d7
-        Board(0x0060_0000_0000_0000), // e7
-        Board(0x0040_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // a8
-    [
-        Board(0x0001_0101_0101_0100), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0002_0408_1020_4000), // h1
-        Board(0x0001_0101_0101_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0002_0408_1020_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0001_0101_0100_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0002_0408_1000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0001_0101_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0002_0408_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0001_0100_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0002_0400_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0001_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0002_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0200_0000_0000_0000), // c8
-        Board(0x0600_0000_0000_0000), // d8
-        Board(0x0e00_0000_0000_0000), // e8
-        Board(0x1e00_0000_0000_0000), // f8
-        Board(0x3e00_0000_0000_0000), // g8
-        Board(0x7e00_0000_0000_0000), // h8
-    ],
-    // b8
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0002_0202_0202_0200), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0002_0202_0202_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0004_0810_2040_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0002_0202_0200_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0004_0810_2000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0002_0202_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0004_0810_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0002_0200_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0004_0800_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0002_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0004_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0400_0000_0000_0000), // d8
-        Board(0x0c00_0000_0000_0000), // e8
-        Board(0x1c00_0000_0000_0000), // f8
-        Board(0x3c00_0000_0000_0000), // g8
-        Board(0x7c00_0000_0000_0000), // h8
-    ],
-    // c8
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0004_0404_0404_0400), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0004_0404_0404_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0004_0404_0400_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0008_1020_4000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0004_0404_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0008_1020_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0004_0400_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0008_1000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0002_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0004_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0008_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0200_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0800_0000_0000_0000), // e8
-        Board(0x1800_0000_0000_0000), // f8
-        Board(0x3800_0000_0000_0000), // g8
-        Board(0x7800_0000_0000_0000), // h8
-    ],
-    // d8
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0008_0808_0808_0800), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0008_0808_0808_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0008_0808_0800_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0008_0808_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0010_2040_0000_0000), // h4
-        Board(0x0004_0200_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0008_0800_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0010_2000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0004_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0008_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0010_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0600_0000_0000_0000), // a8
-        Board(0x0400_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x1000_0000_0000_0000), // f8
-        Board(0x3000_0000_0000_0000), // g8
-        Board(0x7000_0000_0000_0000), // h8
-    ],
-    // e8
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0010_1010_1010_1000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0010_1010_1010_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0010_1010_1000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0008_0402_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0010_1010_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0008_0400_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0010_1000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0020_4000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0008_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0010_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0020_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0e00_0000_0000_0000), // a8
-        Board(0x0c00_0000_0000_0000), // b8
-        Board(0x0800_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x2000_0000_0000_0000), // g8
-        Board(0x6000_0000_0000_0000), // h8
-    ],
-    // f8
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0020_2020_2020_2000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0020_2020_2020_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0010_0804_0200_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0020_2020_2000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0010_0804_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0020_2020_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0010_0800_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0020_2000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0010_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0020_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0040_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x1e00_0000_0000_0000), // a8
-        Board(0x1c00_0000_0000_0000), // b8
-        Board(0x1800_0000_0000_0000), // c8
-        Board(0x1000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x4000_0000_0000_0000), // h8
-    ],
-    // g8
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0040_4040_4040_4000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0020_1008_0402_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0040_4040_4040_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0020_1008_0400_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0040_4040_4000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0020_1008_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0040_4040_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0020_1000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0040_4000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0020_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0040_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x3e00_0000_0000_0000), // a8
-        Board(0x3c00_0000_0000_0000), // b8
-        Board(0x3800_0000_0000_0000), // c8
-        Board(0x3000_0000_0000_0000), // d8
-        Board(0x2000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // h8
-    [
-        Board(0x0040_2010_0804_0200), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0080_8080_8080_8000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0040_2010_0804_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0080_8080_8080_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0040_2010_0800_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0080_8080_8000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0040_2010_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0080_8080_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0040_2000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0080_8000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0040_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0080_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x7e00_0000_0000_0000), // a8
-        Board(0x7c00_0000_0000_0000), // b8
-        Board(0x7800_0000_0000_0000), // c8
-        Board(0x7000_0000_0000_0000), // d8
-        Board(0x6000_0000_0000_0000), // e8
-        Board(0x4000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-];
-
-pub static LINES_ALONG: [[Board; 64]; 64] = [
-    // a1
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_00ff), // b1
-        Board(0x0000_0000_0000_00ff), // c1
-        Board(0x0000_0000_0000_00ff), // d1
-        Board(0x0000_0000_0000_00ff), // e1
-        Board(0x0000_0000_0000_00ff), // f1
-        Board(0x0000_0000_0000_00ff), // g1
-        Board(0x0000_0000_0000_00ff), // h1
-        Board(0x0101_0101_0101_0101), // a2
-        Board(0x8040_2010_0804_0201), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0101_0101_0101_0101), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x8040_2010_0804_0201), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0101_0101_0101_0101), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x8040_2010_0804_0201), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0101_0101_0101_0101), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x8040_2010_0804_0201), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0101_0101_0101_0101), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x8040_2010_0804_0201), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0101_0101_0101_0101), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x8040_2010_0804_0201), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0101_0101_0101_0101), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x8040_2010_0804_0201), // h8
-    ],
-    // b1
-    [
-        Board(0x0000_0000_0000_00ff), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_00ff), // c1
-        Board(0x0000_0000_0000_00ff), // d1
-        Board(0x0000_0000_0000_00ff), // e1
-        Board(0x0000_0000_0000_00ff), // f1
-        Board(0x0000_0000_0000_00ff), // g1
-        Board(0x0000_0000_0000_00ff), // h1
-        Board(0x0000_0000_0000_0102), // a2
-        Board(0x0202_0202_0202_0202), // b2
-        Board(0x0080_4020_1008_0402), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0202_0202_0202_0202), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0080_4020_1008_0402), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0202_0202_0202_0202), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0080_4020_1008_0402), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0202_0202_0202_0202), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0080_4020_1008_0402), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0202_0202_0202_0202), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0080_4020_1008_0402), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0202_0202_0202_0202), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0080_4020_1008_0402), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0202_0202_0202_0202), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // c1
-    [
-        Board(0x0000_0000_0000_00ff), // a1
-        Board(0x0000_0000_0000_00ff), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_00ff), // d1
-        Board(0x0000_0000_0000_00ff), // e1
-        Board(0x0000_0000_0000_00ff), // f1
-        Board(0x0000_0000_0000_00ff), // g1
-        Board(0x0000_0000_0000_00ff), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0001_0204), // b2
-        Board(0x0404_0404_0404_0404), // c2
-        Board(0x0000_8040_2010_0804), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0001_0204), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0404_0404_0404_0404), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_8040_2010_0804), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0404_0404_0404_0404), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_8040_2010_0804), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0404_0404_0404_0404), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_8040_2010_0804), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0404_0404_0404_0404), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_8040_2010_0804), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0404_0404_0404_0404), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0404_0404_0404_0404), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // d1
-    [
-        Board(0x0000_0000_0000_00ff), // a1
-        Board(0x0000_0000_0000_00ff), // b1
-        Board(0x0000_0000_0000_00ff), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_00ff), // e1
-        Board(0x0000_0000_0000_00ff), // f1
-        Board(0x0000_0000_0000_00ff), // g1
-        Board(0x0000_0000_0000_00ff), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0102_0408), // c2
-        Board(0x0808_0808_0808_0808), // d2
-        Board(0x0000_0080_4020_1008), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0102_0408), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0808_0808_0808_0808), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0080_4020_1008), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0102_0408), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0808_0808_0808_0808), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0080_4020_1008), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0808_0808_0808_0808), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0080_4020_1008), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0808_0808_0808_0808), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0808_0808_0808_0808), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0808_0808_0808_0808), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // e1
-    [
-        Board(0x0000_0000_0000_00ff), // a1
-        Board(0x0000_0000_0000_00ff), // b1
-        Board(0x0000_0000_0000_00ff), // c1
-        Board(0x0000_0000_0000_00ff), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_00ff), // f1
-        Board(0x0000_0000_0000_00ff), // g1
-        Board(0x0000_0000_0000_00ff), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0001_0204_0810), // d2
-        Board(0x1010_1010_1010_1010), // e2
-        Board(0x0000_0000_8040_2010), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0001_0204_0810), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x1010_1010_1010_1010), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_8040_2010), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0001_0204_0810), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x1010_1010_1010_1010), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_8040_2010), // h4
-        Board(0x0000_0001_0204_0810), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x1010_1010_1010_1010), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x1010_1010_1010_1010), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x1010_1010_1010_1010), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x1010_1010_1010_1010), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // f1
-    [
-        Board(0x0000_0000_0000_00ff), // a1
-        Board(0x0000_0000_0000_00ff), // b1
-        Board(0x0000_0000_0000_00ff), // c1
-        Board(0x0000_0000_0000_00ff), // d1
-        Board(0x0000_0000_0000_00ff), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_00ff), // g1
-        Board(0x0000_0000_0000_00ff), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0102_0408_1020), // e2
-        Board(0x2020_2020_2020_2020), // f2
-        Board(0x0000_0000_0080_4020), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0102_0408_1020), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x2020_2020_2020_2020), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0080_4020), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0102_0408_1020), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x2020_2020_2020_2020), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0102_0408_1020), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x2020_2020_2020_2020), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0102_0408_1020), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x2020_2020_2020_2020), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x2020_2020_2020_2020), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x2020_2020_2020_2020), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // g1
-    [
-        Board(0x0000_0000_0000_00ff), // a1
-        Board(0x0000_0000_0000_00ff), // b1
-        Board(0x0000_0000_0000_00ff), // c1
-        Board(0x0000_0000_0000_00ff), // d1
-        Board(0x0000_0000_0000_00ff), // e1
-        Board(0x0000_0000_0000_00ff), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_00ff), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0001_0204_0810_2040), // f2
-        Board(0x4040_4040_4040_4040), // g2
-        Board(0x0000_0000_0000_8040), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0001_0204_0810_2040), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x4040_4040_4040_4040), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0001_0204_0810_2040), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x4040_4040_4040_4040), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0001_0204_0810_2040), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x4040_4040_4040_4040), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0001_0204_0810_2040), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x4040_4040_4040_4040), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0001_0204_0810_2040), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x4040_4040_4040_4040), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x4040_4040_4040_4040), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // h1
-    [
-        Board(0x0000_0000_0000_00ff), // a1
-        Board(0x0000_0000_0000_00ff), // b1
-        Board(0x0000_0000_0000_00ff), // c1
-        Board(0x0000_0000_0000_00ff), // d1
-        Board(0x0000_0000_0000_00ff), // e1
-        Board(0x0000_0000_0000_00ff), // f1
-        Board(0x0000_0000_0000_00ff), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0102_0408_1020_4080), // g2
-        Board(0x8080_8080_8080_8080), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0102_0408_1020_4080), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x8080_8080_8080_8080), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0102_0408_1020_4080), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x8080_8080_8080_8080), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0102_0408_1020_4080), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x8080_8080_8080_8080), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0102_0408_1020_4080), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x8080_8080_8080_8080), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0102_0408_1020_4080), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x8080_8080_8080_8080), // h7
-        Board(0x0102_0408_1020_4080), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x8080_8080_8080_8080), // h8
-    ],
-    // a2
-    [
-        Board(0x0101_0101_0101_0101), // a1
-        Board(0x0000_0000_0000_0102), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_ff00), // b2
-        Board(0x0000_0000_0000_ff00), // c2
-        Board(0x0000_0000_0000_ff00), // d2
-        Board(0x0000_0000_0000_ff00), // e2
-        Board(0x0000_0000_0000_ff00), // f2
-        Board(0x0000_0000_0000_ff00), // g2
-        Board(0x0000_0000_0000_ff00), // h2
-        Board(0x0101_0101_0101_0101), // a3
-        Board(0x4020_1008_0402_0100), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0101_0101_0101_0101), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x4020_1008_0402_0100), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0101_0101_0101_0101), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x4020_1008_0402_0100), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0101_0101_0101_0101), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x4020_1008_0402_0100), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0101_0101_0101_0101), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x4020_1008_0402_0100), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0101_0101_0101_0101), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x4020_1008_0402_0100), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // b2
-    [
-        Board(0x8040_2010_0804_0201), // a1
-        Board(0x0202_0202_0202_0202), // b1
-        Board(0x0000_0000_0001_0204), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_ff00), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_ff00), // c2
-        Board(0x0000_0000_0000_ff00), // d2
-        Board(0x0000_0000_0000_ff00), // e2
-        Board(0x0000_0000_0000_ff00), // f2
-        Board(0x0000_0000_0000_ff00), // g2
-        Board(0x0000_0000_0000_ff00), // h2
-        Board(0x0000_0000_0001_0204), // a3
-        Board(0x0202_0202_0202_0202), // b3
-        Board(0x8040_2010_0804_0201), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0202_0202_0202_0202), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x8040_2010_0804_0201), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0202_0202_0202_0202), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x8040_2010_0804_0201), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0202_0202_0202_0202), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x8040_2010_0804_0201), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0202_0202_0202_0202), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x8040_2010_0804_0201), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0202_0202_0202_0202), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x8040_2010_0804_0201), // h8
-    ],
-    // c2
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0080_4020_1008_0402), // b1
-        Board(0x0404_0404_0404_0404), // c1
-        Board(0x0000_0000_0102_0408), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_ff00), // a2
-        Board(0x0000_0000_0000_ff00), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_ff00), // d2
-        Board(0x0000_0000_0000_ff00), // e2
-        Board(0x0000_0000_0000_ff00), // f2
-        Board(0x0000_0000_0000_ff00), // g2
-        Board(0x0000_0000_0000_ff00), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0102_0408), // b3
-        Board(0x0404_0404_0404_0404), // c3
-        Board(0x0080_4020_1008_0402), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0102_0408), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0404_0404_0404_0404), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0080_4020_1008_0402), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0404_0404_0404_0404), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0080_4020_1008_0402), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0404_0404_0404_0404), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0080_4020_1008_0402), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0404_0404_0404_0404), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0080_4020_1008_0402), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0404_0404_0404_0404), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // d2
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_8040_2010_0804), // c1
-        Board(0x0808_0808_0808_0808), // d1
-        Board(0x0000_0001_0204_0810), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_ff00), // a2
-        Board(0x0000_0000_0000_ff00), // b2
-        Board(0x0000_0000_0000_ff00), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_ff00), // e2
-        Board(0x0000_0000_0000_ff00), // f2
-        Board(0x0000_0000_0000_ff00), // g2
-        Board(0x0000_0000_0000_ff00), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0001_0204_0810), // c3
-        Board(0x0808_0808_0808_0808), // d3
-        Board(0x0000_8040_2010_0804), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0001_0204_0810), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0808_0808_0808_0808), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_8040_2010_0804), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0001_0204_0810), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0808_0808_0808_0808), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_8040_2010_0804), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0808_0808_0808_0808), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_8040_2010_0804), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0808_0808_0808_0808), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0808_0808_0808_0808), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // e2
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0080_4020_1008), // d1
-        Board(0x1010_1010_1010_1010), // e1
-        Board(0x0000_0102_0408_1020), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_ff00), // a2
-        Board(0x0000_0000_0000_ff00), // b2
-        Board(0x0000_0000_0000_ff00), // c2
-        Board(0x0000_0000_0000_ff00), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_ff00), // f2
-        Board(0x0000_0000_0000_ff00), // g2
-        Board(0x0000_0000_0000_ff00), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0102_0408_1020), // d3
-        Board(0x1010_1010_1010_1010), // e3
-        Board(0x0000_0080_4020_1008), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0102_0408_1020), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x1010_1010_1010_1010), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0080_4020_1008), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0102_0408_1020), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x1010_1010_1010_1010), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0080_4020_1008), // h5
-        Board(0x0000_0102_0408_1020), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x1010_1010_1010_1010), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x1010_1010_1010_1010), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x1010_1010_1010_1010), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // f2
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_8040_2010), // e1
-        Board(0x2020_2020_2020_2020), // f1
-        Board(0x0001_0204_0810_2040), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_ff00), // a2
-        Board(0x0000_0000_0000_ff00), // b2
-        Board(0x0000_0000_0000_ff00), // c2
-        Board(0x0000_0000_0000_ff00), // d2
-        Board(0x0000_0000_0000_ff00), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_ff00), // g2
-        Board(0x0000_0000_0000_ff00), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0001_0204_0810_2040), // e3
-        Board(0x2020_2020_2020_2020), // f3
-        Board(0x0000_0000_8040_2010), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0001_0204_0810_2040), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x2020_2020_2020_2020), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_8040_2010), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0001_0204_0810_2040), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x2020_2020_2020_2020), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0001_0204_0810_2040), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x2020_2020_2020_2020), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0001_0204_0810_2040), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x2020_2020_2020_2020), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x2020_2020_2020_2020), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // g2
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0080_4020), // f1
-        Board(0x4040_4040_4040_4040), // g1
-        Board(0x0102_0408_1020_4080), // h1
-        Board(0x0000_0000_0000_ff00), // a2
-        Board(0x0000_0000_0000_ff00), // b2
-        Board(0x0000_0000_0000_ff00), // c2
-        Board(0x0000_0000_0000_ff00), // d2
-        Board(0x0000_0000_0000_ff00), // e2
-        Board(0x0000_0000_0000_ff00), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_ff00), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0102_0408_1020_4080), // f3
-        Board(0x4040_4040_4040_4040), // g3
-        Board(0x0000_0000_0080_4020), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0102_0408_1020_4080), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x4040_4040_4040_4040), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0102_0408_1020_4080), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x4040_4040_4040_4040), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0102_0408_1020_4080), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x4040_4040_4040_4040), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0102_0408_1020_4080), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x4040_4040_4040_4040), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0102_0408_1020_4080), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x4040_4040_4040_4040), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // h2
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_8040), // g1
-        Board(0x8080_8080_8080_8080), // h1
-        Board(0x0000_0000_0000_ff00), // a2
-        Board(0x0000_0000_0000_ff00), // b2
-        Board(0x0000_0000_0000_ff00), // c2
-        Board(0x0000_0000_0000_ff00), // d2
-        Board(0x0000_0000_0000_ff00), // e2
-        Board(0x0000_0000_0000_ff00), // f2
-        Board(0x0000_0000_0000_ff00), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0204_0810_2040_8000), // g3
-        Board(0x8080_8080_8080_8080), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0204_0810_2040_8000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x8080_8080_8080_8080), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0204_0810_2040_8000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x8080_8080_8080_8080), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0204_0810_2040_8000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x8080_8080_8080_8080), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0204_0810_2040_8000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x8080_8080_8080_8080), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0204_0810_2040_8000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x8080_8080_8080_8080), // h8
-    ],
-    // a3
-    [
-        Board(0x0101_0101_0101_0101), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0001_0204), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0101_0101_0101_0101), // a2
-        Board(0x0000_0000_0001_0204), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_00ff_0000), // b3
-        Board(0x0000_0000_00ff_0000), // c3
-        Board(0x0000_0000_00ff_0000), // d3
-        Board(0x0000_0000_00ff_0000), // e3
-        Board(0x0000_0000_00ff_0000), // f3
-        Board(0x0000_0000_00ff_0000), // g3
-        Board(0x0000_0000_00ff_0000), // h3
-        Board(0x0101_0101_0101_0101), // a4
-        Board(0x2010_0804_0201_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0101_0101_0101_0101), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x2010_0804_0201_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0101_0101_0101_0101), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x2010_0804_0201_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0101_0101_0101_0101), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x2010_0804_0201_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0101_0101_0101_0101), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x2010_0804_0201_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // b3
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0202_0202_0202_0202), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0102_0408), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x4020_1008_0402_0100), // a2
-        Board(0x0202_0202_0202_0202), // b2
-        Board(0x0000_0000_0102_0408), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_00ff_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_00ff_0000), // c3
-        Board(0x0000_0000_00ff_0000), // d3
-        Board(0x0000_0000_00ff_0000), // e3
-        Board(0x0000_0000_00ff_0000), // f3
-        Board(0x0000_0000_00ff_0000), // g3
-        Board(0x0000_0000_00ff_0000), // h3
-        Board(0x0000_0000_0102_0408), // a4
-        Board(0x0202_0202_0202_0202), // b4
-        Board(0x4020_1008_0402_0100), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0202_0202_0202_0202), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x4020_1008_0402_0100), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0202_0202_0202_0202), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x4020_1008_0402_0100), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0202_0202_0202_0202), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x4020_1008_0402_0100), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0202_0202_0202_0202), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x4020_1008_0402_0100), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // c3
-    [
-        Board(0x8040_2010_0804_0201), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0404_0404_0404_0404), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0001_0204_0810), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x8040_2010_0804_0201), // b2
-        Board(0x0404_0404_0404_0404), // c2
-        Board(0x0000_0001_0204_0810), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_00ff_0000), // a3
-        Board(0x0000_0000_00ff_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_00ff_0000), // d3
-        Board(0x0000_0000_00ff_0000), // e3
-        Board(0x0000_0000_00ff_0000), // f3
-        Board(0x0000_0000_00ff_0000), // g3
-        Board(0x0000_0000_00ff_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0001_0204_0810), // b4
-        Board(0x0404_0404_0404_0404), // c4
-        Board(0x8040_2010_0804_0201), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0001_0204_0810), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0404_0404_0404_0404), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x8040_2010_0804_0201), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0404_0404_0404_0404), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x8040_2010_0804_0201), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0404_0404_0404_0404), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x8040_2010_0804_0201), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0404_0404_0404_0404), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x8040_2010_0804_0201), // h8
-    ],
-    // d3
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0080_4020_1008_0402), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0808_0808_0808_0808), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0102_0408_1020), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0080_4020_1008_0402), // c2
-        Board(0x0808_0808_0808_0808), // d2
-        Board(0x0000_0102_0408_1020), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_00ff_0000), // a3
-        Board(0x0000_0000_00ff_0000), // b3
-        Board(0x0000_0000_00ff_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_00ff_0000), // e3
-        Board(0x0000_0000_00ff_0000), // f3
-        Board(0x0000_0000_00ff_0000), // g3
-        Board(0x0000_0000_00ff_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0102_0408_1020), // c4
-        Board(0x0808_0808_0808_0808), // d4
-        Board(0x0080_4020_1008_0402), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0102_0408_1020), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0808_0808_0808_0808), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0080_4020_1008_0402), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0102_0408_1020), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0808_0808_0808_0808), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0080_4020_1008_0402), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0808_0808_0808_0808), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0080_4020_1008_0402), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0808_0808_0808_0808), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // e3
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_8040_2010_0804), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x1010_1010_1010_1010), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0001_0204_0810_2040), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_8040_2010_0804), // d2
-        Board(0x1010_1010_1010_1010), // e2
-        Board(0x0001_0204_0810_2040), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_00ff_0000), // a3
-        Board(0x0000_0000_00ff_0000), // b3
-        Board(0x0000_0000_00ff_0000), // c3
-        Board(0x0000_0000_00ff_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_00ff_0000), // f3
-        Board(0x0000_0000_00ff_0000), // g3
-        Board(0x0000_0000_00ff_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0001_0204_0810_2040), // d4
-        Board(0x1010_1010_1010_1010), // e4
-        Board(0x0000_8040_2010_0804), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0001_0204_0810_2040), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x1010_1010_1010_1010), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_8040_2010_0804), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0001_0204_0810_2040), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x1010_1010_1010_1010), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_8040_2010_0804), // h6
-        Board(0x0001_0204_0810_2040), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x1010_1010_1010_1010), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x1010_1010_1010_1010), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // f3
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0080_4020_1008), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x2020_2020_2020_2020), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0102_0408_1020_4080), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0080_4020_1008), // e2
-        Board(0x2020_2020_2020_2020), // f2
-        Board(0x0102_0408_1020_4080), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_00ff_0000), // a3
-        Board(0x0000_0000_00ff_0000), // b3
-        Board(0x0000_0000_00ff_0000), // c3
-        Board(0x0000_0000_00ff_0000), // d3
-        Board(0x0000_0000_00ff_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_00ff_0000), // g3
-        Board(0x0000_0000_00ff_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0102_0408_1020_4080), // e4
-        Board(0x2020_2020_2020_2020), // f4
-        Board(0x0000_0080_4020_1008), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0102_0408_1020_4080), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x2020_2020_2020_2020), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0080_4020_1008), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0102_0408_1020_4080), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x2020_2020_2020_2020), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0102_0408_1020_4080), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x2020_2020_2020_2020), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0102_0408_1020_4080), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x2020_2020_2020_2020), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // g3
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_8040_2010), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x4040_4040_4040_4040), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_8040_2010), // f2
-        Board(0x4040_4040_4040_4040), // g2
-        Board(0x0204_0810_2040_8000), // h2
-        Board(0x0000_0000_00ff_0000), // a3
-        Board(0x0000_0000_00ff_0000), // b3
-        Board(0x0000_0000_00ff_0000), // c3
-        Board(0x0000_0000_00ff_0000), // d3
-        Board(0x0000_0000_00ff_0000), // e3
-        Board(0x0000_0000_00ff_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_00ff_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0204_0810_2040_8000), // f4
-        Board(0x4040_4040_4040_4040), // g4
-        Board(0x0000_0000_8040_2010), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0204_0810_2040_8000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x4040_4040_4040_4040), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0204_0810_2040_8000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x4040_4040_4040_4040), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0204_0810_2040_8000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x4040_4040_4040_4040), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0204_0810_2040_8000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x4040_4040_4040_4040), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // h3
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0080_4020), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x8080_8080_8080_8080), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0080_4020), // g2
-        Board(0x8080_8080_8080_8080), // h2
-        Board(0x0000_0000_00ff_0000), // a3
-        Board(0x0000_0000_00ff_0000), // b3
-        Board(0x0000_0000_00ff_0000), // c3
-        Board(0x0000_0000_00ff_0000), // d3
-        Board(0x0000_0000_00ff_0000), // e3
-        Board(0x0000_0000_00ff_0000), // f3
-        Board(0x0000_0000_00ff_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0408_1020_4080_0000), // g4
-        Board(0x8080_8080_8080_8080), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0408_1020_4080_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x8080_8080_8080_8080), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0408_1020_4080_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x8080_8080_8080_8080), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0408_1020_4080_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x8080_8080_8080_8080), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0408_1020_4080_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x8080_8080_8080_8080), // h8
-    ],
-    // a4
-    [
-        Board(0x0101_0101_0101_0101), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0102_0408), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0101_0101_0101_0101), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0102_0408), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0101_0101_0101_0101), // a3
-        Board(0x0000_0000_0102_0408), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_ff00_0000), // b4
-        Board(0x0000_0000_ff00_0000), // c4
-        Board(0x0000_0000_ff00_0000), // d4
-        Board(0x0000_0000_ff00_0000), // e4
-        Board(0x0000_0000_ff00_0000), // f4
-        Board(0x0000_0000_ff00_0000), // g4
-        Board(0x0000_0000_ff00_0000), // h4
-        Board(0x0101_0101_0101_0101), // a5
-        Board(0x1008_0402_0100_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0101_0101_0101_0101), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x1008_0402_0100_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0101_0101_0101_0101), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x1008_0402_0100_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0101_0101_0101_0101), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x1008_0402_0100_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // b4
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0202_0202_0202_0202), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0001_0204_0810), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0202_0202_0202_0202), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0001_0204_0810), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x2010_0804_0201_0000), // a3
-        Board(0x0202_0202_0202_0202), // b3
-        Board(0x0000_0001_0204_0810), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_ff00_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_ff00_0000), // c4
-        Board(0x0000_0000_ff00_0000), // d4
-        Board(0x0000_0000_ff00_0000), // e4
-        Board(0x0000_0000_ff00_0000), // f4
-        Board(0x0000_0000_ff00_0000), // g4
-        Board(0x0000_0000_ff00_0000), // h4
-        Board(0x0000_0001_0204_0810), // a5
-        Board(0x0202_0202_0202_0202), // b5
-        Board(0x2010_0804_0201_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0202_0202_0202_0202), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x2010_0804_0201_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0202_0202_0202_0202), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x2010_0804_0201_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0202_0202_0202_0202), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x2010_0804_0201_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // c4
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0404_0404_0404_0404), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0102_0408_1020), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x4020_1008_0402_0100), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0404_0404_0404_0404), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0102_0408_1020), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x4020_1008_0402_0100), // b3
-        Board(0x0404_0404_0404_0404), // c3
-        Board(0x0000_0102_0408_1020), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_ff00_0000), // a4
-        Board(0x0000_0000_ff00_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_ff00_0000), // d4
-        Board(0x0000_0000_ff00_0000), // e4
-        Board(0x0000_0000_ff00_0000), // f4
-        Board(0x0000_0000_ff00_0000), // g4
-        Board(0x0000_0000_ff00_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0102_0408_1020), // b5
-        Board(0x0404_0404_0404_0404), // c5
-        Board(0x4020_1008_0402_0100), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0102_0408_1020), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0404_0404_0404_0404), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x4020_1008_0402_0100), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0404_0404_0404_0404), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x4020_1008_0402_0100), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0404_0404_0404_0404), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x4020_1008_0402_0100), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // d4
-    [
-        Board(0x8040_2010_0804_0201), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0808_0808_0808_0808), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0001_0204_0810_2040), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x8040_2010_0804_0201), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0808_0808_0808_0808), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0001_0204_0810_2040), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x8040_2010_0804_0201), // c3
-        Board(0x0808_0808_0808_0808), // d3
-        Board(0x0001_0204_0810_2040), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_ff00_0000), // a4
-        Board(0x0000_0000_ff00_0000), // b4
-        Board(0x0000_0000_ff00_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_ff00_0000), // e4
-        Board(0x0000_0000_ff00_0000), // f4
-        Board(0x0000_0000_ff00_0000), // g4
-        Board(0x0000_0000_ff00_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0001_0204_0810_2040), // c5
-        Board(0x0808_0808_0808_0808), // d5
-        Board(0x8040_2010_0804_0201), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0001_0204_0810_2040), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0808_0808_0808_0808), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x8040_2010_0804_0201), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0001_0204_0810_2040), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0808_0808_0808_0808), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x8040_2010_0804_0201), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0808_0808_0808_0808), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x8040_2010_0804_0201), // h8
-    ],
-    // e4
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0080_4020_1008_0402), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x1010_1010_1010_1010), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0102_0408_1020_4080), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0080_4020_1008_0402), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x1010_1010_1010_1010), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0102_0408_1020_4080), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0080_4020_1008_0402), // d3
-        Board(0x1010_1010_1010_1010), // e3
-        Board(0x0102_0408_1020_4080), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_ff00_0000), // a4
-        Board(0x0000_0000_ff00_0000), // b4
-        Board(0x0000_0000_ff00_0000), // c4
-        Board(0x0000_0000_ff00_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_ff00_0000), // f4
-        Board(0x0000_0000_ff00_0000), // g4
-        Board(0x0000_0000_ff00_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0102_0408_1020_4080), // d5
-        Board(0x1010_1010_1010_1010), // e5
-        Board(0x0080_4020_1008_0402), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0102_0408_1020_4080), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x1010_1010_1010_1010), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0080_4020_1008_0402), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0102_0408_1020_4080), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x1010_1010_1010_1010), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0080_4020_1008_0402), // h7
-        Board(0x0102_0408_1020_4080), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x1010_1010_1010_1010), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // f4
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_8040_2010_0804), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x2020_2020_2020_2020), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_8040_2010_0804), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x2020_2020_2020_2020), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0204_0810_2040_8000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_8040_2010_0804), // e3
-        Board(0x2020_2020_2020_2020), // f3
-        Board(0x0204_0810_2040_8000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_ff00_0000), // a4
-        Board(0x0000_0000_ff00_0000), // b4
-        Board(0x0000_0000_ff00_0000), // c4
-        Board(0x0000_0000_ff00_0000), // d4
-        Board(0x0000_0000_ff00_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_ff00_0000), // g4
-        Board(0x0000_0000_ff00_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0204_0810_2040_8000), // e5
-        Board(0x2020_2020_2020_2020), // f5
-        Board(0x0000_8040_2010_0804), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0204_0810_2040_8000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x2020_2020_2020_2020), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_8040_2010_0804), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0204_0810_2040_8000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x2020_2020_2020_2020), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0204_0810_2040_8000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x2020_2020_2020_2020), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // g4
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0080_4020_1008), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x4040_4040_4040_4040), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0080_4020_1008), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x4040_4040_4040_4040), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0080_4020_1008), // f3
-        Board(0x4040_4040_4040_4040), // g3
-        Board(0x0408_1020_4080_0000), // h3
-        Board(0x0000_0000_ff00_0000), // a4
-        Board(0x0000_0000_ff00_0000), // b4
-        Board(0x0000_0000_ff00_0000), // c4
-        Board(0x0000_0000_ff00_0000), // d4
-        Board(0x0000_0000_ff00_0000), // e4
-        Board(0x0000_0000_ff00_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_ff00_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0408_1020_4080_0000), // f5
-        Board(0x4040_4040_4040_4040), // g5
-        Board(0x0000_0080_4020_1008), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0408_1020_4080_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x4040_4040_4040_4040), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0408_1020_4080_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x4040_4040_4040_4040), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0408_1020_4080_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x4040_4040_4040_4040), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // h4
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_8040_2010), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x8080_8080_8080_8080), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_8040_2010), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x8080_8080_8080_8080), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_8040_2010), // g3
-        Board(0x8080_8080_8080_8080), // h3
-        Board(0x0000_0000_ff00_0000), // a4
-        Board(0x0000_0000_ff00_0000), // b4
-        Board(0x0000_0000_ff00_0000), // c4
-        Board(0x0000_0000_ff00_0000), // d4
-        Board(0x0000_0000_ff00_0000), // e4
-        Board(0x0000_0000_ff00_0000), // f4
-        Board(0x0000_0000_ff00_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0810_2040_8000_0000), // g5
-        Board(0x8080_8080_8080_8080), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0810_2040_8000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x8080_8080_8080_8080), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0810_2040_8000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x8080_8080_8080_8080), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0810_2040_8000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x8080_8080_8080_8080), // h8
-    ],
-    // a5
-    [
-        Board(0x0101_0101_0101_0101), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0001_0204_0810), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0101_0101_0101_0101), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0001_0204_0810), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0101_0101_0101_0101), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0001_0204_0810), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0101_0101_0101_0101), // a4
-        Board(0x0000_0001_0204_0810), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_00ff_0000_0000), // b5
-        Board(0x0000_00ff_0000_0000), // c5
-        Board(0x0000_00ff_0000_0000), // d5
-        Board(0x0000_00ff_0000_0000), // e5
-        Board(0x0000_00ff_0000_0000), // f5
-        Board(0x0000_00ff_0000_0000), // g5
-        Board(0x0000_00ff_0000_0000), // h5
-        Board(0x0101_0101_0101_0101), // a6
-        Board(0x0804_0201_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0101_0101_0101_0101), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0804_0201_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0101_0101_0101_0101), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0804_0201_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // b5
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0202_0202_0202_0202), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0102_0408_1020), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0202_0202_0202_0202), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0102_0408_1020), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0202_0202_0202_0202), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0102_0408_1020), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x1008_0402_0100_0000), // a4
-        Board(0x0202_0202_0202_0202), // b4
-        Board(0x0000_0102_0408_1020), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_00ff_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_00ff_0000_0000), // c5
-        Board(0x0000_00ff_0000_0000), // d5
-        Board(0x0000_00ff_0000_0000), // e5
-        Board(0x0000_00ff_0000_0000), // f5
-        Board(0x0000_00ff_0000_0000), // g5
-        Board(0x0000_00ff_0000_0000), // h5
-        Board(0x0000_0102_0408_1020), // a6
-        Board(0x0202_0202_0202_0202), // b6
-        Board(0x1008_0402_0100_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0202_0202_0202_0202), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x1008_0402_0100_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0202_0202_0202_0202), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x1008_0402_0100_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // c5
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0404_0404_0404_0404), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0001_0204_0810_2040), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0404_0404_0404_0404), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0001_0204_0810_2040), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x2010_0804_0201_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0404_0404_0404_0404), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0001_0204_0810_2040), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x2010_0804_0201_0000), // b4
-        Board(0x0404_0404_0404_0404), // c4
-        Board(0x0001_0204_0810_2040), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_00ff_0000_0000), // a5
-        Board(0x0000_00ff_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_00ff_0000_0000), // d5
-        Board(0x0000_00ff_0000_0000), // e5
-        Board(0x0000_00ff_0000_0000), // f5
-        Board(0x0000_00ff_0000_0000), // g5
-        Board(0x0000_00ff_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0001_0204_0810_2040), // b6
-        Board(0x0404_0404_0404_0404), // c6
-        Board(0x2010_0804_0201_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0001_0204_0810_2040), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0404_0404_0404_0404), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x2010_0804_0201_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0404_0404_0404_0404), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x2010_0804_0201_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // d5
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0808_0808_0808_0808), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0102_0408_1020_4080), // h1
-        Board(0x4020_1008_0402_0100), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0808_0808_0808_0808), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0102_0408_1020_4080), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x4020_1008_0402_0100), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0808_0808_0808_0808), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0102_0408_1020_4080), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x4020_1008_0402_0100), // c4
-        Board(0x0808_0808_0808_0808), // d4
-        Board(0x0102_0408_1020_4080), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_00ff_0000_0000), // a5
-        Board(0x0000_00ff_0000_0000), // b5
-        Board(0x0000_00ff_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_00ff_0000_0000), // e5
-        Board(0x0000_00ff_0000_0000), // f5
-        Board(0x0000_00ff_0000_0000), // g5
-        Board(0x0000_00ff_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0102_0408_1020_4080), // c6
-        Board(0x0808_0808_0808_0808), // d6
-        Board(0x4020_1008_0402_0100), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0102_0408_1020_4080), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0808_0808_0808_0808), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x4020_1008_0402_0100), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0102_0408_1020_4080), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0808_0808_0808_0808), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x4020_1008_0402_0100), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // e5
-    [
-        Board(0x8040_2010_0804_0201), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x1010_1010_1010_1010), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x8040_2010_0804_0201), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x1010_1010_1010_1010), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0204_0810_2040_8000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x8040_2010_0804_0201), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x1010_1010_1010_1010), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0204_0810_2040_8000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x8040_2010_0804_0201), // d4
-        Board(0x1010_1010_1010_1010), // e4
-        Board(0x0204_0810_2040_8000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_00ff_0000_0000), // a5
-        Board(0x0000_00ff_0000_0000), // b5
-        Board(0x0000_00ff_0000_0000), // c5
-        Board(0x0000_00ff_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_00ff_0000_0000), // f5
-        Board(0x0000_00ff_0000_0000), // g5
-        Board(0x0000_00ff_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0204_0810_2040_8000), // d6
-        Board(0x1010_1010_1010_1010), // e6
-        Board(0x8040_2010_0804_0201), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0204_0810_2040_8000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x1010_1010_1010_1010), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x8040_2010_0804_0201), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0204_0810_2040_8000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x1010_1010_1010_1010), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x8040_2010_0804_0201), // h8
-    ],
-    // f5
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0080_4020_1008_0402), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x2020_2020_2020_2020), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0080_4020_1008_0402), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x2020_2020_2020_2020), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0080_4020_1008_0402), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x2020_2020_2020_2020), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0408_1020_4080_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0080_4020_1008_0402), // e4
-        Board(0x2020_2020_2020_2020), // f4
-        Board(0x0408_1020_4080_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_00ff_0000_0000), // a5
-        Board(0x0000_00ff_0000_0000), // b5
-        Board(0x0000_00ff_0000_0000), // c5
-        Board(0x0000_00ff_0000_0000), // d5
-        Board(0x0000_00ff_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_00ff_0000_0000), // g5
-        Board(0x0000_00ff_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0408_1020_4080_0000), // e6
-        Board(0x2020_2020_2020_2020), // f6
-        Board(0x0080_4020_1008_0402), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0408_1020_4080_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x2020_2020_2020_2020), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0080_4020_1008_0402), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0408_1020_4080_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x2020_2020_2020_2020), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // g5
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_8040_2010_0804), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x4040_4040_4040_4040), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_8040_2010_0804), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x4040_4040_4040_4040), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_8040_2010_0804), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x4040_4040_4040_4040), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_8040_2010_0804), // f4
-        Board(0x4040_4040_4040_4040), // g4
-        Board(0x0810_2040_8000_0000), // h4
-        Board(0x0000_00ff_0000_0000), // a5
-        Board(0x0000_00ff_0000_0000), // b5
-        Board(0x0000_00ff_0000_0000), // c5
-        Board(0x0000_00ff_0000_0000), // d5
-        Board(0x0000_00ff_0000_0000), // e5
-        Board(0x0000_00ff_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_00ff_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0810_2040_8000_0000), // f6
-        Board(0x4040_4040_4040_4040), // g6
-        Board(0x0000_8040_2010_0804), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0810_2040_8000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x4040_4040_4040_4040), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0810_2040_8000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x4040_4040_4040_4040), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // h5
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0080_4020_1008), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x8080_8080_8080_8080), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0080_4020_1008), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x8080_8080_8080_8080), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0080_4020_1008), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x8080_8080_8080_8080), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0080_4020_1008), // g4
-        Board(0x8080_8080_8080_8080), // h4
-        Board(0x0000_00ff_0000_0000), // a5
-        Board(0x0000_00ff_0000_0000), // b5
-        Board(0x0000_00ff_0000_0000), // c5
-        Board(0x0000_00ff_0000_0000), // d5
-        Board(0x0000_00ff_0000_0000), // e5
-        Board(0x0000_00ff_0000_0000), // f5
-        Board(0x0000_00ff_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x1020_4080_0000_0000), // g6
-        Board(0x8080_8080_8080_8080), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x1020_4080_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x8080_8080_8080_8080), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x1020_4080_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x8080_8080_8080_8080), // h8
-    ],
-    // a6
-    [
-        Board(0x0101_0101_0101_0101), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0102_0408_1020), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0101_0101_0101_0101), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0102_0408_1020), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0101_0101_0101_0101), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0102_0408_1020), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0101_0101_0101_0101), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0102_0408_1020), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0101_0101_0101_0101), // a5
-        Board(0x0000_0102_0408_1020), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_ff00_0000_0000), // b6
-        Board(0x0000_ff00_0000_0000), // c6
-        Board(0x0000_ff00_0000_0000), // d6
-        Board(0x0000_ff00_0000_0000), // e6
-        Board(0x0000_ff00_0000_0000), // f6
-        Board(0x0000_ff00_0000_0000), // g6
-        Board(0x0000_ff00_0000_0000), // h6
-        Board(0x0101_0101_0101_0101), // a7
-        Board(0x0402_0100_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0101_0101_0101_0101), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0402_0100_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // b6
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0202_0202_0202_0202), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0001_0204_0810_2040), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0202_0202_0202_0202), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0001_0204_0810_2040), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0202_0202_0202_0202), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0001_0204_0810_2040), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0202_0202_0202_0202), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0001_0204_0810_2040), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0804_0201_0000_0000), // a5
-        Board(0x0202_0202_0202_0202), // b5
-        Board(0x0001_0204_0810_2040), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_ff00_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_ff00_0000_0000), // c6
-        Board(0x0000_ff00_0000_0000), // d6
-        Board(0x0000_ff00_0000_0000), // e6
-        Board(0x0000_ff00_0000_0000), // f6
-        Board(0x0000_ff00_0000_0000), // g6
-        Board(0x0000_ff00_0000_0000), // h6
-        Board(0x0001_0204_0810_2040), // a7
-        Board(0x0202_0202_0202_0202), // b7
-        Board(0x0804_0201_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0202_0202_0202_0202), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0804_0201_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // c6
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0404_0404_0404_0404), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0102_0408_1020_4080), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0404_0404_0404_0404), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0102_0408_1020_4080), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0404_0404_0404_0404), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0102_0408_1020_4080), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x1008_0402_0100_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0404_0404_0404_0404), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0102_0408_1020_4080), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x1008_0402_0100_0000), // b5
-        Board(0x0404_0404_0404_0404), // c5
-        Board(0x0102_0408_1020_4080), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_ff00_0000_0000), // a6
-        Board(0x0000_ff00_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_ff00_0000_0000), // d6
-        Board(0x0000_ff00_0000_0000), // e6
-        Board(0x0000_ff00_0000_0000), // f6
-        Board(0x0000_ff00_0000_0000), // g6
-        Board(0x0000_ff00_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0102_0408_1020_4080), // b7
-        Board(0x0404_0404_0404_0404), // c7
-        Board(0x1008_0402_0100_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0102_0408_1020_4080), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0404_0404_0404_0404), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x1008_0402_0100_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // d6
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0808_0808_0808_0808), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0808_0808_0808_0808), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0204_0810_2040_8000), // h2
-        Board(0x2010_0804_0201_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0808_0808_0808_0808), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0204_0810_2040_8000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x2010_0804_0201_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0808_0808_0808_0808), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0204_0810_2040_8000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x2010_0804_0201_0000), // c5
-        Board(0x0808_0808_0808_0808), // d5
-        Board(0x0204_0810_2040_8000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_ff00_0000_0000), // a6
-        Board(0x0000_ff00_0000_0000), // b6
-        Board(0x0000_ff00_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_ff00_0000_0000), // e6
-        Board(0x0000_ff00_0000_0000), // f6
-        Board(0x0000_ff00_0000_0000), // g6
-        Board(0x0000_ff00_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0204_0810_2040_8000), // c7
-        Board(0x0808_0808_0808_0808), // d7
-        Board(0x2010_0804_0201_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0204_0810_2040_8000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0808_0808_0808_0808), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x2010_0804_0201_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // e6
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x1010_1010_1010_1010), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x4020_1008_0402_0100), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x1010_1010_1010_1010), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x4020_1008_0402_0100), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x1010_1010_1010_1010), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0408_1020_4080_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x4020_1008_0402_0100), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x1010_1010_1010_1010), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0408_1020_4080_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x4020_1008_0402_0100), // d5
-        Board(0x1010_1010_1010_1010), // e5
-        Board(0x0408_1020_4080_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_ff00_0000_0000), // a6
-        Board(0x0000_ff00_0000_0000), // b6
-        Board(0x0000_ff00_0000_0000), // c6
-        Board(0x0000_ff00_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_ff00_0000_0000), // f6
-        Board(0x0000_ff00_0000_0000), // g6
-        Board(0x0000_ff00_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0408_1020_4080_0000), // d7
-        Board(0x1010_1010_1010_1010), // e7
-        Board(0x4020_1008_0402_0100), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0408_1020_4080_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x1010_1010_1010_1010), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x4020_1008_0402_0100), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // f6
-    [
-        Board(0x8040_2010_0804_0201), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x2020_2020_2020_2020), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x8040_2010_0804_0201), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x2020_2020_2020_2020), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x8040_2010_0804_0201), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x2020_2020_2020_2020), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x8040_2010_0804_0201), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x2020_2020_2020_2020), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0810_2040_8000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x8040_2010_0804_0201), // e5
-        Board(0x2020_2020_2020_2020), // f5
-        Board(0x0810_2040_8000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_ff00_0000_0000), // a6
-        Board(0x0000_ff00_0000_0000), // b6
-        Board(0x0000_ff00_0000_0000), // c6
-        Board(0x0000_ff00_0000_0000), // d6
-        Board(0x0000_ff00_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_ff00_0000_0000), // g6
-        Board(0x0000_ff00_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0810_2040_8000_0000), // e7
-        Board(0x2020_2020_2020_2020), // f7
-        Board(0x8040_2010_0804_0201), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0810_2040_8000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x2020_2020_2020_2020), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x8040_2010_0804_0201), // h8
-    ],
-    // g6
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0080_4020_1008_0402), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x4040_4040_4040_4040), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0080_4020_1008_0402), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x4040_4040_4040_4040), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0080_4020_1008_0402), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x4040_4040_4040_4040), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0080_4020_1008_0402), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x4040_4040_4040_4040), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0080_4020_1008_0402), // f5
-        Board(0x4040_4040_4040_4040), // g5
-        Board(0x1020_4080_0000_0000), // h5
-        Board(0x0000_ff00_0000_0000), // a6
-        Board(0x0000_ff00_0000_0000), // b6
-        Board(0x0000_ff00_0000_0000), // c6
-        Board(0x0000_ff00_0000_0000), // d6
-        Board(0x0000_ff00_0000_0000), // e6
-        Board(0x0000_ff00_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_ff00_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x1020_4080_0000_0000), // f7
-        Board(0x4040_4040_4040_4040), // g7
-        Board(0x0080_4020_1008_0402), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x1020_4080_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x4040_4040_4040_4040), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // h6
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_8040_2010_0804), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x8080_8080_8080_8080), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_8040_2010_0804), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x8080_8080_8080_8080), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_8040_2010_0804), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x8080_8080_8080_8080), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_8040_2010_0804), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x8080_8080_8080_8080), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_8040_2010_0804), // g5
-        Board(0x8080_8080_8080_8080), // h5
-        Board(0x0000_ff00_0000_0000), // a6
-        Board(0x0000_ff00_0000_0000), // b6
-        Board(0x0000_ff00_0000_0000), // c6
-        Board(0x0000_ff00_0000_0000), // d6
-        Board(0x0000_ff00_0000_0000), // e6
-        Board(0x0000_ff00_0000_0000), // f6
-        Board(0x0000_ff00_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x2040_8000_0000_0000), // g7
-        Board(0x8080_8080_8080_8080), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x2040_8000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x8080_8080_8080_8080), // h8
-    ],
-    // a7
-    [
-        Board(0x0101_0101_0101_0101), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0001_0204_0810_2040), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0101_0101_0101_0101), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0001_0204_0810_2040), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0101_0101_0101_0101), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0001_0204_0810_2040), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0101_0101_0101_0101), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0001_0204_0810_2040), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0101_0101_0101_0101), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0001_0204_0810_2040), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0101_0101_0101_0101), // a6
-        Board(0x0001_0204_0810_2040), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x00ff_0000_0000_0000), // b7
-        Board(0x00ff_0000_0000_0000), // c7
-        Board(0x00ff_0000_0000_0000), // d7
-        Board(0x00ff_0000_0000_0000), // e7
-        Board(0x00ff_0000_0000_0000), // f7
-        Board(0x00ff_0000_0000_0000), // g7
-        Board(0x00ff_0000_0000_0000), // h7
-        Board(0x0101_0101_0101_0101), // a8
-        Board(0x0201_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // b7
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0202_0202_0202_0202), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0102_0408_1020_4080), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0202_0202_0202_0202), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0102_0408_1020_4080), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0202_0202_0202_0202), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0102_0408_1020_4080), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0202_0202_0202_0202), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0102_0408_1020_4080), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0202_0202_0202_0202), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0102_0408_1020_4080), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0402_0100_0000_0000), // a6
-        Board(0x0202_0202_0202_0202), // b6
-        Board(0x0102_0408_1020_4080), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x00ff_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x00ff_0000_0000_0000), // c7
-        Board(0x00ff_0000_0000_0000), // d7
-        Board(0x00ff_0000_0000_0000), // e7
-        Board(0x00ff_0000_0000_0000), // f7
-        Board(0x00ff_0000_0000_0000), // g7
-        Board(0x00ff_0000_0000_0000), // h7
-        Board(0x0102_0408_1020_4080), // a8
-        Board(0x0202_0202_0202_0202), // b8
-        Board(0x0402_0100_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // c7
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0404_0404_0404_0404), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0404_0404_0404_0404), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0204_0810_2040_8000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0404_0404_0404_0404), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0204_0810_2040_8000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0404_0404_0404_0404), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0204_0810_2040_8000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0804_0201_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0404_0404_0404_0404), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0204_0810_2040_8000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0804_0201_0000_0000), // b6
-        Board(0x0404_0404_0404_0404), // c6
-        Board(0x0204_0810_2040_8000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x00ff_0000_0000_0000), // a7
-        Board(0x00ff_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x00ff_0000_0000_0000), // d7
-        Board(0x00ff_0000_0000_0000), // e7
-        Board(0x00ff_0000_0000_0000), // f7
-        Board(0x00ff_0000_0000_0000), // g7
-        Board(0x00ff_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0204_0810_2040_8000), // b8
-        Board(0x0404_0404_0404_0404), // c8
-        Board(0x0804_0201_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // d7
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0808_0808_0808_0808), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0808_0808_0808_0808), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0808_0808_0808_0808), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0408_1020_4080_0000), // h3
-        Board(0x1008_0402_0100_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0808_0808_0808_0808), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0408_1020_4080_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x1008_0402_0100_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0808_0808_0808_0808), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0408_1020_4080_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x1008_0402_0100_0000), // c6
-        Board(0x0808_0808_0808_0808), // d6
-        Board(0x0408_1020_4080_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x00ff_0000_0000_0000), // a7
-        Board(0x00ff_0000_0000_0000), // b7
-        Board(0x00ff_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x00ff_0000_0000_0000), // e7
-        Board(0x00ff_0000_0000_0000), // f7
-        Board(0x00ff_0000_0000_0000), // g7
-        Board(0x00ff_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0408_1020_4080_0000), // c8
-        Board(0x0808_0808_0808_0808), // d8
-        Board(0x1008_0402_0100_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // e7
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x1010_1010_1010_1010), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x1010_1010_1010_1010), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x2010_0804_0201_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x1010_1010_1010_1010), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x2010_0804_0201_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x1010_1010_1010_1010), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0810_2040_8000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x2010_0804_0201_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x1010_1010_1010_1010), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0810_2040_8000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x2010_0804_0201_0000), // d6
-        Board(0x1010_1010_1010_1010), // e6
-        Board(0x0810_2040_8000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x00ff_0000_0000_0000), // a7
-        Board(0x00ff_0000_0000_0000), // b7
-        Board(0x00ff_0000_0000_0000), // c7
-        Board(0x00ff_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x00ff_0000_0000_0000), // f7
-        Board(0x00ff_0000_0000_0000), // g7
-        Board(0x00ff_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0810_2040_8000_0000), // d8
-        Board(0x1010_1010_1010_1010), // e8
-        Board(0x2010_0804_0201_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // f7
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x2020_2020_2020_2020), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x4020_1008_0402_0100), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x2020_2020_2020_2020), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x4020_1008_0402_0100), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x2020_2020_2020_2020), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x4020_1008_0402_0100), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x2020_2020_2020_2020), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x4020_1008_0402_0100), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x2020_2020_2020_2020), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x1020_4080_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x4020_1008_0402_0100), // e6
-        Board(0x2020_2020_2020_2020), // f6
-        Board(0x1020_4080_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x00ff_0000_0000_0000), // a7
-        Board(0x00ff_0000_0000_0000), // b7
-        Board(0x00ff_0000_0000_0000), // c7
-        Board(0x00ff_0000_0000_0000), // d7
-        Board(0x00ff_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x00ff_0000_0000_0000), // g7
-        Board(0x00ff_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x1020_4080_0000_0000), // e8
-        Board(0x2020_2020_2020_2020), // f8
-        Board(0x4020_1008_0402_0100), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-    // g7
-    [
-        Board(0x8040_2010_0804_0201), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x4040_4040_4040_4040), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x8040_2010_0804_0201), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x4040_4040_4040_4040), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x8040_2010_0804_0201), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x4040_4040_4040_4040), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x8040_2010_0804_0201), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x4040_4040_4040_4040), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x8040_2010_0804_0201), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x4040_4040_4040_4040), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x8040_2010_0804_0201), // f6
-        Board(0x4040_4040_4040_4040), // g6
-        Board(0x2040_8000_0000_0000), // h6
-        Board(0x00ff_0000_0000_0000), // a7
-        Board(0x00ff_0000_0000_0000), // b7
-        Board(0x00ff_0000_0000_0000), // c7
-        Board(0x00ff_0000_0000_0000), // d7
-        Board(0x00ff_0000_0000_0000), // e7
-        Board(0x00ff_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x00ff_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x2040_8000_0000_0000), // f8
-        Board(0x4040_4040_4040_4040), // g8
-        Board(0x8040_2010_0804_0201), // h8
-    ],
-    // h7
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0080_4020_1008_0402), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x8080_8080_8080_8080), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0080_4020_1008_0402), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x8080_8080_8080_8080), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0080_4020_1008_0402), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x8080_8080_8080_8080), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0080_4020_1008_0402), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x8080_8080_8080_8080), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0080_4020_1008_0402), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x8080_8080_8080_8080), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0080_4020_1008_0402), // g6
-        Board(0x8080_8080_8080_8080), // h6
-        Board(0x00ff_0000_0000_0000), // a7
-        Board(0x00ff_0000_0000_0000), // b7
-        Board(0x00ff_0000_0000_0000), // c7
-        Board(0x00ff_0000_0000_0000), // d7
-        Board(0x00ff_0000_0000_0000), // e7
-        Board(0x00ff_0000_0000_0000), // f7
-        Board(0x00ff_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0x4080_0000_0000_0000), // g8
-        Board(0x8080_8080_8080_8080), // h8
-    ],
-    // a8
-    [
-        Board(0x0101_0101_0101_0101), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0102_0408_1020_4080), // h1
-        Board(0x0101_0101_0101_0101), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0102_0408_1020_4080), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0101_0101_0101_0101), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0102_0408_1020_4080), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0101_0101_0101_0101), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0102_0408_1020_4080), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0101_0101_0101_0101), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0102_0408_1020_4080), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0101_0101_0101_0101), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0102_0408_1020_4080), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0101_0101_0101_0101), // a7
-        Board(0x0102_0408_1020_4080), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0x0000_0000_0000_0000), // a8
-        Board(0xff00_0000_0000_0000), // b8
-        Board(0xff00_0000_0000_0000), // c8
-        Board(0xff00_0000_0000_0000), // d8
-        Board(0xff00_0000_0000_0000), // e8
-        Board(0xff00_0000_0000_0000), // f8
-        Board(0xff00_0000_0000_0000), // g8
-        Board(0xff00_0000_0000_0000), // h8
-    ],
-    // b8
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0202_0202_0202_0202), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0202_0202_0202_0202), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0204_0810_2040_8000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0202_0202_0202_0202), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0204_0810_2040_8000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0202_0202_0202_0202), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0204_0810_2040_8000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0202_0202_0202_0202), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0204_0810_2040_8000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0202_0202_0202_0202), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0204_0810_2040_8000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0201_0000_0000_0000), // a7
-        Board(0x0202_0202_0202_0202), // b7
-        Board(0x0204_0810_2040_8000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0xff00_0000_0000_0000), // a8
-        Board(0x0000_0000_0000_0000), // b8
-        Board(0xff00_0000_0000_0000), // c8
-        Board(0xff00_0000_0000_0000), // d8
-        Board(0xff00_0000_0000_0000), // e8
-        Board(0xff00_0000_0000_0000), // f8
-        Board(0xff00_0000_0000_0000), // g8
-        Board(0xff00_0000_0000_0000), // h8
-    ],
-    // c8
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0404_0404_0404_0404), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0404_0404_0404_0404), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0404_0404_0404_0404), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0408_1020_4080_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0404_0404_0404_0404), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0408_1020_4080_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0404_0404_0404_0404), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0408_1020_4080_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0402_0100_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0404_0404_0404_0404), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0408_1020_4080_0000), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0402_0100_0000_0000), // b7
-        Board(0x0404_0404_0404_0404), // c7
-        Board(0x0408_1020_4080_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0xff00_0000_0000_0000), // a8
-        Board(0xff00_0000_0000_0000), // b8
-        Board(0x0000_0000_0000_0000), // c8
-        Board(0xff00_0000_0000_0000), // d8
-        Board(0xff00_0000_0000_0000), // e8
-        Board(0xff00_0000_0000_0000), // f8
-        Board(0xff00_0000_0000_0000), // g8
-        Board(0xff00_0000_0000_0000), // h8
-    ],
-    // d8
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0808_0808_0808_0808), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0808_0808_0808_0808), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0808_0808_0808_0808), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0808_0808_0808_0808), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0810_2040_8000_0000), // h4
-        Board(0x0804_0201_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0808_0808_0808_0808), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0810_2040_8000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0804_0201_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0808_0808_0808_0808), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x0810_2040_8000_0000), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0804_0201_0000_0000), // c7
-        Board(0x0808_0808_0808_0808), // d7
-        Board(0x0810_2040_8000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0xff00_0000_0000_0000), // a8
-        Board(0xff00_0000_0000_0000), // b8
-        Board(0xff00_0000_0000_0000), // c8
-        Board(0x0000_0000_0000_0000), // d8
-        Board(0xff00_0000_0000_0000), // e8
-        Board(0xff00_0000_0000_0000), // f8
-        Board(0xff00_0000_0000_0000), // g8
-        Board(0xff00_0000_0000_0000), // h8
-    ],
-    // e8
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x1010_1010_1010_1010), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x1010_1010_1010_1010), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x1010_1010_1010_1010), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x1008_0402_0100_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x1010_1010_1010_1010), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x1008_0402_0100_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x1010_1010_1010_1010), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x1020_4080_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x1008_0402_0100_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x1010_1010_1010_1010), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x1020_4080_0000_0000), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x1008_0402_0100_0000), // d7
-        Board(0x1010_1010_1010_1010), // e7
-        Board(0x1020_4080_0000_0000), // f7
-        Board(0x0000_0000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0xff00_0000_0000_0000), // a8
-        Board(0xff00_0000_0000_0000), // b8
-        Board(0xff00_0000_0000_0000), // c8
-        Board(0xff00_0000_0000_0000), // d8
-        Board(0x0000_0000_0000_0000), // e8
-        Board(0xff00_0000_0000_0000), // f8
-        Board(0xff00_0000_0000_0000), // g8
-        Board(0xff00_0000_0000_0000), // h8
-    ],
-    // f8
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x2020_2020_2020_2020), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x2020_2020_2020_2020), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x2010_0804_0201_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x2020_2020_2020_2020), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x2010_0804_0201_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x2020_2020_2020_2020), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x2010_0804_0201_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x2020_2020_2020_2020), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x2010_0804_0201_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x2020_2020_2020_2020), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x2040_8000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x2010_0804_0201_0000), // e7
-        Board(0x2020_2020_2020_2020), // f7
-        Board(0x2040_8000_0000_0000), // g7
-        Board(0x0000_0000_0000_0000), // h7
-        Board(0xff00_0000_0000_0000), // a8
-        Board(0xff00_0000_0000_0000), // b8
-        Board(0xff00_0000_0000_0000), // c8
-        Board(0xff00_0000_0000_0000), // d8
-        Board(0xff00_0000_0000_0000), // e8
-        Board(0x0000_0000_0000_0000), // f8
-        Board(0xff00_0000_0000_0000), // g8
-        Board(0xff00_0000_0000_0000), // h8
-    ],
-    // g8
-    [
-        Board(0x0000_0000_0000_0000), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x4040_4040_4040_4040), // g1
-        Board(0x0000_0000_0000_0000), // h1
-        Board(0x4020_1008_0402_0100), // a2
-        Board(0x0000_0000_0000_0000), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x4040_4040_4040_4040), // g2
-        Board(0x0000_0000_0000_0000), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x4020_1008_0402_0100), // b3
-        Board(0x0000_0000_0000_0000), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x4040_4040_4040_4040), // g3
-        Board(0x0000_0000_0000_0000), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x4020_1008_0402_0100), // c4
-        Board(0x0000_0000_0000_0000), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x4040_4040_4040_4040), // g4
-        Board(0x0000_0000_0000_0000), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x4020_1008_0402_0100), // d5
-        Board(0x0000_0000_0000_0000), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x4040_4040_4040_4040), // g5
-        Board(0x0000_0000_0000_0000), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x4020_1008_0402_0100), // e6
-        Board(0x0000_0000_0000_0000), // f6
-        Board(0x4040_4040_4040_4040), // g6
-        Board(0x0000_0000_0000_0000), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x4020_1008_0402_0100), // f7
-        Board(0x4040_4040_4040_4040), // g7
-        Board(0x4080_0000_0000_0000), // h7
-        Board(0xff00_0000_0000_0000), // a8
-        Board(0xff00_0000_0000_0000), // b8
-        Board(0xff00_0000_0000_0000), // c8
-        Board(0xff00_0000_0000_0000), // d8
-        Board(0xff00_0000_0000_0000), // e8
-        Board(0xff00_0000_0000_0000), // f8
-        Board(0x0000_0000_0000_0000), // g8
-        Board(0xff00_0000_0000_0000), // h8
-    ],
-    // h8
-    [
-        Board(0x8040_2010_0804_0201), // a1
-        Board(0x0000_0000_0000_0000), // b1
-        Board(0x0000_0000_0000_0000), // c1
-        Board(0x0000_0000_0000_0000), // d1
-        Board(0x0000_0000_0000_0000), // e1
-        Board(0x0000_0000_0000_0000), // f1
-        Board(0x0000_0000_0000_0000), // g1
-        Board(0x8080_8080_8080_8080), // h1
-        Board(0x0000_0000_0000_0000), // a2
-        Board(0x8040_2010_0804_0201), // b2
-        Board(0x0000_0000_0000_0000), // c2
-        Board(0x0000_0000_0000_0000), // d2
-        Board(0x0000_0000_0000_0000), // e2
-        Board(0x0000_0000_0000_0000), // f2
-        Board(0x0000_0000_0000_0000), // g2
-        Board(0x8080_8080_8080_8080), // h2
-        Board(0x0000_0000_0000_0000), // a3
-        Board(0x0000_0000_0000_0000), // b3
-        Board(0x8040_2010_0804_0201), // c3
-        Board(0x0000_0000_0000_0000), // d3
-        Board(0x0000_0000_0000_0000), // e3
-        Board(0x0000_0000_0000_0000), // f3
-        Board(0x0000_0000_0000_0000), // g3
-        Board(0x8080_8080_8080_8080), // h3
-        Board(0x0000_0000_0000_0000), // a4
-        Board(0x0000_0000_0000_0000), // b4
-        Board(0x0000_0000_0000_0000), // c4
-        Board(0x8040_2010_0804_0201), // d4
-        Board(0x0000_0000_0000_0000), // e4
-        Board(0x0000_0000_0000_0000), // f4
-        Board(0x0000_0000_0000_0000), // g4
-        Board(0x8080_8080_8080_8080), // h4
-        Board(0x0000_0000_0000_0000), // a5
-        Board(0x0000_0000_0000_0000), // b5
-        Board(0x0000_0000_0000_0000), // c5
-        Board(0x0000_0000_0000_0000), // d5
-        Board(0x8040_2010_0804_0201), // e5
-        Board(0x0000_0000_0000_0000), // f5
-        Board(0x0000_0000_0000_0000), // g5
-        Board(0x8080_8080_8080_8080), // h5
-        Board(0x0000_0000_0000_0000), // a6
-        Board(0x0000_0000_0000_0000), // b6
-        Board(0x0000_0000_0000_0000), // c6
-        Board(0x0000_0000_0000_0000), // d6
-        Board(0x0000_0000_0000_0000), // e6
-        Board(0x8040_2010_0804_0201), // f6
-        Board(0x0000_0000_0000_0000), // g6
-        Board(0x8080_8080_8080_8080), // h6
-        Board(0x0000_0000_0000_0000), // a7
-        Board(0x0000_0000_0000_0000), // b7
-        Board(0x0000_0000_0000_0000), // c7
-        Board(0x0000_0000_0000_0000), // d7
-        Board(0x0000_0000_0000_0000), // e7
-        Board(0x0000_0000_0000_0000), // f7
-        Board(0x8040_2010_0804_0201), // g7
-        Board(0x8080_8080_8080_8080), // h7
-        Board(0xff00_0000_0000_0000), // a8
-        Board(0xff00_0000_0000_0000), // b8
-        Board(0xff00_0000_0000_0000), // c8
-        Board(0xff00_0000_0000_0000), // d8
-        Board(0xff00_0000_0000_0000), // e8
-        Board(0xff00_0000_0000_0000), // f8
-        Board(0xff00_0000_0000_0000), // g8
-        Board(0x0000_0000_0000_0000), // h8
-    ],
-];
+// Generated by the build script from square/generate.rs
+include!(concat!(env!("OUT_DIR"), "/square_tables.rs"));
@@ -0,0 +1,567 @@
+// Generates the lookup tables of the square module. The build script includes this file to write
+// the tables into OUT_DIR, so it only depends on the standard library and works with plain u64
+// bitboards.
+
+use std::fmt::Write;
+
+/// Magic numbers with the offsets of the squares into the shared attack table
+pub type Magics = [(u64, u32); 64];
+
+const DIAGONAL_DIRECTIONS: [(i8, i8); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+const STRAIGHT_DIRECTIONS: [(i8, i8); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const KING_STEPS: [(i8, i8); 8] = [
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+];
+const KNIGHT_STEPS: [(i8, i8); 8] = [
+    (1, 2),
+    (2, 1),
+    (2, -1),
+    (1, -2),
+    (-1, -2),
+    (-2, -1),
+    (-2, 1),
+    (-1, 2),
+];
+
+// The magic numbers were chosen so that the attack tables of the squares overlap as much as
+// possible. `mick magics` searches for new ones and prints them in this form.
+
+/// Magic numbers and offsets into the shared attack table of the bishop moves from each square
+pub const DIAGONAL_MAGICS: Magics = [
+    (0x404040404040, 33104),   // a1
+    (0xa060401007fc, 4094),    // b1
+    (0x401020200000, 24764),   // c1
+    (0x806004000000, 13882),   // d1
+    (0x440200000000, 23090),   // e1
+    (0x80100800000, 32640),    // f1
+    (0x104104004000, 11558),   // g1
+    (0x20020820080, 32912),    // h1
+    (0x40100202004, 13674),    // a2
+    (0x20080200802, 6109),     // b2
+    (0x10040080200, 26494),    // c2
+    (0x8060040000, 17919),     // d2
+    (0x4402000000, 25757),     // e2
+    (0x21c100b200, 17338),     // f2
+    (0x400410080, 16983),      // g2
+    (0x3f7f05fffc0, 16659),    // h2
+    (0x4228040808010, 13610),  // a3
+    (0x200040404040, 2224),    // b3
+    (0x400080808080, 60405),   // c3
+    (0x200200801000, 7983),    // d3
+    (0x240080840000, 17),      // e3
+    (0x18000c03fff8, 34321),   // f3
+    (0xa5840208020, 33216),    // g3
+    (0x58408404010, 17127),    // h3
+    (0x2022000408020, 6397),   // a4
+    (0x402000408080, 22169),   // b4
+    (0x804000810100, 42727),   // c4
+    (0x100403c0403ff, 155),    // d4
+    (0x78402a8802000, 8601),   // e4
+    (0x101000804400, 21101),   // f4
+    (0x80800104100, 29885),    // g4
+    (0x400480101008, 29340),   // h4
+    (0x1010102004040, 19785),  // a5
+    (0x808090402020, 12258),   // b5
+    (0x7fefe08810010, 50451),  // c5
+    (0x3ff0f833fc080, 1712),   // d5
+    (0x7fe08019003042, 78475), // e5
+    (0x202040008040, 7855),    // f5
+    (0x1004008381008, 13642),  // g5
+    (0x802003700808, 8156),    // h5
+    (0x208200400080, 4348),    // a6
+    (0x104100200040, 28794),   // b6
+    (0x3ffdf7f833fc0, 22578),  // c6
+    (0x8840450020, 50315),     // d6
+    (0x20040100100, 85452),    // e6
+    (0x7fffdd80140028, 32816), // f6
+    (0x202020200040, 13930),   // g6
+    (0x1004010039004, 17967),  // h6
+    (0x40041008000, 33200),    // a7
+    (0x3ffefe0c02200, 32456),  // b7
+    (0x1010806000, 7762),      // c7
+    (0x8403000, 7794),         // d7
+    (0x100202000, 22761),      // e7
+    (0x40100200800, 14918),    // f7
+    (0x404040404000, 11620),   // g7
+    (0x6020601803f4, 15925),   // h7
+    (0x3ffdfdfc28048, 32528),  // a8
+    (0x820820020, 12196),      // b8
+    (0x10108060, 32720),       // c8
+    (0x84030, 26781),          // d8
+    (0x1002020, 19817),        // e8
+    (0x40408020, 24732),       // f8
+    (0x4040404040, 25468),     // g8
+    (0x404040404040, 10186),   // h8
+];
+
+/// Magic numbers and offsets into the shared attack table of the rook moves from each square
+pub const STRAIGHT_MAGICS: Magics = [
+    (0x280077ffebfffe, 41305),   // a1
+    (0x2004010201097fff, 14326), // b1
+    (0x10020010053fff, 24477),   // c1
+    (0x30002ff71ffffa, 8223),    // d1
+    (0x7fd00441ffffd003, 49795), // e1
+    (0x4001d9e03ffff7, 60546),   // f1
+    (0x4000888847ffff, 28543),   // g1
+    (0x6800fbff75fffd, 79282),   // h1
+    (0x28010113ffff, 6457),      // a2
+    (0x20040201fcffff, 4125),    // b2
+    (0x7fe80042ffffe8, 81021),   // c2
+    (0x1800217fffe8, 42341),     // d2
+    (0x1800073fffe8, 14139),     // e2
+    (0x7fe8009effffe8, 19465),   // f2
+    (0x1800602fffe8, 9514),      // g2
+    (0x30002fffffa0, 71090),     // h2
+    (0x300018010bffff, 75419),   // a3
+    (0x3000c0085fffb, 33476),    // b3
+    (0x4000802010008, 27117),    // c3
+    (0x2002004002002, 85964),    // d3
+    (0x2002020010002, 54915),    // e3
+    (0x1002020008001, 36544),    // f3
+    (0x4040008001, 71854),       // g3
+    (0x802000200040, 37996),     // h3
+    (0x40200010080010, 30398),   // a4
+    (0x80010040010, 55939),      // b4
+    (0x4010008020008, 53891),    // c4
+    (0x40020200200, 56963),      // d4
+    (0x10020020020, 77451),      // e4
+    (0x10020200080, 12319),      // f4
+    (0x8020200040, 88500),       // g4
+    (0x200020004081, 51405),     // h4
+    (0xfffd1800300030, 72878),   // a5
+    (0x7fff7fbfd40020, 676),     // b5
+    (0x3fffbd00180018, 83122),   // c5
+    (0x1fffde80180018, 22206),   // d5
+    (0xfffe0bfe80018, 75186),    // e5
+    (0x1000080202001, 681),      // f5
+    (0x3fffbff980180, 36453),    // g5
+    (0x1fffdff9000e0, 20369),    // h5
+    (0xfffeebfeffd800, 1981),    // a6
+    (0x7ffff7ffc01400, 13343),   // b6
+    (0x408104200204, 10650),     // c6
+    (0x1ffff01fc03000, 57987),   // d6
+    (0xfffe7f8bfe800, 26302),    // e6
+    (0x8001002020, 58357),       // f6
+    (0x3fff85fffa804, 40546),    // g6
+    (0x1fffd75ffa802, 0),        // h6
+    (0xffffec00280028, 14967),   // a7
+    (0x7fff75ff7fbfd8, 80361),   // b7
+    (0x3fff863fbf7fd8, 40905),   // c7
+    (0x1fffbfdfd7ffd8, 58347),   // d7
+    (0xffff810280028, 20381),    // e7
+    (0x7ffd7f7feffd8, 81868),    // f7
+    (0x3fffc0c480048, 59381),    // g7
+    (0x1ffffafd7ffd8, 84404),    // h7
+    (0xffffe4ffdfa3ba, 45811),   // a8
+    (0x7fffef7ff3d3da, 62898),   // b8
+    (0x3fffbfdfeff7fa, 45796),   // c8
+    (0x1fffeff7fbfc22, 66994),   // d8
+    (0x20408001001, 67204),      // e8
+    (0x7fffeffff77fd, 32448),    // f8
+    (0x3ffffbf7dfeec, 62946),    // g8
+    (0x1ffff9dffa333, 17005),    // h8
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Slider {
+    Diagonal,
+    Straight,
+}
+
+impl Slider {
+    fn directions(self) -> &'static [(i8, i8); 4] {
+        match self {
+            Slider::Diagonal => &DIAGONAL_DIRECTIONS,
+            Slider::Straight => &STRAIGHT_DIRECTIONS,
+        }
+    }
+
+    /// Number of bits of the index into the attack table of a square, which is the same for all
+    /// squares so that the magic lookup can shift by a constant
+    pub fn index_bits(self) -> u32 {
+        match self {
+            Slider::Diagonal => 9,
+            Slider::Straight => 12,
+        }
+    }
+
+    /// Returns the squares attacked from the square, up to and including the first occupied
+    /// square in each direction
+    pub fn attacks(self, square: u8, occupied: u64) -> u64 {
+        self.directions()
+            .iter()
+            .map(|&direction| ray(square, direction, occupied))
+            .fold(0, |attacks, ray| attacks | ray)
+    }
+
+    /// Returns the squares whose occupancy affects the attacks from the square. The last square
+    /// of each ray never does, as it is attacked either way.
+    pub fn mask(self, square: u8) -> u64 {
+        let mut mask = 0;
+        for &direction in self.directions() {
+            let mut current = square;
+            while let Some(next) = step(current, direction) {
+                if step(next, direction).is_none() {
+                    break;
+                }
+                mask |= 1 << next;
+                current = next;
+            }
+        }
+        mask
+    }
+}
+
+/// Returns the square one step away in the direction of (files, ranks), if it is on the board
+fn step(square: u8, (files, ranks): (i8, i8)) -> Option<u8> {
+    let file = (square % 8) as i8 + files;
+    let rank = (square / 8) as i8 + ranks;
+    ((0..8).contains(&file) && (0..8).contains(&rank)).then_some((rank * 8 + file) as u8)
+}
+
+fn ray(square: u8, direction: (i8, i8), occupied: u64) -> u64 {
+    let mut ray = 0;
+    let mut current = square;
+    while let Some(next) = step(current, direction) {
+        ray |= 1 << next;
+        if occupied & (1 << next) != 0 {
+            break;
+        }
+        current = next;
+    }
+    ray
+}
+
+fn steps(square: u8, steps: &[(i8, i8)]) -> u64 {
+    steps
+        .iter()
+        .filter_map(|&direction| step(square, direction))
+        .fold(0, |moves, to| moves | 1 << to)
+}
+
+/// Returns the direction of the line from one square to the other, if they are on the same rank,
+/// file or diagonal
+fn direction(from: u8, to: u8) -> Option<(i8, i8)> {
+    DIAGONAL_DIRECTIONS
+        .iter()
+        .chain(STRAIGHT_DIRECTIONS.iter())
+        .copied()
+        .find(|&direction| from != to && ray(from, direction, 1 << to) & (1 << to) != 0)
+}
+
+fn squares_between(from: u8, to: u8) -> u64 {
+    direction(from, to).map_or(0, |direction| ray(from, direction, 1 << to) & !(1 << to))
+}
+
+fn lines_along(from: u8, to: u8) -> u64 {
+    direction(from, to).map_or(0, |(files, ranks)| {
+        ray(from, (files, ranks), 0) | ray(from, (-files, -ranks), 0) | 1 << from
+    })
+}
+
+/// Iterates over all subsets of the mask, in the order of their index in a PEXT table
+pub fn subsets(mask: u64) -> impl Iterator<Item = u64> {
+    let mut subset = Some(0u64);
+    std::iter::from_fn(move || {
+        let current = subset?;
+        let next = current.wrapping_sub(mask) & mask;
+        subset = (next != 0).then_some(next);
+        Some(current)
+    })
+}
+
+fn magic_index(slider: Slider, occupied: u64, magic_number: u64) -> usize {
+    (occupied.wrapping_mul(magic_number) >> (64 - slider.index_bits())) as usize
+}
+
+/// Returns the attacks from the square by the index of the magic number, with 0 for the indices
+/// that are never used, or `None` if two occupancies with different attacks share an index
+fn magic_table(slider: Slider, square: u8, magic_number: u64) -> Option<Vec<u64>> {
+    let mut table = vec![0; 1 << slider.index_bits()];
+    for occupied in subsets(slider.mask(square)) {
+        let attacks = slider.attacks(square, occupied);
+        let entry = &mut table[magic_index(slider, occupied, magic_number)];
+        if *entry != 0 && *entry != attacks {
+            return None;
+        }
+        *entry = attacks;
+    }
+    Some(table)
+}
+
+/// Builds the attack table shared by all squares, which is indexed by the offset of the square
+/// plus the magic index
+fn shared_attacks(diagonal: &Magics, straight: &Magics) -> Result<Vec<u64>, String> {
+    let mut shared = vec![];
+    for (slider, magics) in [(Slider::Diagonal, diagonal), (Slider::Straight, straight)] {
+        for (square, &(magic_number, offset)) in (0..64).zip(magics.iter()) {
+            let table = magic_table(slider, square, magic_number)
+                .ok_or_else(|| format!("Invalid {slider:?} magic for square {square}"))?;
+            for (index, &attacks) in table.iter().enumerate().filter(|(_, &a)| a != 0) {
+                let index = offset as usize + index;
+                if shared.len() <= index {
+                    shared.resize(index + 1, 0);
+                }
+                if shared[index] != 0 && shared[index] != attacks {
+                    return Err(format!("{slider:?} table of square {square} overlaps"));
+                }
+                shared[index] = attacks;
+            }
+        }
+    }
+    Ok(shared)
+}
+
+/// Xorshift generator, to search for magic numbers reproducibly without any dependencies
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed.max(1))
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Magic numbers with few bits set are much more likely to work
+    fn sparse(&mut self) -> u64 {
+        self.next() & self.next() & self.next()
+    }
+}
+
+/// Tries random magic numbers until one maps the occupancies of the square without destructive
+/// collisions
+pub fn find_magic(slider: Slider, square: u8, rng: &mut Rng) -> u64 {
+    let mask = slider.mask(square);
+    loop {
+        let magic_number = rng.sparse();
+        if (mask.wrapping_mul(magic_number) >> 56).count_ones() < 6 {
+            continue;
+        }
+        if magic_table(slider, square, magic_number).is_some() {
+            return magic_number;
+        }
+    }
+}
+
+/// Finds the offsets of the squares into the shared attack table. The tables with the most used
+/// entries are placed first, each at the lowest offset where it only overlaps unused or equal
+/// entries. Returns the magic numbers with their offsets and the size of the shared table.
+pub fn pack_magics(diagonal: &[u64; 64], straight: &[u64; 64]) -> (Magics, Magics, usize) {
+    let mut tables = vec![];
+    for (slider, magics) in [(Slider::Diagonal, diagonal), (Slider::Straight, straight)] {
+        for (square, &magic_number) in (0..64).zip(magics.iter()) {
+            let table = magic_table(slider, square, magic_number).unwrap();
+            let used: Vec<(usize, u64)> = table
+                .into_iter()
+                .enumerate()
+                .filter(|&(_, attacks)| attacks != 0)
+                .collect();
+            tables.push((slider, square, magic_number, used));
+        }
+    }
+    tables.sort_by_key(|(_, _, _, used)| std::cmp::Reverse(used.len()));
+
+    let mut shared: Vec<u64> = vec![];
+    let mut packed = [[(0, 0); 64]; 2];
+    for (slider, square, magic_number, used) in tables {
+        let fits = |offset: usize| {
+            used.iter().all(|&(index, attacks)| {
+                shared
+                    .get(offset + index)
+                    .is_none_or(|&entry| entry == 0 || entry == attacks)
+            })
+        };
+        let offset = (0..).find(|&offset| fits(offset)).unwrap();
+
+        for &(index, attacks) in used.iter() {
+            if shared.len() <= offset + index {
+                shared.resize(offset + index + 1, 0);
+            }
+            shared[offset + index] = attacks;
+        }
+        packed[slider as usize][square as usize] = (magic_number, offset as u32);
+    }
+
+    (packed[0], packed[1], shared.len())
+}
+
+/// Returns the magic numbers and offsets in the form of the constants at the top of this file
+pub fn magics_source(diagonal: &Magics, straight: &Magics) -> String {
+    let mut source = String::new();
+    for (name, magics) in [("DIAGONAL_MAGICS", diagonal), ("STRAIGHT_MAGICS", straight)] {
+        writeln!(source, "pub const {name}: Magics = [").unwrap();
+        for (square, (magic_number, offset)) in magics.iter().enumerate() {
+            let name = square_name(square as u8);
+            writeln!(source, "    ({magic_number:#x}, {offset}), // {name}").unwrap();
+        }
+        writeln!(source, "];").unwrap();
+    }
+    source
+}
+
+/// Returns the size of the shared attack table of the magic numbers above
+pub fn current_magics_size() -> usize {
+    shared_attacks(&DIAGONAL_MAGICS, &STRAIGHT_MAGICS)
+        .unwrap()
+        .len()
+}
+
+fn square_name(square: u8) -> String {
+    format!("{}{}", (b'a' + square % 8) as char, square / 8 + 1)
+}
+
+fn write_boards(source: &mut String, name: &str, boards: &[u64]) {
+    writeln!(source, "pub static {name}: [Board; {}] = [", boards.len()).unwrap();
+    for board in boards {
+        writeln!(source, "    Board({board:#x}),").unwrap();
+    }
+    writeln!(source, "];").unwrap();
+}
+
+fn write_square_pairs(source: &mut String, name: &str, f: fn(u8, u8) -> u64) {
+    writeln!(source, "pub static {name}: [[Board; 64]; 64] = [").unwrap();
+    for from in 0..64 {
+        let boards: Vec<String> = (0..64)
+            .map(|to| format!("Board({:#x})", f(from, to)))
+            .collect();
+        writeln!(source, "    [{}],", boards.join(", ")).unwrap();
+    }
+    writeln!(source, "];").unwrap();
+}
+
+/// Returns the source of the rays, king and knight moves and lines between squares
+pub fn square_tables_source() -> String {
+    let mut source = String::new();
+    let table = |f: &dyn Fn(u8) -> u64| (0..64).map(f).collect::<Vec<u64>>();
+
+    write_boards(
+        &mut source,
+        "DIAGONAL_RAYS",
+        &table(&|square| Slider::Diagonal.attacks(square, 0)),
+    );
+    write_boards(
+        &mut source,
+        "STRAIGHT_RAYS",
+        &table(&|square| Slider::Straight.attacks(square, 0)),
+    );
+    write_boards(
+        &mut source,
+        "KING_MOVES",
+        &table(&|square| steps(square, &KING_STEPS)),
+    );
+    write_boards(
+        &mut source,
+        "KNIGHT_MOVES",
+        &table(&|square| steps(square, &KNIGHT_STEPS)),
+    );
+    write_square_pairs(&mut source, "SQUARES_BETWEEN", squares_between);
+    write_square_pairs(&mut source, "LINES_ALONG", lines_along);
+    source
+}
+
+/// Returns the source of the magic numbers with their masks and offsets, and the attack table
+/// shared by all squares
+pub fn magic_tables_source() -> String {
+    let mut source = String::new();
+    for (slider, name, magics) in [
+        (Slider::Diagonal, "DIAGONAL_MAGICS", &DIAGONAL_MAGICS),
+        (Slider::Straight, "STRAIGHT_MAGICS", &STRAIGHT_MAGICS),
+    ] {
+        writeln!(source, "pub static {name}: [Magic; 64] = [").unwrap();
+        for (square, &(magic_number, offset)) in (0..64).zip(magics.iter()) {
+            let mask = slider.mask(square);
+            writeln!(
+                source,
+                "    Magic {{ magic_number: {magic_number:#x}, mask: Board({mask:#x}), offset: {offset} }},"
+            )
+            .unwrap();
+        }
+        writeln!(source, "];").unwrap();
+    }
+
+    let shared = shared_attacks(&DIAGONAL_MAGICS, &STRAIGHT_MAGICS).unwrap();
+    write_boards(&mut source, "SHARED_ATTACKS", &shared);
+    source
+}
+
+/// Returns the source of the masks and offsets of the squares into a table indexed by the PEXT
+/// of the occupancy, which needs no magic numbers but is larger
+pub fn pext_tables_source() -> String {
+    let mut source = String::new();
+    let mut attacks = vec![];
+    for (slider, name) in [
+        (Slider::Diagonal, "DIAGONAL_PEXT"),
+        (Slider::Straight, "STRAIGHT_PEXT"),
+    ] {
+        writeln!(source, "pub static {name}: [PextEntry; 64] = [").unwrap();
+        for square in 0..64 {
+            let mask = slider.mask(square);
+            let offset = attacks.len();
+            writeln!(
+                source,
+                "    PextEntry {{ mask: Board({mask:#x}), offset: {offset} }},"
+            )
+            .unwrap();
+            attacks.extend(subsets(mask).map(|occupied| slider.attacks(square, occupied)));
+        }
+        writeln!(source, "];").unwrap();
+    }
+
+    write_boards(&mut source, "PEXT_ATTACKS", &attacks);
+    source
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::square::generate::{
+        find_magic, lines_along, pack_magics, shared_attacks, squares_between, subsets, Rng, Slider,
+    };
+
+    #[test]
+    fn slider_masks_and_lines() {
+        // Rook on a1: the edges of the board don't affect the attacks
+        assert_eq!(Slider::Straight.mask(0), 0x0001_0101_0101_017e);
+        assert_eq!(Slider::Diagonal.mask(27), 0x0040_2214_0014_2200);
+        assert_eq!(Slider::Straight.attacks(0, 0x0100 | 0x04), 0x0106);
+
+        // a1 to c3
+        assert_eq!(squares_between(0, 18), 0x0200);
+        assert_eq!(lines_along(18, 0), 0x8040_2010_0804_0201);
+        assert_eq!(lines_along(0, 17), 0);
+    }
+
+    #[test]
+    fn subsets_in_pext_order() {
+        assert_eq!(
+            subsets(0b1010).collect::<Vec<u64>>(),
+            vec![0, 0b10, 0b1000, 0b1010]
+        );
+        assert_eq!(subsets(Slider::Straight.mask(0)).count(), 1 << 12);
+    }
+
+    #[test]
+    fn packs_found_magics() {
+        let mut rng = Rng::new(3);
+        let diagonal =
+            std::array::from_fn(|square| find_magic(Slider::Diagonal, square as u8, &mut rng));
+        let straight =
+            std::array::from_fn(|square| find_magic(Slider::Straight, square as u8, &mut rng));
+        let (diagonal, straight, size) = pack_magics(&diagonal, &straight);
+
+        // Without overlapping the tables would need 64 * (512 + 4096) entries
+        assert!(size < 64 * (512 + 4096));
+        assert_eq!(shared_attacks(&diagonal, &straight).unwrap().len(), size);
+    }
+}
@@ -1,6 +1,9 @@
 mod consts;
+pub mod generate;
 mod rays;
 
+pub(crate) use self::rays::*;
+
 use self::consts::{
     DIAGONAL_RAYS, KING_MOVES, KNIGHT_MOVES, LINES_ALONG, SQUARES_BETWEEN, STRAIGHT_RAYS,
 };
@@ -1,25 +1,223 @@
+#[cfg(not(any(feature = "pext", feature = "kogge-stone")))]
 mod consts;
+#[cfg(any(
+    feature = "pext",
+    all(target_arch = "x86_64", not(feature = "kogge-stone"))
+))]
+mod pext;
 
-use self::consts::{DIAGONAL_MAGICS, SHARED_ATTACKS, STRAIGHT_MAGICS};
 use super::Square;
 use crate::board::Board;
 
+#[cfg(all(feature = "pext", feature = "kogge-stone"))]
+compile_error!("The pext and kogge-stone features can't be enabled together");
+
+/// A way to look up the attacks of sliding pieces. The move generator is generic over it, so that
+/// it can be compiled a second time for CPUs with BMI2 where the PEXT lookups are inlined.
+pub(crate) trait SliderAttacks {
+    fn diagonal(square: Square, occupied: Board) -> Board;
+    fn straight(square: Square, occupied: Board) -> Board;
+}
+
+/// The lookup without PEXT, or the only one when it's chosen at compile time
+#[cfg(not(any(feature = "pext", feature = "kogge-stone")))]
+pub(crate) type DefaultAttacks = Magics;
+#[cfg(feature = "pext")]
+pub(crate) type DefaultAttacks = Pext;
+#[cfg(feature = "kogge-stone")]
+pub(crate) type DefaultAttacks = KoggeStone;
+
+/// Uses PEXT on CPUs with BMI2 and magics otherwise, which is decided the first time
+#[cfg(all(
+    target_arch = "x86_64",
+    not(any(feature = "pext", feature = "kogge-stone"))
+))]
 impl Square {
+    #[inline]
     pub fn diagonal_attacks(self, occupied: Board) -> Board {
-        let magic = unsafe { *DIAGONAL_MAGICS.get_unchecked(self.0 as usize) };
-        let mult = (occupied & magic.mask).0.wrapping_mul(magic.magic_number);
-        let index = (mult >> 55) as usize;
-        let offset = index + (magic.offset as usize);
+        if pext::is_available() {
+            return unsafe { pext::attacks(&pext::DIAGONAL_PEXT, self, occupied) };
+        }
+
+        Magics::diagonal(self, occupied)
+    }
+
+    #[inline]
+    pub fn straight_attacks(self, occupied: Board) -> Board {
+        if pext::is_available() {
+            return unsafe { pext::attacks(&pext::STRAIGHT_PEXT, self, occupied) };
+        }
+
+        Magics::straight(self, occupied)
+    }
+}
 
-        unsafe { *SHARED_ATTACKS.get_unchecked(offset) }
+#[cfg(not(all(
+    target_arch = "x86_64",
+    not(any(feature = "pext", feature = "kogge-stone"))
+)))]
+impl Square {
+    pub fn diagonal_attacks(self, occupied: Board) -> Board {
+        DefaultAttacks::diagonal(self, occupied)
     }
 
     pub fn straight_attacks(self, occupied: Board) -> Board {
-        let magic = unsafe { *STRAIGHT_MAGICS.get_unchecked(self.0 as usize) };
-        let mult = (occupied & magic.mask).0.wrapping_mul(magic.magic_number);
-        let index = (mult >> 52) as usize;
-        let offset = index + (magic.offset as usize);
+        DefaultAttacks::straight(self, occupied)
+    }
+}
+
+/// Fancy magic bitboards with a fixed shift, where the attack tables of the squares overlap
+#[cfg(not(any(feature = "pext", feature = "kogge-stone")))]
+pub(crate) struct Magics;
+
+#[cfg(not(any(feature = "pext", feature = "kogge-stone")))]
+impl SliderAttacks for Magics {
+    #[inline(always)]
+    fn diagonal(square: Square, occupied: Board) -> Board {
+        magic_attacks(&consts::DIAGONAL_MAGICS, 55, square, occupied)
+    }
+
+    #[inline(always)]
+    fn straight(square: Square, occupied: Board) -> Board {
+        magic_attacks(&consts::STRAIGHT_MAGICS, 52, square, occupied)
+    }
+}
+
+#[cfg(not(any(feature = "pext", feature = "kogge-stone")))]
+#[inline(always)]
+fn magic_attacks(
+    magics: &[consts::Magic; 64],
+    shift: u32,
+    square: Square,
+    occupied: Board,
+) -> Board {
+    let magic = unsafe { *magics.get_unchecked(square.0 as usize) };
+    let mult = (occupied & magic.mask).0.wrapping_mul(magic.magic_number);
+    let index = (mult >> shift) as usize;
+    let offset = index + (magic.offset as usize);
+
+    unsafe { *consts::SHARED_ATTACKS.get_unchecked(offset) }
+}
+
+/// PEXT lookups, which must only be used on CPUs with BMI2. Without the pext feature that means
+/// checking [`pext_available`] first, which is why this stays private to the crate. The lookups
+/// only inline into functions compiled with `#[target_feature(enable = "bmi2")]`.
+#[cfg(any(
+    feature = "pext",
+    all(target_arch = "x86_64", not(feature = "kogge-stone"))
+))]
+pub(crate) struct Pext;
+
+#[cfg(any(
+    feature = "pext",
+    all(target_arch = "x86_64", not(feature = "kogge-stone"))
+))]
+impl SliderAttacks for Pext {
+    #[inline(always)]
+    fn diagonal(square: Square, occupied: Board) -> Board {
+        unsafe { pext::attacks(&pext::DIAGONAL_PEXT, square, occupied) }
+    }
+
+    #[inline(always)]
+    fn straight(square: Square, occupied: Board) -> Board {
+        unsafe { pext::attacks(&pext::STRAIGHT_PEXT, square, occupied) }
+    }
+}
+
+#[cfg(all(
+    target_arch = "x86_64",
+    not(any(feature = "pext", feature = "kogge-stone"))
+))]
+pub(crate) use pext::is_available as pext_available;
+
+/// Fills along the rays without any tables
+#[cfg(feature = "kogge-stone")]
+pub(crate) struct KoggeStone;
+
+#[cfg(feature = "kogge-stone")]
+impl SliderAttacks for KoggeStone {
+    #[inline(always)]
+    fn diagonal(square: Square, occupied: Board) -> Board {
+        Board::new(square).diagonal_attacks(occupied)
+    }
+
+    #[inline(always)]
+    fn straight(square: Square, occupied: Board) -> Board {
+        Board::new(square).straight_attacks(occupied)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::Board,
+        square::{generate::Slider, Square},
+    };
+
+    #[test]
+    fn attacks_match_generator() {
+        let mut occupancy: u64 = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..200 {
+            occupancy ^= occupancy << 13;
+            occupancy ^= occupancy >> 7;
+            occupancy ^= occupancy << 17;
+            let occupied = occupancy & (occupancy >> 3);
+
+            for square in 0..64 {
+                assert_eq!(
+                    Square(square).diagonal_attacks(Board(occupied)).0,
+                    Slider::Diagonal.attacks(square, occupied)
+                );
+                assert_eq!(
+                    Square(square).straight_attacks(Board(occupied)).0,
+                    Slider::Straight.attacks(square, occupied)
+                );
+            }
+        }
+    }
+
+    /// Both paths of the runtime dispatch give the same attacks for every occupancy of the
+    /// squares that matter, along with some that don't
+    #[cfg(all(
+        target_arch = "x86_64",
+        not(any(feature = "pext", feature = "kogge-stone"))
+    ))]
+    #[test]
+    fn pext_matches_magics() {
+        use crate::square::{
+            generate::subsets,
+            rays::{consts, magic_attacks, pext},
+        };
+
+        if !pext::is_available() {
+            return;
+        }
+
+        for (slider, magics, shift, entries) in [
+            (
+                Slider::Diagonal,
+                &consts::DIAGONAL_MAGICS,
+                55,
+                &pext::DIAGONAL_PEXT,
+            ),
+            (
+                Slider::Straight,
+                &consts::STRAIGHT_MAGICS,
+                52,
+                &pext::STRAIGHT_PEXT,
+            ),
+        ] {
+            for square in 0..64 {
+                let mask = slider.mask(square);
+                for (i, occupied) in subsets(mask).enumerate() {
+                    let noise = !mask & (i as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+                    let occupied = Board(occupied | noise);
 
-        unsafe { *SHARED_ATTACKS.get_unchecked(offset) }
+                    let magic = magic_attacks(magics, shift, Square(square), occupied);
+                    let pext = unsafe { pext::attacks(entries, Square(square), occupied) };
+                    assert_eq!(magic, pext, "{slider:?} {square} {:#x}", occupied.0);
+                }
+            }
+        }
     }
 }
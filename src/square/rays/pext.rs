@@ -0,0 +1,36 @@
+use super::Square;
+use crate::board::Board;
+use std::arch::x86_64::_pext_u64;
+#[cfg(not(feature = "pext"))]
+use std::sync::OnceLock;
+
+#[cfg(all(feature = "pext", not(target_feature = "bmi2")))]
+compile_error!("The pext feature needs BMI2, for example with RUSTFLAGS=\"-C target-cpu=native\"");
+
+#[derive(Copy, Clone)]
+pub struct PextEntry {
+    pub mask: Board,
+    pub offset: u32,
+}
+
+// Generated by the build script from square/generate.rs
+include!(concat!(env!("OUT_DIR"), "/pext_tables.rs"));
+
+/// Detects BMI2 the first time and then only reads the cached answer
+#[cfg(not(feature = "pext"))]
+#[inline]
+pub fn is_available() -> bool {
+    static AVAILABLE: OnceLock<bool> = OnceLock::new();
+    *AVAILABLE.get_or_init(|| std::is_x86_feature_detected!("bmi2"))
+}
+
+/// The occupied squares of the mask, packed into the low bits, are the index into the attack
+/// table of the square. The CPU must support BMI2.
+#[inline]
+#[target_feature(enable = "bmi2")]
+pub unsafe fn attacks(entries: &[PextEntry; 64], square: Square, occupied: Board) -> Board {
+    let entry = entries.get_unchecked(square.0 as usize);
+    let index = _pext_u64(occupied.0, entry.mask.0) as usize;
+
+    *PEXT_ATTACKS.get_unchecked(entry.offset as usize + index)
+}
@@ -0,0 +1,528 @@
+//! Solves endgames with few pieces by retrograde analysis and writes them as Syzygy files. This is
+//! how the tables in `tests/syzygy` were created, see the README there.
+
+use super::table::{write_table, TableKind};
+use crate::{
+    board::{Board, FILE_A, FILE_H},
+    castle::NO_RIGHTS,
+    piece::{Piece, PieceKind, BISHOP, KING, KNIGHT, NULL_PIECE, PAWN, QUEEN, ROOK},
+    position::State,
+    side::Side,
+    square::Square,
+    Position,
+};
+use std::{collections::HashMap, path::Path};
+
+/// Values of positions from the perspective of the side to move, ignoring the fifty move rule
+const UNKNOWN: u8 = 0;
+const LOSS: u8 = 1;
+const DRAW: u8 = 2;
+const WIN: u8 = 3;
+const INVALID: u8 = 4;
+
+const MAX_PIECES: usize = 5;
+
+fn negate(value: u8) -> u8 {
+    WIN + LOSS - value
+}
+
+/// Packs the number of pieces per side and kind like `table::material_key`
+fn material_key(pieces: &[Piece], flip: bool) -> u64 {
+    pieces
+        .iter()
+        .map(|pc| 1 << (4 * (((pc.0 & 1) ^ flip as u8) * 6 + (pc.0 >> 1))))
+        .sum()
+}
+
+/// Each endgame is solved only with the colors chosen such that its material key is the lower one
+fn is_flipped(pieces: &[Piece]) -> bool {
+    material_key(pieces, false) > material_key(pieces, true)
+}
+
+/// The pieces of an endgame together with their squares. Solved endgames store the values of all
+/// placements indexed by the squares of the pieces (ordered by `Piece`) and the side to move.
+#[derive(Clone, Copy)]
+struct Placement {
+    pieces: [Piece; MAX_PIECES],
+    squares: [u8; MAX_PIECES],
+    len: usize,
+}
+
+impl Placement {
+    fn from_index(pieces: &[Piece], mut idx: usize) -> Placement {
+        let mut placement = Placement {
+            pieces: [NULL_PIECE; MAX_PIECES],
+            squares: [0; MAX_PIECES],
+            len: pieces.len(),
+        };
+        idx >>= 1;
+        for i in (0..pieces.len()).rev() {
+            placement.pieces[i] = pieces[i];
+            placement.squares[i] = (idx & 63) as u8;
+            idx >>= 6;
+        }
+        placement
+    }
+
+    fn index(&self, stm: u8) -> usize {
+        let squares = self.squares[..self.len]
+            .iter()
+            .fold(0, |idx, &sq| (idx << 6) | sq as usize);
+        (squares << 1) | stm as usize
+    }
+
+    fn occupied(&self) -> u64 {
+        self.squares[..self.len]
+            .iter()
+            .fold(0, |board, &sq| board | (1 << sq))
+    }
+
+    fn side(&self, side: u8) -> u64 {
+        (0..self.len)
+            .filter(|&i| self.pieces[i].0 & 1 == side)
+            .fold(0, |board, i| board | (1 << self.squares[i]))
+    }
+
+    fn king(&self, side: u8) -> u8 {
+        let i = (0..self.len)
+            .position(|i| self.pieces[i] == KING.to_piece(Side(side)))
+            .unwrap();
+        self.squares[i]
+    }
+
+    fn attacks(&self, i: usize, occupied: u64) -> u64 {
+        let sq = Square(self.squares[i]);
+        let occupied = Board(occupied);
+        match self.pieces[i].kind() {
+            kind if kind == KING => sq.king_moves().0,
+            kind if kind == QUEEN => {
+                (sq.diagonal_attacks(occupied) | sq.straight_attacks(occupied)).0
+            }
+            kind if kind == ROOK => sq.straight_attacks(occupied).0,
+            kind if kind == BISHOP => sq.diagonal_attacks(occupied).0,
+            kind if kind == KNIGHT => sq.knight_moves().0,
+            _ => {
+                let board = 1u64 << sq.0;
+                if self.pieces[i].0 & 1 == 0 {
+                    ((board << 7) & !FILE_H.0) | ((board << 9) & !FILE_A.0)
+                } else {
+                    ((board >> 9) & !FILE_H.0) | ((board >> 7) & !FILE_A.0)
+                }
+            }
+        }
+    }
+
+    fn is_attacked(&self, sq: u8, by: u8) -> bool {
+        let occupied = self.occupied();
+        (0..self.len)
+            .any(|i| self.pieces[i].0 & 1 == by && self.attacks(i, occupied) & (1 << sq) != 0)
+    }
+
+    /// Positions are valid if no two pieces share a square, there are no pawns on the first or
+    /// last rank and the side that is not to move is not in check
+    fn is_valid(&self, stm: u8) -> bool {
+        self.occupied().count_ones() as usize == self.len
+            && (0..self.len)
+                .all(|i| self.pieces[i].kind() != PAWN || (1..7).contains(&(self.squares[i] >> 3)))
+            && !self.is_attacked(self.king(stm ^ 1), stm)
+    }
+
+    /// Moves the piece `i` to `to`, removing the piece captured there
+    fn with_move(&self, i: usize, to: u8, promotion: Option<PieceKind>) -> Placement {
+        let mut placement = *self;
+        placement.squares[i] = to;
+        if let Some(kind) = promotion {
+            placement.pieces[i] = kind.to_piece(self.pieces[i].side());
+        }
+        if let Some(j) = (0..self.len).find(|&j| j != i && self.squares[j] == to) {
+            placement.len -= 1;
+            placement.squares[j] = placement.squares[placement.len];
+            placement.pieces[j] = placement.pieces[placement.len];
+        }
+        placement
+    }
+
+    /// Calls `f` with every position reached by a legal move of `stm`, whether the position is
+    /// part of the same endgame and whether the move is a capture or pawn move
+    fn for_each_move(&self, stm: u8, mut f: impl FnMut(&Placement, bool, bool)) {
+        let occupied = self.occupied();
+        let own = self.side(stm);
+        let mut emit = |placement: Placement, in_endgame: bool, is_zeroing: bool| {
+            if !placement.is_attacked(placement.king(stm), stm ^ 1) {
+                f(&placement, in_endgame, is_zeroing);
+            }
+        };
+
+        for i in (0..self.len).filter(|&i| self.pieces[i].0 & 1 == stm) {
+            let sq = self.squares[i];
+            let mut targets = self.attacks(i, occupied) & !own;
+
+            if self.pieces[i].kind() == PAWN {
+                targets &= occupied;
+                let push = if stm == 0 { sq + 8 } else { sq - 8 };
+                if occupied & (1 << push) == 0 {
+                    targets |= 1 << push;
+                    let double = if stm == 0 { push + 8 } else { push - 8 };
+                    let start_rank = if stm == 0 { 1 } else { 6 };
+                    if sq >> 3 == start_rank && occupied & (1 << double) == 0 {
+                        targets |= 1 << double;
+                    }
+                }
+
+                for (to, _) in Board(targets).iter() {
+                    if to.0 >> 3 == 0 || to.0 >> 3 == 7 {
+                        for kind in [QUEEN, ROOK, BISHOP, KNIGHT] {
+                            emit(self.with_move(i, to.0, Some(kind)), false, true);
+                        }
+                    } else {
+                        let is_capture = occupied & (1 << to.0) != 0;
+                        emit(self.with_move(i, to.0, None), !is_capture, true);
+                    }
+                }
+            } else {
+                for (to, _) in Board(targets).iter() {
+                    let is_capture = occupied & (1 << to.0) != 0;
+                    emit(self.with_move(i, to.0, None), !is_capture, is_capture);
+                }
+            }
+        }
+    }
+
+    /// Calls `f` with every position of the same endgame from which `mover` could have reached
+    /// this one, only with moves of pieces other than pawns unless `pawns` is true. The positions
+    /// still need to be checked for validity.
+    fn for_each_unmove(&self, mover: u8, pawns: bool, mut f: impl FnMut(&Placement)) {
+        let occupied = self.occupied();
+        for i in (0..self.len).filter(|&i| self.pieces[i].0 & 1 == mover) {
+            let sq = self.squares[i];
+            let mut sources = 0;
+
+            if self.pieces[i].kind() == PAWN {
+                if !pawns {
+                    continue;
+                }
+                let (from, double, start_rank) = if mover == 0 {
+                    (sq - 8, sq.wrapping_sub(16), 3)
+                } else {
+                    (sq + 8, sq + 16, 4)
+                };
+                if occupied & (1 << from) == 0 && (1..7).contains(&(from >> 3)) {
+                    sources |= 1 << from;
+                    if sq >> 3 == start_rank && occupied & (1 << double) == 0 {
+                        sources |= 1 << double;
+                    }
+                }
+            } else {
+                sources = self.attacks(i, occupied) & !occupied;
+            }
+
+            for (from, _) in Board(sources).iter() {
+                let mut placement = *self;
+                placement.squares[i] = from.0;
+                f(&placement);
+            }
+        }
+    }
+
+    fn to_position(self, stm: u8) -> Position {
+        let mut pieces = [NULL_PIECE; 64];
+        for i in 0..self.len {
+            pieces[self.squares[i] as usize] = self.pieces[i];
+        }
+        Position::new(
+            pieces,
+            State {
+                side_to_move: Side(stm),
+                castling_rights: NO_RIGHTS,
+                en_passant_target: None,
+                halfmove_clock: 0,
+                fullmove_number: 1,
+                prev_hashes: None,
+            },
+        )
+    }
+}
+
+struct Solved {
+    pieces: Vec<Piece>,
+    values: Vec<u8>,
+    is_mate: Vec<bool>,
+}
+
+/// Solves endgames together with all endgames reachable from them by captures and promotions
+#[derive(Default)]
+pub struct Generator {
+    solved: HashMap<u64, Solved>,
+}
+
+impl Generator {
+    /// Solves the endgame and writes its WDL and DTZ file into `dir`
+    pub fn write(&mut self, name: &str, dir: &Path) -> Result<(), String> {
+        let mut pieces = vec![];
+        for (side, part) in name.split('v').enumerate() {
+            for c in part.chars() {
+                pieces.push(PieceKind::try_from_char(c)?.to_piece(Side(side as u8)));
+            }
+        }
+        pieces.sort_by_key(|pc| pc.0);
+        if is_flipped(&pieces) {
+            return Err(format!("{name} has to be written with the colors swapped"));
+        }
+
+        self.solve(pieces.clone());
+        let solved = &self.solved[&material_key(&pieces, false)];
+        let dtz = &self.dtz(solved);
+
+        let positions = |value: fn(u8, u16) -> Option<u16>| {
+            (0..solved.values.len()).filter_map(move |idx| {
+                let value = value(solved.values[idx], dtz[idx])?;
+                let placement = Placement::from_index(&solved.pieces, idx);
+                Some((placement.to_position(idx as u8 & 1), Some(value)))
+            })
+        };
+
+        write_table(
+            &dir.join(format!("{name}.rtbw")),
+            name,
+            TableKind::Wdl,
+            positions(|value, _| match value {
+                LOSS => Some(0),
+                DRAW => Some(2),
+                WIN => Some(4),
+                _ => None,
+            }),
+        )?;
+        write_table(
+            &dir.join(format!("{name}.rtbz")),
+            name,
+            TableKind::Dtz,
+            positions(|value, dtz| (value == WIN || value == LOSS).then(|| dtz - 1)),
+        )
+    }
+
+    /// Returns the value of a position reached by a capture or promotion
+    fn probe(&self, placement: &Placement, stm: u8) -> u8 {
+        if placement.len == 2 {
+            return DRAW;
+        }
+
+        let flip = is_flipped(&placement.pieces[..placement.len]);
+        let mut flipped = *placement;
+        if flip {
+            for i in 0..flipped.len {
+                flipped.pieces[i].0 ^= 1;
+                flipped.squares[i] ^= 56;
+            }
+        }
+        let len = flipped.len;
+        let mut order: Vec<usize> = (0..len).collect();
+        order.sort_by_key(|&i| flipped.pieces[i].0);
+        let sorted = Placement {
+            pieces: flipped.pieces,
+            squares: std::array::from_fn(|i| {
+                if i < len {
+                    flipped.squares[order[i]]
+                } else {
+                    0
+                }
+            }),
+            len,
+        };
+
+        let solved = &self.solved[&material_key(&flipped.pieces[..len], false)];
+        solved.values[sorted.index(stm ^ flip as u8)]
+    }
+
+    fn solve_flipped(&mut self, mut pieces: Vec<Piece>) {
+        if pieces.len() <= 2 {
+            return;
+        }
+        if is_flipped(&pieces) {
+            for pc in pieces.iter_mut() {
+                pc.0 ^= 1;
+            }
+        }
+        pieces.sort_by_key(|pc| pc.0);
+        self.solve(pieces);
+    }
+
+    /// Computes the value of every position of the endgame with the given pieces, which have to
+    /// be sorted
+    fn solve(&mut self, pieces: Vec<Piece>) {
+        let key = material_key(&pieces, false);
+        if self.solved.contains_key(&key) {
+            return;
+        }
+
+        for (i, pc) in pieces.iter().enumerate() {
+            if pc.kind() == KING {
+                continue;
+            }
+            let mut sub = pieces.clone();
+            sub.remove(i);
+            self.solve_flipped(sub);
+
+            if pc.kind() == PAWN {
+                for kind in [QUEEN, ROOK, BISHOP, KNIGHT] {
+                    let mut sub = pieces.clone();
+                    sub[i] = kind.to_piece(pc.side());
+                    self.solve_flipped(sub);
+                }
+            }
+        }
+
+        let size = 2 << (6 * pieces.len());
+        let mut values = vec![UNKNOWN; size];
+        let mut is_mate = vec![false; size];
+        // The number of moves within the endgame whose value isn't known yet, and the best value
+        // of the moves whose value is known
+        let mut moves_left = vec![0u8; size];
+        let mut best = vec![LOSS; size];
+        let mut queue = vec![];
+
+        for idx in 0..size {
+            let placement = Placement::from_index(&pieces, idx);
+            let stm = idx as u8 & 1;
+            if !placement.is_valid(stm) {
+                values[idx] = INVALID;
+                continue;
+            }
+
+            let mut has_moves = false;
+            placement.for_each_move(stm, |child, in_endgame, _| {
+                has_moves = true;
+                if in_endgame {
+                    moves_left[idx] += 1;
+                } else {
+                    best[idx] = best[idx].max(negate(self.probe(child, stm ^ 1)));
+                }
+            });
+
+            if !has_moves {
+                is_mate[idx] = placement.is_attacked(placement.king(stm), stm ^ 1);
+                values[idx] = if is_mate[idx] { LOSS } else { DRAW };
+            } else if best[idx] == WIN || moves_left[idx] == 0 {
+                values[idx] = best[idx];
+            } else {
+                continue;
+            }
+            queue.push(idx);
+        }
+
+        let mut next = 0;
+        while next < queue.len() {
+            let idx = queue[next];
+            next += 1;
+            let value = values[idx];
+            let mover = (idx as u8 & 1) ^ 1;
+
+            Placement::from_index(&pieces, idx).for_each_unmove(mover, true, |parent| {
+                let parent_idx = parent.index(mover);
+                if values[parent_idx] != UNKNOWN || !parent.is_valid(mover) {
+                    return;
+                }
+
+                if value == LOSS {
+                    values[parent_idx] = WIN;
+                    queue.push(parent_idx);
+                    return;
+                }
+                best[parent_idx] = best[parent_idx].max(negate(value));
+                moves_left[parent_idx] -= 1;
+                if moves_left[parent_idx] == 0 {
+                    values[parent_idx] = best[parent_idx];
+                    queue.push(parent_idx);
+                }
+            });
+        }
+
+        // Positions that are still unknown can avoid losing forever
+        for value in values.iter_mut().filter(|value| **value == UNKNOWN) {
+            *value = DRAW;
+        }
+
+        self.solved.insert(
+            key,
+            Solved {
+                pieces,
+                values,
+                is_mate,
+            },
+        );
+    }
+
+    /// Computes the number of plies to the next capture or pawn move of every won or lost
+    /// position with perfect play, where the winning side converts as fast as possible
+    fn dtz(&self, solved: &Solved) -> Vec<u16> {
+        let values = &solved.values;
+        let mut dtz = vec![0u16; values.len()];
+        // The number of moves of lost positions that don't capture or move a pawn and haven't
+        // been assigned a value yet
+        let mut moves_left = vec![0u8; values.len()];
+        let mut queue = vec![];
+
+        for idx in 0..values.len() {
+            let value = values[idx];
+            if value != WIN && value != LOSS {
+                continue;
+            }
+            let placement = Placement::from_index(&solved.pieces, idx);
+            let stm = idx as u8 & 1;
+
+            let mut converts = false;
+            placement.for_each_move(stm, |child, in_endgame, is_zeroing| {
+                if in_endgame && !is_zeroing {
+                    moves_left[idx] += 1;
+                }
+                // Captures and promotions always lead to other endgames
+                converts |= if in_endgame {
+                    let child_idx = child.index(stm ^ 1);
+                    values[child_idx] == LOSS && (is_zeroing || solved.is_mate[child_idx])
+                } else {
+                    self.probe(child, stm ^ 1) == LOSS
+                };
+            });
+
+            if (value == WIN && converts) || (value == LOSS && moves_left[idx] == 0) {
+                dtz[idx] = 1;
+                queue.push(idx);
+            }
+        }
+
+        let mut next = 0;
+        while next < queue.len() {
+            let idx = queue[next];
+            next += 1;
+            let mover = (idx as u8 & 1) ^ 1;
+
+            Placement::from_index(&solved.pieces, idx).for_each_unmove(mover, false, |parent| {
+                let parent_idx = parent.index(mover);
+                if dtz[parent_idx] != 0 || !parent.is_valid(mover) {
+                    return;
+                }
+
+                if values[idx] == LOSS && values[parent_idx] == WIN {
+                    dtz[parent_idx] = dtz[idx] + 1;
+                    queue.push(parent_idx);
+                } else if values[idx] == WIN && values[parent_idx] == LOSS {
+                    moves_left[parent_idx] -= 1;
+                    if moves_left[parent_idx] == 0 {
+                        dtz[parent_idx] = dtz[idx] + 1;
+                        queue.push(parent_idx);
+                    }
+                }
+            });
+        }
+
+        for (idx, &value) in values.iter().enumerate() {
+            if value == WIN || value == LOSS {
+                assert!(
+                    (1..=100).contains(&dtz[idx]),
+                    "DTZ {} at index {idx} is out of range",
+                    dtz[idx]
+                );
+            }
+        }
+        dtz
+    }
+}
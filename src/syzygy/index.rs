@@ -0,0 +1,170 @@
+use crate::square::Square;
+use std::sync::OnceLock;
+
+/// Lookup tables used to turn a placement of pieces into an index of a tablebase file
+pub struct Indices {
+    /// Encodes the squares a2 to h7 to 0..48, the pawn with the highest value is the leading pawn
+    pub map_pawns: [usize; 64],
+    /// Encodes the squares below the a1-h8 diagonal to 0..28
+    pub map_b1h1h7: [usize; 64],
+    /// Encodes the squares in the triangle a1-d1-d4 to 0..10
+    pub map_a1d1d4: [usize; 64],
+    /// Encodes the 462 legal placements of two kings where the first one is in the triangle
+    /// a1-d1-d4, indexed by `map_a1d1d4` of the first king and the square of the second king
+    pub map_kk: [[usize; 64]; 10],
+    /// Number of ways to choose `k` elements from a set of `n` elements, indexed by `[k][n]`
+    pub binomial: [[u64; 64]; 6],
+    /// Indexed by the number of leading pawns and the square of the leading pawn
+    pub lead_pawn_idx: [[u64; 64]; 6],
+    /// Indexed by the number of leading pawns and the file of the leading pawn
+    pub lead_pawns_size: [[u64; 4]; 6],
+}
+
+/// Distance of a square from the a1-h8 diagonal, positive values are above the diagonal
+pub fn off_a1h8(sq: usize) -> i32 {
+    (sq >> 3) as i32 - (sq & 7) as i32
+}
+
+pub fn indices() -> &'static Indices {
+    static INDICES: OnceLock<Indices> = OnceLock::new();
+    INDICES.get_or_init(Indices::new)
+}
+
+impl Indices {
+    fn new() -> Indices {
+        let mut indices = Indices {
+            map_pawns: [0; 64],
+            map_b1h1h7: [0; 64],
+            map_a1d1d4: [0; 64],
+            map_kk: [[0; 64]; 10],
+            binomial: [[0; 64]; 6],
+            lead_pawn_idx: [[0; 64]; 6],
+            lead_pawns_size: [[0; 4]; 6],
+        };
+
+        let mut code = 0;
+        for sq in 0..64 {
+            if off_a1h8(sq) < 0 {
+                indices.map_b1h1h7[sq] = code;
+                code += 1;
+            }
+        }
+
+        // Squares on the diagonal are encoded after the ones below the diagonal
+        let mut diagonal = vec![];
+        code = 0;
+        for sq in 0..28 {
+            if off_a1h8(sq) < 0 && sq & 7 <= 3 {
+                indices.map_a1d1d4[sq] = code;
+                code += 1;
+            } else if off_a1h8(sq) == 0 && sq & 7 <= 3 {
+                diagonal.push(sq);
+            }
+        }
+        for sq in diagonal {
+            indices.map_a1d1d4[sq] = code;
+            code += 1;
+        }
+
+        // If the first king is on the diagonal, the second one must not be above it. Placements
+        // with both kings on the diagonal are encoded last.
+        let mut both_on_diagonal = vec![];
+        code = 0;
+        for idx in 0..10 {
+            for s1 in 0..28 {
+                // b1 is the only square in the triangle that is mapped to zero
+                if indices.map_a1d1d4[s1] != idx || (idx == 0 && s1 != 1) {
+                    continue;
+                }
+
+                let king_moves = Square(s1 as u8).king_moves().0 | (1 << s1);
+                for s2 in 0..64 {
+                    if king_moves & (1 << s2) != 0 {
+                        continue;
+                    }
+
+                    if off_a1h8(s1) == 0 && off_a1h8(s2) > 0 {
+                        continue;
+                    }
+
+                    if off_a1h8(s1) == 0 && off_a1h8(s2) == 0 {
+                        both_on_diagonal.push((idx, s2));
+                    } else {
+                        indices.map_kk[idx][s2] = code;
+                        code += 1;
+                    }
+                }
+            }
+        }
+        for (idx, s2) in both_on_diagonal {
+            indices.map_kk[idx][s2] = code;
+            code += 1;
+        }
+
+        indices.binomial[0][0] = 1;
+        for n in 1..64 {
+            for k in 0..6.min(n + 1) {
+                indices.binomial[k][n] = if k > 0 {
+                    indices.binomial[k - 1][n - 1]
+                } else {
+                    0
+                } + if k < n { indices.binomial[k][n - 1] } else { 0 };
+            }
+        }
+
+        // There are 47 squares left for the other pawns if the leading pawn is on a2, and two
+        // less for each square the leading pawn is further away from the edge
+        let mut available_squares = 48;
+        for lead_pawns_count in 1..6 {
+            for file in 0..4 {
+                let mut idx = 0;
+
+                for rank in 1..7 {
+                    let sq = rank * 8 + file;
+
+                    if lead_pawns_count == 1 {
+                        indices.map_pawns[sq] = available_squares - 1;
+                        indices.map_pawns[sq ^ 7] = available_squares - 2;
+                        available_squares -= 2;
+                    }
+
+                    indices.lead_pawn_idx[lead_pawns_count][sq] = idx;
+                    idx += indices.binomial[lead_pawns_count - 1][indices.map_pawns[sq]];
+                }
+
+                indices.lead_pawns_size[lead_pawns_count][file] = idx;
+            }
+        }
+
+        indices
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::syzygy::index::indices;
+
+    #[test]
+    fn king_placements() {
+        let indices = indices();
+        let mut seen = vec![false; 462];
+        for row in indices.map_kk.iter() {
+            for &code in row.iter() {
+                seen[code] = true;
+            }
+        }
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[test]
+    fn pawn_squares() {
+        let indices = indices();
+        let mut codes: Vec<usize> = (8..56).map(|sq| indices.map_pawns[sq]).collect();
+        codes.sort();
+        assert_eq!(codes, (0..48).collect::<Vec<usize>>());
+
+        // The leading pawn can be on one of 24 squares
+        assert_eq!(indices.lead_pawns_size[1].iter().sum::<u64>(), 24);
+        assert_eq!(indices.binomial[2][48], 48 * 47 / 2);
+    }
+}
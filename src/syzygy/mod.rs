@@ -0,0 +1,584 @@
+#[cfg(test)]
+mod generate;
+mod index;
+mod table;
+
+use self::table::{material_key, DtzProbe, Table};
+use crate::{castle::NO_RIGHTS, piece::PAWN, r#move::Move, Position};
+use std::{collections::HashMap, env, fs, ops::Neg, path::PathBuf};
+
+/// Score for a position that is won according to the tablebases, lower than any checkmate but
+/// higher than any evaluation
+pub const TABLEBASE_WIN: i32 = 1_000_000;
+
+/// Centipawns reported over UCI for a tablebase win, as GUIs don't expect scores as large as
+/// `TABLEBASE_WIN`
+pub const TABLEBASE_WIN_CP: i32 = 20_000;
+
+/// Result of a position from the perspective of the side to move, taking the fifty move rule into
+/// account
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Wdl {
+    Loss = -2,
+    /// Loss that is a draw due to the fifty move rule
+    BlessedLoss = -1,
+    Draw = 0,
+    /// Win that is a draw due to the fifty move rule
+    CursedWin = 1,
+    Win = 2,
+}
+
+impl Wdl {
+    fn from_value(value: i32) -> Wdl {
+        match value {
+            i32::MIN..=-2 => Wdl::Loss,
+            -1 => Wdl::BlessedLoss,
+            0 => Wdl::Draw,
+            1 => Wdl::CursedWin,
+            _ => Wdl::Win,
+        }
+    }
+
+    pub fn to_score(self) -> i32 {
+        match self {
+            Wdl::Loss => -TABLEBASE_WIN,
+            Wdl::BlessedLoss => -2,
+            Wdl::Draw => 0,
+            Wdl::CursedWin => 2,
+            Wdl::Win => TABLEBASE_WIN,
+        }
+    }
+}
+
+impl Neg for Wdl {
+    type Output = Wdl;
+
+    fn neg(self) -> Wdl {
+        Wdl::from_value(-(self as i32))
+    }
+}
+
+/// The best move in a position according to the DTZ tables
+#[derive(Debug, PartialEq)]
+pub struct RootProbe {
+    pub best_move: Move,
+    pub wdl: Wdl,
+    /// Distance to the next capture or pawn move in plies, negative if the position is lost
+    pub dtz: i32,
+}
+
+impl RootProbe {
+    /// Score in centipawns to report over UCI, where wins that are closer to zeroing the fifty
+    /// move counter score higher
+    pub fn uci_score(&self) -> i32 {
+        match self.wdl {
+            Wdl::Win => TABLEBASE_WIN_CP - self.dtz.abs(),
+            Wdl::Loss => -TABLEBASE_WIN_CP + self.dtz.abs(),
+            wdl => wdl.to_score(),
+        }
+    }
+}
+
+/// A set of Syzygy tablebases, with the files being memory mapped on first access
+pub struct Tablebases {
+    tables: Vec<Table>,
+    /// Maps both material keys of a table (with either side being the stronger one) to the table
+    keys: HashMap<u64, usize>,
+    max_pieces: usize,
+}
+
+impl Tablebases {
+    /// Looks for tablebase files in the given directories, separated like the `PATH` variable
+    pub fn open(paths: &str) -> Result<Tablebases, String> {
+        let mut wdl_paths = HashMap::new();
+        let mut dtz_paths = HashMap::new();
+
+        for dir in env::split_paths(paths) {
+            let entries = fs::read_dir(&dir)
+                .map_err(|err| format!("Cannot read directory {}: {err}", dir.display()))?;
+
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let (name, extension) = match (path.file_stem(), path.extension()) {
+                    (Some(name), Some(extension)) => (
+                        name.to_string_lossy().into_owned(),
+                        extension.to_string_lossy().into_owned(),
+                    ),
+                    _ => continue,
+                };
+
+                match extension.as_str() {
+                    "rtbw" => wdl_paths.insert(name, path),
+                    "rtbz" => dtz_paths.insert(name, path),
+                    _ => None,
+                };
+            }
+        }
+
+        let mut tablebases = Tablebases {
+            tables: vec![],
+            keys: HashMap::new(),
+            max_pieces: 0,
+        };
+
+        // Only the WDL files are required, DTZ files are just needed for probing at the root
+        for (name, wdl_path) in wdl_paths {
+            let dtz_path: Option<PathBuf> = dtz_paths.remove(&name);
+            if let Some(table) = Table::new(&name, wdl_path, dtz_path) {
+                tablebases.max_pieces = tablebases.max_pieces.max(table.piece_count);
+                tablebases.keys.insert(table.key, tablebases.tables.len());
+                tablebases.keys.insert(table.key2, tablebases.tables.len());
+                tablebases.tables.push(table);
+            }
+        }
+
+        Ok(tablebases)
+    }
+
+    pub fn table_count(&self) -> usize {
+        self.tables.len()
+    }
+
+    pub fn max_pieces(&self) -> usize {
+        self.max_pieces
+    }
+
+    /// Tablebases don't contain positions where castling is still possible
+    pub fn can_probe(&self, position: &Position) -> bool {
+        position.state().castling_rights == NO_RIGHTS
+            && (position.occupied().occupied() as usize) <= self.max_pieces
+    }
+
+    fn table(&self, position: &Position) -> Option<&Table> {
+        self.keys
+            .get(&material_key(position, false))
+            .map(|&index| &self.tables[index])
+    }
+
+    /// Probes the WDL table for the position, returns `None` if the position is not covered by
+    /// the tablebases. The result does not account for the halfmove clock of the position.
+    pub fn probe_wdl(&self, position: &mut Position) -> Option<Wdl> {
+        if !self.can_probe(position) {
+            return None;
+        }
+        self.search(position, false).map(|(wdl, _)| wdl)
+    }
+
+    /// Probes the DTZ table for the position, returns `None` if the position is not covered by
+    /// the tablebases. The result is the number of plies to the next capture or pawn move,
+    /// assuming a halfmove clock of zero:
+    ///
+    /// - `n < -100`: loss, but draw under the fifty move rule
+    /// - `-100 <= n < -1`: loss in `n` plies
+    /// - `-1`: the side to move is checkmated
+    /// - `0`: draw
+    /// - `1 < n <= 100`: win in `n` plies
+    /// - `100 < n`: win, but draw under the fifty move rule
+    ///
+    /// The value might be one ply off in either direction, except for positions right on the
+    /// edge of the fifty move rule.
+    pub fn probe_dtz(&self, position: &mut Position) -> Option<i32> {
+        if !self.can_probe(position) {
+            return None;
+        }
+
+        let (wdl, zeroing_is_best) = self.search(position, true)?;
+
+        // DTZ tables don't store draws
+        if wdl == Wdl::Draw {
+            return Some(0);
+        }
+
+        // Positions where a capture or pawn move is best are not stored in the tables
+        if zeroing_is_best {
+            return Some(dtz_before_zeroing(wdl));
+        }
+
+        match self.table(position)?.probe_dtz(position, wdl)? {
+            DtzProbe::Value(dtz) => {
+                let is_cursed = wdl == Wdl::CursedWin || wdl == Wdl::BlessedLoss;
+                Some((dtz + if is_cursed { 100 } else { 0 }) * (wdl as i32).signum())
+            }
+            DtzProbe::ChangeSideToMove => self.probe_dtz_by_search(position, wdl),
+        }
+    }
+
+    /// The DTZ table only stores the values of positions with the other side to move, so find the
+    /// best value by searching one ply
+    fn probe_dtz_by_search(&self, position: &mut Position, wdl: Wdl) -> Option<i32> {
+        let (legal_moves, _) = position.legal_moves_vec();
+
+        let mut min_dtz = i32::MAX;
+        for m in legal_moves.iter() {
+            let is_zeroing = m.is_capture() || position.at(m.from()).kind() == PAWN;
+
+            let state = position.state().clone();
+            let hash = position.hash();
+            let capture = position.make(*m);
+
+            // For zeroing moves we want the DTZ of the move before it is played, the search
+            // after playing the move just tells us whether it is winning
+            let dtz = if is_zeroing {
+                self.search(position, false)
+                    .map(|(wdl, _)| -dtz_before_zeroing(wdl))
+            } else {
+                self.probe_dtz(position).map(|dtz| -dtz)
+            };
+            let is_mate = dtz == Some(1) && {
+                let (legal_moves, is_in_check) = position.legal_moves_vec();
+                is_in_check && legal_moves.is_empty()
+            };
+
+            position.unmake(*m, capture, &state, hash);
+
+            let mut dtz = dtz?;
+            if is_mate {
+                min_dtz = 1;
+            }
+            if !is_zeroing {
+                dtz += dtz.signum();
+            }
+
+            // Skip draws, and only consider winning moves if the position is won
+            if dtz < min_dtz && dtz.signum() == (wdl as i32).signum() {
+                min_dtz = dtz;
+            }
+        }
+
+        // Without legal moves the position is checkmate
+        Some(if min_dtz == i32::MAX { -1 } else { min_dtz })
+    }
+
+    /// Tablebases store "don't care" values for positions where a capture is the best move, so
+    /// captures always need to be searched. If `check_zeroing_moves` is true, pawn moves are
+    /// searched as well, which is needed before probing DTZ tables. Returns the result of the
+    /// position together with whether a capture or pawn move is the best move.
+    fn search(&self, position: &mut Position, check_zeroing_moves: bool) -> Option<(Wdl, bool)> {
+        let (legal_moves, _) = position.legal_moves_vec();
+
+        let mut best_value = Wdl::Loss;
+        let mut move_count = 0;
+
+        for m in legal_moves.iter() {
+            if !m.is_capture() && (!check_zeroing_moves || position.at(m.from()).kind() != PAWN) {
+                continue;
+            }
+            move_count += 1;
+
+            let state = position.state().clone();
+            let hash = position.hash();
+            let capture = position.make(*m);
+            let result = self.search(position, false);
+            position.unmake(*m, capture, &state, hash);
+
+            let value = -result?.0;
+            if value > best_value {
+                best_value = value;
+                if value == Wdl::Win {
+                    return Some((value, true));
+                }
+            }
+        }
+
+        // If all legal moves were searched, the value stored in the table might be wrong (for
+        // example because tables don't know about en-passant captures)
+        let all_moves_searched = move_count > 0 && move_count == legal_moves.len();
+        let value = if all_moves_searched {
+            best_value
+        } else if position.occupied().occupied() == 2 {
+            Wdl::Draw
+        } else {
+            self.table(position)?.probe_wdl(position)?
+        };
+
+        if best_value >= value {
+            return Some((best_value, best_value > Wdl::Draw || all_moves_searched));
+        }
+        Some((value, false))
+    }
+
+    /// Ranks all legal moves using the DTZ tables and returns the best one. Winning moves are
+    /// chosen such that the next capture or pawn move happens as soon as possible, while losing
+    /// moves delay it as long as possible.
+    pub fn probe_root(&self, position: &mut Position) -> Option<RootProbe> {
+        if !self.can_probe(position) {
+            return None;
+        }
+
+        let halfmove_clock = position.state().halfmove_clock as i32;
+        let has_repeated = has_repeated(position);
+
+        let (legal_moves, _) = position.legal_moves_vec();
+        let mut best: Option<(i32, RootProbe)> = None;
+
+        for m in legal_moves.iter() {
+            let state = position.state().clone();
+            let hash = position.hash();
+            let capture = position.make(*m);
+
+            let dtz = if position.state().halfmove_clock == 0 {
+                self.probe_wdl(position).map(|wdl| dtz_before_zeroing(-wdl))
+            } else {
+                self.probe_dtz(position)
+                    .map(|dtz| -dtz)
+                    .map(|dtz| dtz + dtz.signum())
+            };
+            let is_mate = dtz == Some(2) && {
+                let (legal_moves, is_in_check) = position.legal_moves_vec();
+                is_in_check && legal_moves.is_empty()
+            };
+
+            position.unmake(*m, capture, &state, hash);
+
+            let dtz = if is_mate { 1 } else { dtz? };
+
+            // Wins are ranked equally as long as they can be converted before the fifty move
+            // rule kicks in, same for losses
+            let rank = if dtz > 0 {
+                if dtz + halfmove_clock <= 99 && !has_repeated {
+                    1000
+                } else {
+                    1000 - (dtz + halfmove_clock)
+                }
+            } else if dtz < 0 {
+                if -dtz * 2 + halfmove_clock < 100 {
+                    -1000
+                } else {
+                    -1000 + (-dtz + halfmove_clock)
+                }
+            } else {
+                0
+            };
+
+            let is_better = match &best {
+                None => true,
+                Some((best_rank, best_probe)) => {
+                    rank > *best_rank
+                        || (rank == *best_rank && dtz > 0 && dtz < best_probe.dtz)
+                        || (rank == *best_rank && dtz < 0 && dtz < best_probe.dtz)
+                }
+            };
+            if is_better {
+                let wdl = match rank {
+                    900.. => Wdl::Win,
+                    1..=899 => Wdl::CursedWin,
+                    0 => Wdl::Draw,
+                    -899..=-1 => Wdl::BlessedLoss,
+                    _ => Wdl::Loss,
+                };
+                best = Some((
+                    rank,
+                    RootProbe {
+                        best_move: *m,
+                        wdl,
+                        dtz,
+                    },
+                ));
+            }
+        }
+
+        best.map(|(_, probe)| probe)
+    }
+}
+
+/// DTZ tables don't store values for positions where a capture or pawn move is best, but given
+/// the result of the position the value can be recovered
+fn dtz_before_zeroing(wdl: Wdl) -> i32 {
+    match wdl {
+        Wdl::Win => 1,
+        Wdl::CursedWin => 101,
+        Wdl::Draw => 0,
+        Wdl::BlessedLoss => -101,
+        Wdl::Loss => -1,
+    }
+}
+
+/// Checks if any position since the last capture or pawn move occurred twice
+fn has_repeated(position: &Position) -> bool {
+    let state = position.state();
+    let prev_hashes = match &state.prev_hashes {
+        Some(prev_hashes) => prev_hashes,
+        None => return false,
+    };
+
+    let count = (state.halfmove_clock as usize).min(prev_hashes.len());
+    let mut hashes: Vec<u64> = prev_hashes[prev_hashes.len() - count..].to_vec();
+    hashes.push(position.hash());
+    hashes.sort();
+    hashes.windows(2).any(|w| w[0] == w[1])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        r#move::Move,
+        syzygy::{generate::Generator, RootProbe, Tablebases, Wdl, TABLEBASE_WIN_CP},
+        Position,
+    };
+    use std::path::Path;
+
+    const TABLES: [&str; 7] = ["KQvK", "KRvK", "KBvK", "KNvK", "KPvK", "KBNvK", "KPvKP"];
+
+    /// Uses the tables of `tests/syzygy`, or the directory set by `SYZYGY_PATH` at compile time
+    fn tablebases() -> Tablebases {
+        let path = option_env!("SYZYGY_PATH")
+            .unwrap_or(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/syzygy"));
+        for name in TABLES {
+            for extension in ["rtbw", "rtbz"] {
+                let file = Path::new(path).join(format!("{name}.{extension}"));
+                assert!(file.exists(), "Tablebase file {} not found", file.display());
+            }
+        }
+
+        Tablebases::open(path).unwrap()
+    }
+
+    /// Solves the endgames of `tests/syzygy` and writes their files, takes a few minutes even
+    /// with `--release`
+    #[test]
+    #[ignore = "rewrites the files of tests/syzygy"]
+    fn generate_tables() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/syzygy");
+        let mut generator = Generator::default();
+        for name in TABLES {
+            generator.write(name, &dir).unwrap();
+        }
+    }
+
+    #[test]
+    fn uci_score() {
+        let position = Position::from_fen("8/8/8/8/4k3/8/8/KR6 w - - 0 1");
+        let probe = |wdl, dtz| RootProbe {
+            best_move: Move::try_from_str("b1b4", &position).unwrap(),
+            wdl,
+            dtz,
+        };
+
+        assert_eq!(probe(Wdl::Win, 27).uci_score(), TABLEBASE_WIN_CP - 27);
+        assert_eq!(probe(Wdl::Loss, -26).uci_score(), -TABLEBASE_WIN_CP + 26);
+        assert_eq!(probe(Wdl::CursedWin, 120).uci_score(), 2);
+        assert_eq!(probe(Wdl::Draw, 0).uci_score(), 0);
+    }
+
+    fn probe_wdl(tablebases: &Tablebases, fen: &str) -> Option<Wdl> {
+        tablebases.probe_wdl(&mut Position::from_fen(fen))
+    }
+
+    fn probe_dtz(tablebases: &Tablebases, fen: &str) -> Option<i32> {
+        tablebases.probe_dtz(&mut Position::from_fen(fen))
+    }
+
+    #[test]
+    fn wdl() {
+        let tablebases = tablebases();
+        assert!(tablebases.max_pieces() >= 4);
+
+        assert_eq!(
+            probe_wdl(&tablebases, "8/8/8/8/8/8/8/K1k5 w - - 0 1"),
+            Some(Wdl::Draw)
+        );
+        assert_eq!(
+            probe_wdl(&tablebases, "8/8/8/4k3/8/8/8/KQ6 w - - 0 1"),
+            Some(Wdl::Win)
+        );
+        assert_eq!(
+            probe_wdl(&tablebases, "8/8/8/4k3/8/8/8/KQ6 b - - 0 1"),
+            Some(Wdl::Loss)
+        );
+        // The same position with the colors swapped
+        assert_eq!(
+            probe_wdl(&tablebases, "kq6/8/8/8/4K3/8/8/8 b - - 0 1"),
+            Some(Wdl::Win)
+        );
+
+        // Black can capture the queen
+        assert_eq!(
+            probe_wdl(&tablebases, "8/8/8/8/8/8/1k6/1Q5K b - - 0 1"),
+            Some(Wdl::Draw)
+        );
+
+        // Rook pawn with the defending king in the corner
+        assert_eq!(
+            probe_wdl(&tablebases, "k7/8/8/8/8/8/P7/K7 w - - 0 1"),
+            Some(Wdl::Draw)
+        );
+        assert_eq!(
+            probe_wdl(&tablebases, "8/4P3/4K3/8/8/8/8/k7 w - - 0 1"),
+            Some(Wdl::Win)
+        );
+
+        // White can capture en-passant, which the tables don't know about
+        assert_eq!(
+            probe_wdl(&tablebases, "8/8/8/8/3Pp3/8/8/k3K3 b - d3 0 1"),
+            Some(Wdl::Draw)
+        );
+
+        // Too many pieces
+        assert_eq!(
+            probe_wdl(&tablebases, "8/8/8/8/8/8/PPPPPP2/K1k5 w - - 0 1"),
+            None
+        );
+    }
+
+    #[test]
+    fn dtz() {
+        let tablebases = tablebases();
+
+        // Mate in one
+        assert_eq!(
+            probe_dtz(&tablebases, "k7/8/1K6/8/8/8/8/6Q1 w - - 0 1"),
+            Some(1)
+        );
+        assert_eq!(
+            probe_dtz(&tablebases, "k7/Q7/1K6/8/8/8/8/8 b - - 0 1"),
+            Some(-1)
+        );
+
+        // The pawn can promote right away
+        assert_eq!(
+            probe_dtz(&tablebases, "8/4P3/4K3/8/8/8/8/k7 w - - 0 1"),
+            Some(1)
+        );
+
+        let dtz = probe_dtz(&tablebases, "8/8/8/8/4k3/8/8/KR6 w - - 0 1").unwrap();
+        assert!(dtz > 1 && dtz <= 100);
+        let dtz = probe_dtz(&tablebases, "8/8/8/8/4k3/8/8/KR6 b - - 0 1").unwrap();
+        assert!((-100..-1).contains(&dtz));
+
+        // KBNK is won, but might take more than fifty moves
+        let dtz = probe_dtz(&tablebases, "8/8/8/8/8/8/8/KBN4k w - - 0 1").unwrap();
+        assert!(dtz > 0);
+    }
+
+    #[test]
+    fn root() {
+        let tablebases = tablebases();
+
+        let mut position = Position::from_fen("k7/8/1K6/8/8/8/8/6Q1 w - - 0 1");
+        let probe = tablebases.probe_root(&mut position).unwrap();
+        assert_eq!(probe.wdl, Wdl::Win);
+        assert_eq!(probe.dtz, 1);
+        position.make(probe.best_move);
+        let (legal_moves, is_in_check) = position.legal_moves_vec();
+        assert!(is_in_check && legal_moves.is_empty());
+
+        // Playing perfectly converts the endgame within the fifty move rule
+        let mut position = Position::from_fen("8/8/8/8/4k3/8/8/KR6 w - - 0 1");
+        for _ in 0..100 {
+            let probe = tablebases.probe_root(&mut position).unwrap();
+            assert_eq!(probe.wdl, Wdl::Win);
+            position.make(probe.best_move);
+
+            let (legal_moves, is_in_check) = position.legal_moves_vec();
+            if legal_moves.is_empty() {
+                assert!(is_in_check);
+                return;
+            }
+
+            let probe = tablebases.probe_root(&mut position).unwrap();
+            assert_eq!(probe.wdl, Wdl::Loss);
+            position.make(probe.best_move);
+        }
+        panic!("did not checkmate within fifty moves");
+    }
+}
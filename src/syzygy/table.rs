@@ -0,0 +1,851 @@
+use super::{
+    index::{indices, off_a1h8},
+    Wdl,
+};
+use crate::{piece::Piece, side::WHITE, Position};
+use memmap2::Mmap;
+use std::{fs::File, path::PathBuf, sync::OnceLock};
+
+#[cfg(test)]
+mod write;
+
+#[cfg(test)]
+pub use self::write::write_table;
+
+pub const MAX_PIECES: usize = 7;
+
+const WDL_MAGIC: [u8; 4] = [0x71, 0xE8, 0x23, 0x5D];
+const DTZ_MAGIC: [u8; 4] = [0xD7, 0x66, 0x0C, 0xA5];
+
+/// Flags stored per table in the header of the compressed data
+const FLAG_STM: u8 = 1;
+const FLAG_MAPPED: u8 = 2;
+const FLAG_WIN_PLIES: u8 = 4;
+const FLAG_LOSS_PLIES: u8 = 8;
+const FLAG_WIDE: u8 = 16;
+const FLAG_SINGLE_VALUE: u8 = 128;
+
+/// Order of the piece kinds used in the names of tablebase files
+const PIECE_CHARS: [char; 6] = ['K', 'Q', 'R', 'B', 'N', 'P'];
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum TableKind {
+    Wdl,
+    Dtz,
+}
+
+/// Packs the number of pieces per side and kind into a single number. If `mirror` is true, the
+/// key is computed as if the colors of all pieces were swapped.
+pub fn material_key(position: &Position, mirror: bool) -> u64 {
+    let mut key = 0;
+    for pc in 0..12 {
+        let count = position.piece(Piece(pc)).occupied() as u64;
+        let side = (pc & 1) ^ mirror as u8;
+        key += count << (4 * (side * 6 + (pc >> 1)));
+    }
+    key
+}
+
+/// Parses a tablebase name like `KRPvKP` into the number of pieces per side and kind, with the
+/// pieces before the `v` belonging to white
+fn parse_material(name: &str) -> Option<[[u8; 6]; 2]> {
+    let (white, black) = name.split_once('v')?;
+
+    let mut counts = [[0; 6]; 2];
+    for (side, pieces) in [white, black].iter().enumerate() {
+        if !pieces.starts_with('K') {
+            return None;
+        }
+        for c in pieces.chars() {
+            let kind = PIECE_CHARS.iter().position(|&pc| pc == c)?;
+            counts[side][kind] += 1;
+        }
+    }
+
+    if counts[0][0] != 1 || counts[1][0] != 1 {
+        return None;
+    }
+    Some(counts)
+}
+
+fn key_from_counts(counts: &[[u8; 6]; 2], mirror: bool) -> u64 {
+    let mut key = 0;
+    for (side, kinds) in counts.iter().enumerate() {
+        for (kind, &count) in kinds.iter().enumerate() {
+            key += (count as u64) << (4 * ((side ^ mirror as usize) * 6 + kind));
+        }
+    }
+    key
+}
+
+/// Indexing information of a single table within a tablebase file. There are one or two of them
+/// for tables without pawns (one for each side to move), and four or eight for tables with pawns
+/// (one for each file of the leading pawn).
+#[derive(Default)]
+struct PairsData {
+    flags: u8,
+    max_sym_len: u8,
+    /// Stores the value of the table if all positions have the same value
+    min_sym_len: u8,
+    block_size: usize,
+    span: usize,
+    /// Offset of the lowest symbol for each symbol length
+    lowest_sym: usize,
+    /// Offset of the pairs of symbols each symbol expands to
+    btree: usize,
+    block_lengths: usize,
+    block_lengths_size: usize,
+    sparse_index: usize,
+    sparse_index_size: usize,
+    num_blocks: usize,
+    /// Offset of the Huffman compressed data
+    data: usize,
+    base64: Vec<u64>,
+    /// Number of values (minus one) represented by each symbol
+    symlen: Vec<u8>,
+    /// The pieces in the order they are encoded
+    pieces: [u8; MAX_PIECES],
+    group_idx: [u64; MAX_PIECES + 1],
+    group_len: [usize; MAX_PIECES + 1],
+    /// Offsets into the value map of DTZ tables for wins, losses, cursed wins and blessed losses
+    map_idx: [usize; 4],
+}
+
+/// A memory mapped tablebase file together with the indexing information of all its tables
+struct TableFile {
+    data: Mmap,
+    sides: usize,
+    files: usize,
+    pairs: Vec<PairsData>,
+    /// Offset of the value map of DTZ tables
+    map: usize,
+}
+
+impl TableFile {
+    fn pairs(&self, stm: usize, file: usize) -> &PairsData {
+        &self.pairs[(stm % self.sides) * self.files + file.min(self.files - 1)]
+    }
+}
+
+pub enum DtzProbe {
+    Value(i32),
+    /// The table only stores positions where the other side is to move
+    ChangeSideToMove,
+}
+
+/// A single endgame like `KRvK`, with the corresponding WDL and DTZ files being loaded on first
+/// access
+pub struct Table {
+    pub key: u64,
+    pub key2: u64,
+    pub piece_count: usize,
+    has_pawns: bool,
+    has_unique_pieces: bool,
+    /// Number of pawns of the leading side and the other side
+    pawn_count: [usize; 2],
+    wdl_path: PathBuf,
+    dtz_path: Option<PathBuf>,
+    wdl: OnceLock<Option<TableFile>>,
+    dtz: OnceLock<Option<TableFile>>,
+}
+
+impl Table {
+    pub fn new(name: &str, wdl_path: PathBuf, dtz_path: Option<PathBuf>) -> Option<Table> {
+        let counts = parse_material(name)?;
+
+        let piece_count = counts.iter().flatten().map(|&c| c as usize).sum();
+        if piece_count > MAX_PIECES {
+            return None;
+        }
+
+        let pawns = [counts[0][5] as usize, counts[1][5] as usize];
+        let has_unique_pieces = counts.iter().any(|kinds| kinds[1..].contains(&1));
+
+        // The leading side is the one with less pawns, because this compresses better
+        let white_leads = pawns[1] == 0 || (pawns[0] > 0 && pawns[1] >= pawns[0]);
+        let pawn_count = if white_leads {
+            pawns
+        } else {
+            [pawns[1], pawns[0]]
+        };
+
+        Some(Table {
+            key: key_from_counts(&counts, false),
+            key2: key_from_counts(&counts, true),
+            piece_count,
+            has_pawns: pawns[0] + pawns[1] > 0,
+            has_unique_pieces,
+            pawn_count,
+            wdl_path,
+            dtz_path,
+            wdl: OnceLock::new(),
+            dtz: OnceLock::new(),
+        })
+    }
+
+    fn file(&self, kind: TableKind) -> Option<&TableFile> {
+        match kind {
+            TableKind::Wdl => self
+                .wdl
+                .get_or_init(|| self.load(&self.wdl_path, kind))
+                .as_ref(),
+            TableKind::Dtz => self
+                .dtz
+                .get_or_init(|| self.load(self.dtz_path.as_ref()?, kind))
+                .as_ref(),
+        }
+    }
+
+    fn load(&self, path: &PathBuf, kind: TableKind) -> Option<TableFile> {
+        let file = File::open(path).ok()?;
+        // Safety: tablebase files are never modified while the engine is running
+        let data = unsafe { Mmap::map(&file) }.ok()?;
+
+        let magic = if kind == TableKind::Wdl {
+            WDL_MAGIC
+        } else {
+            DTZ_MAGIC
+        };
+        if data.len() < 5 || data[0..4] != magic {
+            return None;
+        }
+
+        self.parse(data, kind)
+    }
+
+    fn parse(&self, data: Mmap, kind: TableKind) -> Option<TableFile> {
+        let sides = if kind == TableKind::Wdl && self.key != self.key2 {
+            2
+        } else {
+            1
+        };
+        let files = if self.has_pawns { 4 } else { 1 };
+
+        let mut pairs: Vec<PairsData> = (0..sides * files).map(|_| PairsData::default()).collect();
+
+        // The first byte stores flags, which we already know from the name of the file
+        let mut offset = 5;
+
+        let pawns_on_both_sides = self.has_pawns && self.pawn_count[1] > 0;
+        for file in 0..files {
+            let order_byte = data[offset];
+            let remaining_pawns_byte = if pawns_on_both_sides {
+                data[offset + 1]
+            } else {
+                0xFF
+            };
+            let order = [
+                [order_byte & 0xF, remaining_pawns_byte & 0xF],
+                [order_byte >> 4, remaining_pawns_byte >> 4],
+            ];
+            offset += 1 + pawns_on_both_sides as usize;
+
+            for k in 0..self.piece_count {
+                for (side, d) in pairs.iter_mut().skip(file).step_by(files).enumerate() {
+                    let pc = if side == 0 {
+                        data[offset] & 0xF
+                    } else {
+                        data[offset] >> 4
+                    };
+                    d.pieces[k] = piece_from_table(pc).0;
+                }
+                offset += 1;
+            }
+
+            for (side, d) in pairs.iter_mut().skip(file).step_by(files).enumerate() {
+                self.set_groups(d, order[side], file);
+            }
+        }
+
+        offset += offset & 1;
+
+        for file in 0..files {
+            for side in 0..sides {
+                offset = set_sizes(&mut pairs[side * files + file], &data, offset)?;
+            }
+        }
+
+        let mut map = 0;
+        if kind == TableKind::Dtz {
+            map = offset;
+            for d in pairs.iter_mut() {
+                if d.flags & FLAG_MAPPED == 0 {
+                    continue;
+                }
+
+                if d.flags & FLAG_WIDE != 0 {
+                    offset += offset & 1;
+                    for i in 0..4 {
+                        d.map_idx[i] = (offset - map) / 2 + 1;
+                        offset += 2 * read_u16_le(&data, offset) as usize + 2;
+                    }
+                } else {
+                    for i in 0..4 {
+                        d.map_idx[i] = offset - map + 1;
+                        offset += data[offset] as usize + 1;
+                    }
+                }
+            }
+            offset += offset & 1;
+        }
+
+        for file in 0..files {
+            for side in 0..sides {
+                let d = &mut pairs[side * files + file];
+                d.sparse_index = offset;
+                offset += d.sparse_index_size * 6;
+            }
+        }
+
+        for file in 0..files {
+            for side in 0..sides {
+                let d = &mut pairs[side * files + file];
+                d.block_lengths = offset;
+                offset += d.block_lengths_size * 2;
+            }
+        }
+
+        for file in 0..files {
+            for side in 0..sides {
+                let d = &mut pairs[side * files + file];
+                offset = (offset + 0x3F) & !0x3F;
+                d.data = offset;
+                offset += d.num_blocks * d.block_size;
+            }
+        }
+
+        if offset > data.len() {
+            return None;
+        }
+
+        Some(TableFile {
+            data,
+            sides,
+            files,
+            pairs,
+            map,
+        })
+    }
+
+    /// Groups together pieces that are encoded together. Usually a group consists of pieces of the
+    /// same kind and color, except for the leading group. Without pawns the leading group consists
+    /// of three unique pieces or, if there are none apart from the kings, of the two kings. With
+    /// pawns the leading group are the pawns of the leading side.
+    fn set_groups(&self, d: &mut PairsData, order: [u8; 2], file: usize) {
+        let indices = indices();
+
+        let mut n = 0;
+        let mut first_len: i32 = if self.has_pawns {
+            0
+        } else if self.has_unique_pieces {
+            3
+        } else {
+            2
+        };
+        d.group_len[n] = 1;
+
+        for i in 1..self.piece_count {
+            first_len -= 1;
+            if first_len > 0 || d.pieces[i] == d.pieces[i - 1] {
+                d.group_len[n] += 1;
+            } else {
+                n += 1;
+                d.group_len[n] = 1;
+            }
+        }
+        n += 1;
+        d.group_len[n] = 0;
+
+        // The groups are not necessarily encoded in the order they appear in. The position of the
+        // leading group is stored in `order[0]` and the position of the remaining pawns, if there
+        // are any, in `order[1]`.
+        let pawns_on_both_sides = self.has_pawns && self.pawn_count[1] > 0;
+        let mut next = if pawns_on_both_sides { 2 } else { 1 };
+        let mut free_squares = 64
+            - d.group_len[0]
+            - if pawns_on_both_sides {
+                d.group_len[1]
+            } else {
+                0
+            };
+        let mut idx: u64 = 1;
+
+        let mut k = 0;
+        while next < n || k == order[0] as usize || k == order[1] as usize {
+            if k == order[0] as usize {
+                d.group_idx[0] = idx;
+                idx *= if self.has_pawns {
+                    indices.lead_pawns_size[d.group_len[0]][file]
+                } else if self.has_unique_pieces {
+                    31332
+                } else {
+                    462
+                };
+            } else if k == order[1] as usize {
+                d.group_idx[1] = idx;
+                idx *= indices.binomial[d.group_len[1]][48 - d.group_len[0]];
+            } else {
+                d.group_idx[next] = idx;
+                idx *= indices.binomial[d.group_len[next]][free_squares];
+                free_squares -= d.group_len[next];
+                next += 1;
+            }
+            k += 1;
+        }
+
+        d.group_idx[n] = idx;
+    }
+
+    pub fn probe_wdl(&self, position: &Position) -> Option<Wdl> {
+        let file = self.file(TableKind::Wdl)?;
+        let (value, _) = self.probe(file, TableKind::Wdl, position)?;
+        Some(Wdl::from_value(value as i32 - 2))
+    }
+
+    pub fn probe_dtz(&self, position: &Position, wdl: Wdl) -> Option<DtzProbe> {
+        let file = self.file(TableKind::Dtz)?;
+        Some(match self.probe(file, TableKind::Dtz, position) {
+            Some((value, tb_file)) => DtzProbe::Value(map_dtz_score(file, tb_file, value, wdl)),
+            None => DtzProbe::ChangeSideToMove,
+        })
+    }
+
+    /// Returns the decompressed value of the position, together with the file of the leading
+    /// pawn. DTZ tables only store positions for one side to move, so this returns `None` if the
+    /// position can't be found in the table.
+    fn probe(
+        &self,
+        file: &TableFile,
+        kind: TableKind,
+        position: &Position,
+    ) -> Option<(u16, usize)> {
+        let (idx, stm, tb_file) = self.index(|stm, f| file.pairs(stm, f), kind, position)?;
+        Some((
+            decompress_pairs(&file.data, file.pairs(stm, tb_file), idx),
+            tb_file,
+        ))
+    }
+
+    /// Computes the index of the position within the table, together with the side to move and
+    /// the file of the leading pawn that select the table within the file
+    fn index<'a>(
+        &self,
+        pairs: impl Fn(usize, usize) -> &'a PairsData,
+        kind: TableKind,
+        position: &Position,
+    ) -> Option<(u64, usize, usize)> {
+        let indices = indices();
+        let side_to_move = position.state().side_to_move;
+
+        // Tables are stored with white being the stronger side, and for symmetric endgames only
+        // with white to move. For all other positions we swap the colors and flip the board.
+        let flip = (self.key == self.key2 && side_to_move != WHITE)
+            || material_key(position, false) != self.key;
+        let flip_color = flip as u8;
+        let flip_squares = if flip { 56 } else { 0 };
+        let stm = flip as usize ^ side_to_move.0 as usize;
+
+        let mut squares = [0usize; MAX_PIECES];
+        let mut pieces = [Piece(0); MAX_PIECES];
+        let mut size = 0;
+        let mut lead_pawns_count = 0;
+        let mut lead_pawns = crate::board::EMPTY;
+        let mut tb_file = 0;
+
+        // The leading pawn is the one closest to the edge with the lowest rank
+        if self.has_pawns {
+            let pc = Piece(pairs(0, 0).pieces[0] ^ flip_color);
+            lead_pawns = position.piece(pc);
+            for (sq, _) in lead_pawns.iter() {
+                squares[size] = sq.0 as usize ^ flip_squares;
+                size += 1;
+            }
+            lead_pawns_count = size;
+
+            let lead = (0..lead_pawns_count)
+                .max_by_key(|&i| indices.map_pawns[squares[i]])
+                .unwrap_or(0);
+            squares.swap(0, lead);
+
+            tb_file = squares[0] & 7;
+            tb_file = tb_file.min(7 - tb_file);
+        }
+
+        if kind == TableKind::Dtz
+            && (pairs(stm, tb_file).flags & FLAG_STM) as usize != stm
+            && (self.key != self.key2 || self.has_pawns)
+        {
+            return None;
+        }
+
+        for (sq, _) in (position.occupied() ^ lead_pawns).iter() {
+            squares[size] = sq.0 as usize ^ flip_squares;
+            pieces[size] = Piece(position.at(sq).0 ^ flip_color);
+            size += 1;
+        }
+
+        let d = pairs(stm, tb_file);
+
+        // Order the pieces in the same way as they are stored in the table
+        for i in lead_pawns_count..size - 1 {
+            for j in i + 1..size {
+                if d.pieces[i] == pieces[j].0 {
+                    pieces.swap(i, j);
+                    squares.swap(i, j);
+                    break;
+                }
+            }
+        }
+
+        // Mirror the board so that the leading piece is on the files a to d
+        if squares[0] & 7 > 3 {
+            for sq in squares[..size].iter_mut() {
+                *sq ^= 7;
+            }
+        }
+
+        let mut idx;
+        if self.has_pawns {
+            idx = indices.lead_pawn_idx[lead_pawns_count][squares[0]];
+
+            squares[1..lead_pawns_count].sort_by_key(|&sq| indices.map_pawns[sq]);
+            for (i, &sq) in squares.iter().enumerate().take(lead_pawns_count).skip(1) {
+                idx += indices.binomial[i][indices.map_pawns[sq]];
+            }
+        } else {
+            // Mirror the board so that the leading piece is on the ranks 1 to 4
+            if squares[0] >> 3 > 3 {
+                for sq in squares[..size].iter_mut() {
+                    *sq ^= 56;
+                }
+            }
+
+            // Mirror along the a1-h8 diagonal so that the first piece of the leading group that
+            // is not on the diagonal ends up below it
+            for i in 0..d.group_len[0] {
+                let off = off_a1h8(squares[i]);
+                if off == 0 {
+                    continue;
+                }
+                if off > 0 {
+                    for sq in squares[i..size].iter_mut() {
+                        *sq = ((*sq >> 3) | (*sq << 3)) & 63;
+                    }
+                }
+                break;
+            }
+
+            idx = if self.has_unique_pieces {
+                let adjust1 = (squares[1] > squares[0]) as usize;
+                let adjust2 =
+                    (squares[2] > squares[0]) as usize + (squares[2] > squares[1]) as usize;
+
+                if off_a1h8(squares[0]) != 0 {
+                    ((indices.map_a1d1d4[squares[0]] * 63 + (squares[1] - adjust1)) * 62
+                        + squares[2]
+                        - adjust2) as u64
+                } else if off_a1h8(squares[1]) != 0 {
+                    ((6 * 63 + (squares[0] >> 3) * 28 + indices.map_b1h1h7[squares[1]]) * 62
+                        + squares[2]
+                        - adjust2) as u64
+                } else if off_a1h8(squares[2]) != 0 {
+                    (6 * 63 * 62
+                        + 4 * 28 * 62
+                        + (squares[0] >> 3) * 7 * 28
+                        + ((squares[1] >> 3) - adjust1) * 28
+                        + indices.map_b1h1h7[squares[2]]) as u64
+                } else {
+                    (6 * 63 * 62
+                        + 4 * 28 * 62
+                        + 4 * 7 * 28
+                        + (squares[0] >> 3) * 7 * 6
+                        + ((squares[1] >> 3) - adjust1) * 6
+                        + ((squares[2] >> 3) - adjust2)) as u64
+                }
+            } else {
+                indices.map_kk[indices.map_a1d1d4[squares[0]]][squares[1]] as u64
+            };
+        }
+
+        // Encode the remaining groups, with the squares of each group in ascending order and
+        // mapped down for every square of a previous group that comes before it
+        idx *= d.group_idx[0];
+        let mut group_start = d.group_len[0];
+        let mut remaining_pawns = self.has_pawns && self.pawn_count[1] > 0;
+        let mut next = 1;
+        while d.group_len[next] != 0 {
+            let group_end = group_start + d.group_len[next];
+            squares[group_start..group_end].sort();
+
+            let mut n = 0;
+            for i in 0..d.group_len[next] {
+                let sq = squares[group_start + i];
+                let adjust = squares[..group_start].iter().filter(|&&s| sq > s).count();
+                n += indices.binomial[i + 1][sq - adjust - 8 * remaining_pawns as usize];
+            }
+
+            remaining_pawns = false;
+            idx += n * d.group_idx[next];
+            group_start = group_end;
+            next += 1;
+        }
+
+        Some((idx, stm, tb_file))
+    }
+}
+
+/// Converts the piece encoding used in tablebase files into our own one
+fn piece_from_table(pc: u8) -> Piece {
+    let kind = 6 - (pc & 7).clamp(1, 6);
+    Piece((kind << 1) | (pc >> 3))
+}
+
+fn read_u16_le(data: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([data[offset], data[offset + 1]])
+}
+
+fn read_u32_le(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+fn read_u32_be(data: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+fn read_u64_be(data: &[u8], offset: usize) -> u64 {
+    u64::from_be_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+/// Returns the left and right symbol a symbol expands to, stored as two 12 bit numbers
+fn btree_symbols(data: &[u8], btree: usize, sym: usize) -> (usize, usize) {
+    let lr = &data[btree + 3 * sym..btree + 3 * sym + 3];
+    let left = (((lr[1] & 0xF) as usize) << 8) | lr[0] as usize;
+    let right = ((lr[2] as usize) << 4) | (lr[1] >> 4) as usize;
+    (left, right)
+}
+
+/// Reads the sizes and the Huffman code of a table and returns the offset after it
+fn set_sizes(d: &mut PairsData, data: &[u8], mut offset: usize) -> Option<usize> {
+    d.flags = data[offset];
+    offset += 1;
+
+    if d.flags & FLAG_SINGLE_VALUE != 0 {
+        d.min_sym_len = data[offset];
+        return Some(offset + 1);
+    }
+
+    // The last index of the groups is the number of positions in the table
+    let table_size = d.group_idx[d.group_len.iter().position(|&len| len == 0)?];
+
+    d.block_size = 1 << data[offset];
+    d.span = 1 << data[offset + 1];
+    d.sparse_index_size = table_size.div_ceil(d.span as u64) as usize;
+    let padding = data[offset + 2] as usize;
+    d.num_blocks = read_u32_le(data, offset + 3) as usize;
+    d.block_lengths_size = d.num_blocks + padding;
+    d.max_sym_len = data[offset + 7];
+    d.min_sym_len = data[offset + 8];
+    d.lowest_sym = offset + 9;
+    offset += 9;
+
+    if d.max_sym_len < d.min_sym_len {
+        return None;
+    }
+
+    // The canonical Huffman code is ordered such that longer symbols have lower values. Compute
+    // the lowest symbol of each length padded to 64 bits, so that the length of a symbol can be
+    // found by comparing it to these values.
+    let lengths = (d.max_sym_len - d.min_sym_len + 1) as usize;
+    d.base64 = vec![0; lengths];
+    for i in (0..lengths - 1).rev() {
+        let lowest = read_u16_le(data, d.lowest_sym + 2 * i) as u64;
+        let next_lowest = read_u16_le(data, d.lowest_sym + 2 * (i + 1)) as u64;
+        d.base64[i] = d.base64[i + 1]
+            .wrapping_add(lowest)
+            .wrapping_sub(next_lowest)
+            / 2;
+    }
+    for (i, base) in d.base64.iter_mut().enumerate() {
+        *base = base
+            .checked_shl(64 - i as u32 - d.min_sym_len as u32)
+            .unwrap_or(0);
+    }
+    offset += lengths * 2;
+
+    let symbols = read_u16_le(data, offset) as usize;
+    offset += 2;
+    d.btree = offset;
+
+    // Each symbol represents a pair of symbols ("recursive pairing"), expand them to find out how
+    // many values each symbol represents
+    d.symlen = vec![0; symbols];
+    let mut visited = vec![false; symbols];
+    for sym in 0..symbols {
+        if !visited[sym] {
+            d.symlen[sym] = set_symlen(data, d.btree, &mut d.symlen, &mut visited, sym);
+        }
+    }
+
+    Some(offset + symbols * 3 + (symbols & 1))
+}
+
+fn set_symlen(
+    data: &[u8],
+    btree: usize,
+    symlen: &mut [u8],
+    visited: &mut [bool],
+    sym: usize,
+) -> u8 {
+    visited[sym] = true;
+
+    let (left, right) = btree_symbols(data, btree, sym);
+    if right == 0xFFF {
+        return 0;
+    }
+
+    if !visited[left] {
+        symlen[left] = set_symlen(data, btree, symlen, visited, left);
+    }
+    if !visited[right] {
+        symlen[right] = set_symlen(data, btree, symlen, visited, right);
+    }
+
+    symlen[left].wrapping_add(symlen[right]).wrapping_add(1)
+}
+
+/// Finds the value stored at the given index of a table
+fn decompress_pairs(data: &[u8], d: &PairsData, idx: u64) -> u16 {
+    if d.flags & FLAG_SINGLE_VALUE != 0 {
+        return d.min_sym_len as u16;
+    }
+
+    // The sparse index stores the block and the offset within the block of every `span`th
+    // value, starting at the middle of the first span
+    let k = (idx / d.span as u64) as usize;
+    let entry = d.sparse_index + 6 * k;
+    let mut block = read_u32_le(data, entry) as usize;
+    let mut offset = read_u16_le(data, entry + 4) as i64;
+
+    offset += (idx % d.span as u64) as i64 - (d.span / 2) as i64;
+
+    // Each block stores the number of values in it minus one
+    let block_length = |block: usize| read_u16_le(data, d.block_lengths + 2 * block) as i64;
+    while offset < 0 {
+        block -= 1;
+        offset += block_length(block) + 1;
+    }
+    while offset > block_length(block) {
+        offset -= block_length(block) + 1;
+        block += 1;
+    }
+
+    // Read the symbols of the block until we reach the one containing our value
+    let mut ptr = d.data + block * d.block_size;
+    let mut buf64 = read_u64_be(data, ptr);
+    ptr += 8;
+    let mut buf64_size = 64;
+
+    let mut sym;
+    loop {
+        let mut len = 0;
+        while buf64 < d.base64[len] {
+            len += 1;
+        }
+
+        sym = ((buf64 - d.base64[len]) >> (64 - len - d.min_sym_len as usize)) as usize;
+        sym += read_u16_le(data, d.lowest_sym + 2 * len) as usize;
+
+        if offset < d.symlen[sym] as i64 + 1 {
+            break;
+        }
+
+        offset -= d.symlen[sym] as i64 + 1;
+        len += d.min_sym_len as usize;
+        buf64 <<= len;
+        buf64_size -= len;
+
+        if buf64_size <= 32 {
+            buf64_size += 32;
+            buf64 |= (read_u32_be(data, ptr) as u64) << (64 - buf64_size);
+            ptr += 4;
+        }
+    }
+
+    // Expand the symbol until we reach the leaf containing our value
+    while d.symlen[sym] != 0 {
+        let (left, right) = btree_symbols(data, d.btree, sym);
+        if offset < d.symlen[left] as i64 + 1 {
+            sym = left;
+        } else {
+            offset -= d.symlen[left] as i64 + 1;
+            sym = right;
+        }
+    }
+
+    btree_symbols(data, d.btree, sym).0 as u16
+}
+
+/// Converts the stored value of a DTZ table into plies
+fn map_dtz_score(file: &TableFile, tb_file: usize, value: u16, wdl: Wdl) -> i32 {
+    let d = file.pairs(0, tb_file);
+    let mut value = value as i32;
+
+    if d.flags & FLAG_MAPPED != 0 {
+        let idx = d.map_idx[match wdl {
+            Wdl::Win | Wdl::Draw => 0,
+            Wdl::Loss => 1,
+            Wdl::CursedWin => 2,
+            Wdl::BlessedLoss => 3,
+        }];
+        value = if d.flags & FLAG_WIDE != 0 {
+            read_u16_le(&file.data, file.map + 2 * (idx + value as usize)) as i32
+        } else {
+            file.data[file.map + idx + value as usize] as i32
+        };
+    }
+
+    // Values are either stored in moves or in plies
+    if (wdl == Wdl::Win && d.flags & FLAG_WIN_PLIES == 0)
+        || (wdl == Wdl::Loss && d.flags & FLAG_LOSS_PLIES == 0)
+        || wdl == Wdl::CursedWin
+        || wdl == Wdl::BlessedLoss
+    {
+        value *= 2;
+    }
+
+    value + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        piece::{BLACK_KING, BLACK_PAWN, WHITE_KING, WHITE_ROOK},
+        syzygy::table::{material_key, piece_from_table, Table},
+        Position,
+    };
+    use std::path::PathBuf;
+
+    #[test]
+    fn material_keys() {
+        let table = Table::new("KRvKP", PathBuf::new(), None).unwrap();
+        assert_eq!(table.piece_count, 4);
+
+        let position = Position::from_fen("8/8/8/3k4/8/4p3/8/R3K3 w - - 0 1");
+        assert_eq!(material_key(&position, false), table.key);
+        assert_eq!(material_key(&position, true), table.key2);
+
+        let position = Position::from_fen("8/8/8/3k4/8/4P3/8/r3K3 w - - 0 1");
+        assert_eq!(material_key(&position, false), table.key2);
+
+        assert!(Table::new("KRvR", PathBuf::new(), None).is_none());
+        assert!(Table::new("KRRRRvKPP", PathBuf::new(), None).is_none());
+    }
+
+    #[test]
+    fn table_pieces() {
+        assert_eq!(piece_from_table(6), WHITE_KING);
+        assert_eq!(piece_from_table(14), BLACK_KING);
+        assert_eq!(piece_from_table(4), WHITE_ROOK);
+        assert_eq!(piece_from_table(9), BLACK_PAWN);
+    }
+}
@@ -0,0 +1,481 @@
+//! Writes tablebase files in the Syzygy format, which is used to create the test tables. The
+//! values are compressed the same way as in the official files (recursive pairing followed by a
+//! canonical Huffman code), but without the tricks used to make them as small as possible.
+
+use super::{
+    decompress_pairs, parse_material, PairsData, Table, TableKind, DTZ_MAGIC, FLAG_LOSS_PLIES,
+    FLAG_SINGLE_VALUE, FLAG_WIN_PLIES, MAX_PIECES, WDL_MAGIC,
+};
+use crate::{piece::Piece, Position};
+use std::{collections::BinaryHeap, fs, path::Path};
+
+const BLOCK_SIZE_LOG2: u8 = 6;
+const SPAN_LOG2: u8 = 10;
+/// Maximum number of values in a block, such that the offsets of the sparse index fit into 16 bits
+const MAX_BLOCK_VALUES: usize = 60_000;
+/// Symbols are stored as 12 bit numbers, with `0xFFF` marking the leaves of the tree
+const MAX_SYMBOLS: usize = 4000;
+/// The decoder refills its buffer whenever there are 32 bits or less left in it
+const MAX_CODE_LEN: usize = 32;
+
+/// Writes the WDL or DTZ file of the endgame `name` to `path`. The values are stored as the
+/// decoder expects them: WDL values as `wdl + 2`, DTZ values in plies minus one, only for white
+/// to move unless the endgame is symmetric. Positions without a value are "don't care" positions
+/// and can take any value. Positions sharing an index have to have the same value.
+pub fn write_table(
+    path: &Path,
+    name: &str,
+    kind: TableKind,
+    positions: impl IntoIterator<Item = (Position, Option<u16>)>,
+) -> Result<(), String> {
+    let table = Table::new(name, path.to_path_buf(), Some(path.to_path_buf()))
+        .ok_or(format!("Invalid endgame {name}"))?;
+    let sides = if kind == TableKind::Wdl && table.key != table.key2 {
+        2
+    } else {
+        1
+    };
+    let files = if table.has_pawns { 4 } else { 1 };
+    let pieces = piece_order(name);
+    let pawns_on_both_sides = table.has_pawns && table.pawn_count[1] > 0;
+    let order = [0, if pawns_on_both_sides { 1 } else { 0xF }];
+
+    let mut pairs: Vec<PairsData> = (0..sides * files).map(|_| PairsData::default()).collect();
+    for (i, d) in pairs.iter_mut().enumerate() {
+        for (k, pc) in pieces.iter().enumerate() {
+            d.pieces[k] = pc.0;
+        }
+        table.set_groups(d, order, i % files);
+        if kind == TableKind::Dtz {
+            d.flags = FLAG_WIN_PLIES | FLAG_LOSS_PLIES;
+        }
+    }
+
+    let table_size =
+        |d: &PairsData| d.group_idx[d.group_len.iter().position(|&len| len == 0).unwrap()];
+    let mut values: Vec<Vec<Option<u16>>> = pairs
+        .iter()
+        .map(|d| vec![None; table_size(d) as usize])
+        .collect();
+
+    for (position, value) in positions {
+        let Some(value) = value else { continue };
+        let Some((idx, stm, tb_file)) =
+            table.index(|stm, f| &pairs[(stm % sides) * files + f], kind, &position)
+        else {
+            continue;
+        };
+
+        let slot = &mut values[(stm % sides) * files + tb_file][idx as usize];
+        match *slot {
+            Some(stored) if stored != value => {
+                return Err(format!(
+                    "Conflicting values {stored} and {value} at index {idx} of {name}"
+                ))
+            }
+            _ => *slot = Some(value),
+        }
+    }
+
+    let compressed: Vec<Compressed> = values.iter().map(|values| compress(values)).collect();
+
+    // The header lists the pieces in the order they are encoded for every file of the leading
+    // pawn, with the pieces for white to move in the low and black to move in the high nibbles
+    let mut data = if kind == TableKind::Wdl {
+        WDL_MAGIC.to_vec()
+    } else {
+        DTZ_MAGIC.to_vec()
+    };
+    data.push((table.key != table.key2) as u8 | (table.has_pawns as u8) << 1);
+    for _ in 0..files {
+        data.push(order[0] | order[0] << 4);
+        if pawns_on_both_sides {
+            data.push(order[1] | order[1] << 4);
+        }
+        for pc in pieces.iter() {
+            let code = table_piece(*pc);
+            data.push(code | code << 4);
+        }
+    }
+    data.resize(data.len() + (data.len() & 1), 0);
+
+    for file in 0..files {
+        for side in 0..sides {
+            let flags = pairs[side * files + file].flags;
+            compressed[side * files + file].write_sizes(&mut data, flags);
+        }
+    }
+    if kind == TableKind::Dtz {
+        data.resize(data.len() + (data.len() & 1), 0);
+    }
+
+    for c in sides_by_file(&compressed, sides, files) {
+        for &(block, offset) in c.sparse_index.iter() {
+            data.extend(block.to_le_bytes());
+            data.extend(offset.to_le_bytes());
+        }
+    }
+    for c in sides_by_file(&compressed, sides, files) {
+        for length in c.block_lengths.iter() {
+            data.extend(length.to_le_bytes());
+        }
+    }
+    for c in sides_by_file(&compressed, sides, files) {
+        data.resize((data.len() + 0x3F) & !0x3F, 0);
+        data.extend(c.data.iter());
+    }
+
+    // The decoder reads a few bytes past the end of a block
+    data.resize(data.len() + 64, 0);
+    fs::write(path, data).map_err(|err| format!("Cannot write {}: {err}", path.display()))?;
+
+    // Read the file back to make sure every value can be decoded again
+    let file = table
+        .file(kind)
+        .ok_or(format!("Cannot read back {}", path.display()))?;
+    for (i, values) in values.iter().enumerate() {
+        let d = file.pairs(i / files, i % files);
+        for (idx, &value) in values.iter().enumerate() {
+            if let Some(value) = value {
+                if decompress_pairs(&file.data, d, idx as u64) != value {
+                    return Err(format!("Cannot decode index {idx} of {name}"));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn sides_by_file(compressed: &[Compressed], sides: usize, files: usize) -> Vec<&Compressed> {
+    (0..files)
+        .flat_map(|file| (0..sides).map(move |side| side * files + file))
+        .map(|i| &compressed[i])
+        .collect()
+}
+
+/// Orders the pieces of the endgame the way they are encoded. With pawns the pawns of the leading
+/// side come first, followed by the pawns of the other side. Without pawns the first three pieces
+/// form the leading group, so endgames with only two unique pieces besides the kings aren't
+/// supported.
+fn piece_order(name: &str) -> Vec<Piece> {
+    let counts = parse_material(name).unwrap();
+    let pawns = [counts[0][5], counts[1][5]];
+    let white_leads = pawns[1] == 0 || (pawns[0] > 0 && pawns[1] >= pawns[0]);
+    let lead = if white_leads { 0 } else { 1 };
+
+    let mut pieces = vec![];
+    for side in [lead, 1 - lead] {
+        pieces.extend((0..counts[side][5]).map(|_| Piece(10 | side as u8)));
+    }
+    for (side, kinds) in counts.iter().enumerate() {
+        for (kind, &count) in kinds.iter().enumerate().take(5) {
+            pieces.extend((0..count).map(|_| Piece((kind << 1) as u8 | side as u8)));
+        }
+    }
+    assert!(pieces.len() <= MAX_PIECES);
+    pieces
+}
+
+/// Converts our piece encoding into the one used in tablebase files
+fn table_piece(pc: Piece) -> u8 {
+    (6 - (pc.0 >> 1)) | (pc.0 & 1) << 3
+}
+
+/// A single table compressed into blocks of Huffman codes
+#[derive(Default)]
+struct Compressed {
+    /// Set if all values of the table are the same
+    single_value: Option<u16>,
+    min_sym_len: u8,
+    max_sym_len: u8,
+    lowest_sym: Vec<u16>,
+    /// The pair of symbols each symbol expands to, with leaves storing the value and `0xFFF`
+    btree: Vec<(u16, u16)>,
+    sparse_index: Vec<(u32, u16)>,
+    block_lengths: Vec<u16>,
+    data: Vec<u8>,
+}
+
+impl Compressed {
+    fn write_sizes(&self, data: &mut Vec<u8>, flags: u8) {
+        if let Some(value) = self.single_value {
+            data.extend([flags | FLAG_SINGLE_VALUE, value as u8]);
+            return;
+        }
+
+        data.extend([flags, BLOCK_SIZE_LOG2, SPAN_LOG2, 0]);
+        data.extend((self.block_lengths.len() as u32).to_le_bytes());
+        data.extend([self.max_sym_len, self.min_sym_len]);
+        for lowest in self.lowest_sym.iter() {
+            data.extend(lowest.to_le_bytes());
+        }
+        data.extend((self.btree.len() as u16).to_le_bytes());
+        for &(left, right) in self.btree.iter() {
+            data.extend([
+                left as u8,
+                (left >> 8) as u8 | (right << 4) as u8,
+                (right >> 4) as u8,
+            ]);
+        }
+        data.resize(data.len() + (self.btree.len() & 1), 0);
+    }
+}
+
+fn compress(values: &[Option<u16>]) -> Compressed {
+    // "Don't care" positions repeat the previous value, which helps the pairing
+    let mut last = values.iter().flatten().next().copied().unwrap_or(0);
+    let values: Vec<u16> = values
+        .iter()
+        .map(|value| {
+            last = value.unwrap_or(last);
+            last
+        })
+        .collect();
+
+    let mut leaves = values.clone();
+    leaves.sort();
+    leaves.dedup();
+    if leaves.len() <= 1 {
+        return Compressed {
+            single_value: Some(leaves.first().copied().unwrap_or(0)),
+            ..Compressed::default()
+        };
+    }
+
+    // Recursive pairing: repeatedly replace the most frequent pairs of adjacent symbols by new
+    // symbols, each expanding to at most 256 values
+    let mut btree: Vec<(u16, u16)> = leaves.iter().map(|&value| (value, 0xFFF)).collect();
+    let mut lengths = vec![1; leaves.len()];
+    let mut symbols: Vec<u16> = values
+        .iter()
+        .map(|value| leaves.binary_search(value).unwrap() as u16)
+        .collect();
+
+    let mut pair_counts = vec![0u32; 1 << 24];
+    let mut touched = vec![];
+    while btree.len() < MAX_SYMBOLS {
+        for w in symbols.windows(2) {
+            let (left, right) = (w[0] as usize, w[1] as usize);
+            if lengths[left] + lengths[right] > 256 {
+                continue;
+            }
+            let key = left << 12 | right;
+            if pair_counts[key] == 0 {
+                touched.push(key);
+            }
+            pair_counts[key] += 1;
+        }
+
+        let mut candidates: Vec<(u32, usize)> = touched
+            .drain(..)
+            .map(|key| (std::mem::take(&mut pair_counts[key]), key))
+            .filter(|&(count, _)| count >= 8)
+            .collect();
+        candidates.sort_by(|a, b| b.cmp(a));
+
+        // Only replace pairs without symbols in common within one pass
+        let mut replacements = vec![None; btree.len()];
+        let mut used = vec![false; btree.len()];
+        for (_, key) in candidates.into_iter().take(64) {
+            let (left, right) = (key >> 12, key & 0xFFF);
+            if used[left] || used[right] || btree.len() >= MAX_SYMBOLS {
+                continue;
+            }
+            used[left] = true;
+            used[right] = true;
+            replacements[left] = Some((right as u16, btree.len() as u16));
+            btree.push((left as u16, right as u16));
+            lengths.push(lengths[left] + lengths[right]);
+        }
+        if !used.contains(&true) {
+            break;
+        }
+
+        let mut paired = Vec::with_capacity(symbols.len());
+        let mut i = 0;
+        while i < symbols.len() {
+            match replacements[symbols[i] as usize] {
+                Some((right, sym)) if symbols.get(i + 1) == Some(&right) => {
+                    paired.push(sym);
+                    i += 2;
+                }
+                _ => {
+                    paired.push(symbols[i]);
+                    i += 1;
+                }
+            }
+        }
+        symbols = paired;
+    }
+
+    let mut frequencies = vec![0u64; btree.len()];
+    for &sym in symbols.iter() {
+        frequencies[sym as usize] += 1;
+    }
+    let code_lens = huffman_lengths(&frequencies);
+
+    // Number the symbols such that longer codes get lower numbers, followed by the symbols that
+    // don't appear in the data and only are needed to expand other symbols
+    let min_sym_len = *code_lens.iter().filter(|&&len| len > 0).min().unwrap();
+    let max_sym_len = *code_lens.iter().max().unwrap();
+    let mut order: Vec<usize> = (0..btree.len()).collect();
+    order.sort_by_key(|&sym| (code_lens[sym] == 0, std::cmp::Reverse(code_lens[sym])));
+    let mut numbers = vec![0u16; btree.len()];
+    for (number, &sym) in order.iter().enumerate() {
+        numbers[sym] = number as u16;
+    }
+
+    let count_of = |len: usize| code_lens.iter().filter(|&&l| l == len).count() as u64;
+    let lens = max_sym_len - min_sym_len + 1;
+    let mut lowest_sym = vec![0u16; lens];
+    let mut base = vec![0u64; lens];
+    for i in (0..lens - 1).rev() {
+        let count = count_of(min_sym_len + i + 1);
+        lowest_sym[i] = lowest_sym[i + 1] + count as u16;
+        base[i] = (base[i + 1] + count) / 2;
+    }
+    let mut codes = vec![(0u64, 0usize); btree.len()];
+    for &sym in order.iter().filter(|&&sym| code_lens[sym] > 0) {
+        let i = code_lens[sym] - min_sym_len;
+        let rank = (numbers[sym] - lowest_sym[i]) as u64;
+        codes[sym] = (base[i] + rank, code_lens[sym]);
+    }
+
+    // Pack the codes into blocks, every block starting with a new symbol
+    let block_size = 1 << BLOCK_SIZE_LOG2;
+    let mut data = vec![];
+    let mut block_lengths = vec![];
+    let mut block_starts = vec![];
+    let mut bits = BitWriter::default();
+    let mut block_values = 0;
+    let mut start = 0;
+    for &sym in symbols.iter() {
+        let (code, len) = codes[sym as usize];
+        let sym_values = lengths[sym as usize];
+        if bits.len + len > block_size * 8 || block_values + sym_values > MAX_BLOCK_VALUES {
+            data.extend(bits.finish(block_size));
+            block_lengths.push((block_values - 1) as u16);
+            block_starts.push(start);
+            start += block_values;
+            block_values = 0;
+        }
+        bits.push(code, len);
+        block_values += sym_values;
+    }
+    data.extend(bits.finish(block_size));
+    block_lengths.push((block_values - 1) as u16);
+    block_starts.push(start);
+
+    // Every entry of the sparse index points to the middle of a span of values
+    let span = 1 << SPAN_LOG2;
+    let sparse_index = (0..values.len().div_ceil(span))
+        .map(|k| {
+            let target = k * span + span / 2;
+            let block = block_starts.partition_point(|&start| start <= target) - 1;
+            let offset = target - block_starts[block];
+            (block as u32, u16::try_from(offset).unwrap())
+        })
+        .collect();
+
+    let btree = btree_in_order(&btree, &order, &numbers);
+    Compressed {
+        single_value: None,
+        min_sym_len: min_sym_len as u8,
+        max_sym_len: max_sym_len as u8,
+        lowest_sym,
+        btree,
+        sparse_index,
+        block_lengths,
+        data,
+    }
+}
+
+/// Renumbers the symbols of the tree, keeping the values stored in the leaves
+fn btree_in_order(btree: &[(u16, u16)], order: &[usize], numbers: &[u16]) -> Vec<(u16, u16)> {
+    order
+        .iter()
+        .map(|&sym| match btree[sym] {
+            (value, 0xFFF) => (value, 0xFFF),
+            (left, right) => (numbers[left as usize], numbers[right as usize]),
+        })
+        .collect()
+}
+
+/// Computes the lengths of a Huffman code for the given frequencies, flattening the frequencies
+/// until no code is longer than the decoder supports. Unused symbols get a length of zero.
+fn huffman_lengths(frequencies: &[u64]) -> Vec<usize> {
+    let mut frequencies = frequencies.to_vec();
+    loop {
+        let used: Vec<usize> = (0..frequencies.len())
+            .filter(|&sym| frequencies[sym] > 0)
+            .collect();
+        let mut lengths = vec![0; frequencies.len()];
+        if used.len() == 1 {
+            lengths[used[0]] = 1;
+            return lengths;
+        }
+
+        let mut parents = vec![usize::MAX; used.len()];
+        let mut heap: BinaryHeap<_> = used
+            .iter()
+            .enumerate()
+            .map(|(node, &sym)| std::cmp::Reverse((frequencies[sym], node)))
+            .collect();
+        while heap.len() > 1 {
+            let std::cmp::Reverse((a, left)) = heap.pop().unwrap();
+            let std::cmp::Reverse((b, right)) = heap.pop().unwrap();
+            let node = parents.len();
+            parents.push(usize::MAX);
+            parents[left] = node;
+            parents[right] = node;
+            heap.push(std::cmp::Reverse((a + b, node)));
+        }
+
+        for (node, &sym) in used.iter().enumerate() {
+            let mut parent = parents[node];
+            while parent != usize::MAX {
+                lengths[sym] += 1;
+                parent = parents[parent];
+            }
+        }
+
+        if lengths.iter().all(|&len| len <= MAX_CODE_LEN) {
+            return lengths;
+        }
+        for frequency in frequencies.iter_mut().filter(|f| **f > 0) {
+            *frequency = frequency.div_ceil(2);
+        }
+    }
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u64,
+    len: usize,
+}
+
+impl BitWriter {
+    /// Appends the lowest `len` bits of `code`, most significant bit first
+    fn push(&mut self, code: u64, len: usize) {
+        for i in (0..len).rev() {
+            self.buffer = self.buffer << 1 | (code >> i) & 1;
+            self.len += 1;
+            if self.len.is_multiple_of(8) {
+                self.bytes.push(self.buffer as u8);
+            }
+        }
+    }
+
+    /// Returns the bits written so far padded with zeros to `size` bytes and starts a new block
+    fn finish(&mut self, size: usize) -> Vec<u8> {
+        if !self.len.is_multiple_of(8) {
+            self.bytes.push((self.buffer << (8 - self.len % 8)) as u8);
+        }
+        let mut bytes = std::mem::take(&mut self.bytes);
+        bytes.resize(size, 0);
+        self.buffer = 0;
+        self.len = 0;
+        bytes
+    }
+}
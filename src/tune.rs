@@ -0,0 +1,839 @@
+use crate::{
+    datagen::Record,
+    piece::KING,
+    position::{
+        psqt::{Score, MAX_PHASE, PHASE_WEIGHTS},
+        EvalParams,
+    },
+    side::WHITE,
+    Position,
+};
+use serde_json::Value;
+use std::{
+    fmt::Write,
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+    sync::Arc,
+    thread,
+};
+
+/// Number of entries of the piece-square tables, one for each piece kind on each square. Each
+/// entry has a middlegame and an endgame parameter.
+const PARAMS: usize = 6 * 64;
+
+/// Weights that are tuned as part of the combined piece-square tables
+const TABLE_WEIGHTS: [&str; 3] = ["piece_values", "mg_tables", "eg_tables"];
+
+/// Weights the evaluation divides by, which have to stay positive
+const DIVISORS: [&str; 1] = ["/king_danger_divisor"];
+
+/// A source file with the visibility of its constants, and the name and type of each weight
+type Constants = (
+    &'static str,
+    &'static str,
+    &'static [(&'static str, &'static str)],
+);
+
+/// The constants the other weights are compiled in from, by file, with their visibility and type.
+/// The names of the constants are the names of the weights in upper case.
+const CONSTANTS: [Constants; 3] = [
+    (
+        "src/position/pawns.rs",
+        "pub(super) ",
+        &[
+            ("doubled", "Score"),
+            ("isolated", "Score"),
+            ("backward", "Score"),
+            ("connected", "[i32; 8]"),
+            ("passed", "[Score; 8]"),
+            ("passed_king_distance", "[i32; 2]"),
+        ],
+    ),
+    (
+        "src/position/mobility.rs",
+        "pub(super) ",
+        &[
+            ("mobility", "[Score; 6]"),
+            ("mobility_offset", "[i32; 6]"),
+            ("king_attack_weights", "[i32; 6]"),
+            ("max_king_attack_units", "i32"),
+            ("king_danger_divisor", "i32"),
+            ("king_danger_endgame", "i32"),
+            ("pawn_shield", "[Score; 2]"),
+            ("semi_open_file_near_king", "Score"),
+            ("open_file_near_king", "Score"),
+            ("threat_by_pawn", "Score"),
+            ("hanging", "Score"),
+        ],
+    ),
+    (
+        "src/position/endgame/mod.rs",
+        "pub ",
+        &[("known_win", "i32")],
+    ),
+];
+
+// Parameters of the Adam optimizer
+const BETA1: f64 = 0.9;
+const BETA2: f64 = 0.999;
+const EPSILON: f64 = 1e-8;
+
+/// A position reduced to what is needed to evaluate it with different weights
+struct Entry {
+    /// Index into the piece-square tables and whether the piece is white (1) or black (-1)
+    features: Vec<(u16, i8)>,
+    /// Change of the evaluation when increasing one of the other weights by one, for each weight
+    /// that changes it
+    weights: Vec<(u16, Score)>,
+    phase: i32,
+    /// The part of the evaluation that doesn't depend on any weight, from white's perspective
+    fixed: Score,
+    /// Result of the game from white's perspective, between 0 and 1
+    result: f64,
+}
+
+impl Entry {
+    /// Returns `None` for positions that are evaluated by dedicated endgame knowledge
+    fn new(position: &mut Position, params: &Arc<EvalParams>, result: f64) -> Option<Entry> {
+        position.set_params(params.clone());
+        if position.evaluate_endgame().is_some() {
+            return None;
+        }
+
+        let mut features = vec![];
+        let mut phase = 0;
+        for (sq, _) in position.occupied().iter() {
+            let pc = position.at(sq);
+            let kind = pc.kind().0 as usize;
+            phase += PHASE_WEIGHTS[kind];
+
+            // The tables start with a8, so white needs to flip the rank to look up a square
+            let (idx, sign) = if pc.side() == WHITE {
+                (sq.0 ^ 56, 1)
+            } else {
+                (sq.0, -1)
+            };
+            features.push(((kind * 64 + idx as usize) as u16, sign));
+        }
+
+        Some(Entry {
+            features,
+            weights: vec![],
+            phase: phase.min(MAX_PHASE),
+            fixed: position.evaluate_pawns() + position.evaluate_activity(),
+            result,
+        })
+    }
+
+    fn evaluate(&self, params: &[f64]) -> f64 {
+        let mut mg = self.fixed.0 as f64;
+        let mut eg = self.fixed.1 as f64;
+        for &(idx, sign) in self.features.iter() {
+            mg += sign as f64 * params[2 * idx as usize];
+            eg += sign as f64 * params[2 * idx as usize + 1];
+        }
+        for &(weight, change) in self.weights.iter() {
+            let weight = params[2 * PARAMS + weight as usize];
+            mg += change.0 as f64 * weight;
+            eg += change.1 as f64 * weight;
+        }
+        let phase = self.phase as f64 / MAX_PHASE as f64;
+        mg * phase + eg * (1.0 - phase)
+    }
+}
+
+fn sigmoid(k: f64, score: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf(-k * score / 400.0))
+}
+
+/// Parses the game result of an EPD line, either as a PGN result like `"1-0"` or as a number in
+/// brackets like `[0.5]`
+fn parse_result(s: &str) -> Option<f64> {
+    s.split(|c: char| c.is_whitespace() || c == ';' || c == '"')
+        .find_map(|token| match token.trim_matches(|c| c == '[' || c == ']') {
+            "1-0" | "1.0" | "1" => Some(1.0),
+            "0-1" | "0.0" | "0" => Some(0.0),
+            "1/2-1/2" | "0.5" => Some(0.5),
+            _ => None,
+        })
+}
+
+/// Parses a line with the four EPD fields, optionally followed by the move clocks, and the result
+fn parse_epd(line: &str) -> Result<(Position, f64), String> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    if fields.len() < 5 {
+        return Err(format!("Not enough fields for EPD: {line}"));
+    }
+
+    // The move clocks are optional, and neither of them matters for the evaluation
+    let position = Position::try_from_fen(&fields[0..4].join(" "))?;
+    let result = parse_result(&fields[4..].join(" "))
+        .ok_or_else(|| format!("Missing result in EPD: {line}"))?;
+
+    Ok((position, result))
+}
+
+/// Collects the paths of all numbers in the serialized weights, in the format of `Value::pointer`
+fn weight_paths(value: &Value, path: String, paths: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter() {
+                if !TABLE_WEIGHTS.contains(&key.as_str()) {
+                    weight_paths(value, format!("{path}/{key}"), paths);
+                }
+            }
+        }
+        Value::Array(values) => {
+            for (i, value) in values.iter().enumerate() {
+                weight_paths(value, format!("{path}/{i}"), paths);
+            }
+        }
+        Value::Number(_) => paths.push(path),
+        _ => (),
+    }
+}
+
+/// Formats a serialized weight as a Rust expression of the given type
+fn rust_value(value: &Value, ty: &str) -> String {
+    match value {
+        Value::Array(values) if ty.starts_with('[') => {
+            let (inner, _) = ty[1..].split_once(';').unwrap();
+            let values: Vec<String> = values.iter().map(|v| rust_value(v, inner)).collect();
+            if inner == "Score" && values.len() > 2 {
+                format!("[\n    {},\n]", values.join(",\n    "))
+            } else {
+                format!("[{}]", values.join(", "))
+            }
+        }
+        Value::Array(values) => format!("Score({}, {})", values[0], values[1]),
+        _ => value.to_string(),
+    }
+}
+
+/// Tunes all weights of the evaluation by minimizing the error between the game results and the
+/// sigmoid of the evaluation. The piece values and piece-square tables are tuned as one table for
+/// each piece kind, which is linear in the evaluation. How much the other weights change the
+/// evaluation is measured once per position by increasing each of them by one, which is exact
+/// for weights the evaluation is linear in and an approximation for the others.
+pub struct Tuner {
+    entries: Vec<Entry>,
+    /// The weights to start from, used for the parts of the evaluation that aren't tuned
+    base: Arc<EvalParams>,
+    /// Paths of the weights outside of the tables in the serialized weights
+    weights: Vec<String>,
+    /// The base weights with one of the weights outside of the tables increased by one
+    increased: Vec<Arc<EvalParams>>,
+    /// The middlegame and endgame parameter of each table entry, followed by the other weights
+    params: Vec<f64>,
+    /// Scales the evaluation before applying the sigmoid
+    pub k: f64,
+    threads: usize,
+    // State of the Adam optimizer
+    momentum: Vec<f64>,
+    velocity: Vec<f64>,
+    steps: i32,
+}
+
+impl Tuner {
+    pub fn new(base: Arc<EvalParams>, threads: usize) -> Tuner {
+        let mut params = vec![0.0; 2 * PARAMS];
+        for (kind, value) in base.piece_values().iter().enumerate() {
+            for idx in 0..64 {
+                let param = 2 * (kind * 64 + idx);
+                params[param] = (value.0 + base.mg_tables()[kind][idx / 8][idx % 8]) as f64;
+                params[param + 1] = (value.1 + base.eg_tables()[kind][idx / 8][idx % 8]) as f64;
+            }
+        }
+
+        let value = serde_json::to_value(&*base).unwrap();
+        let mut weights = vec![];
+        weight_paths(&value, String::new(), &mut weights);
+        let increased = weights
+            .iter()
+            .map(|path| {
+                let mut value = value.clone();
+                let weight = value.pointer_mut(path).unwrap();
+                *weight = (weight.as_i64().unwrap() + 1).into();
+                Arc::new(serde_json::from_value(value).unwrap())
+            })
+            .collect();
+        params.extend(
+            weights
+                .iter()
+                .map(|path| value.pointer(path).unwrap().as_f64().unwrap()),
+        );
+
+        Tuner {
+            entries: vec![],
+            base,
+            weights,
+            increased,
+            momentum: vec![0.0; params.len()],
+            velocity: vec![0.0; params.len()],
+            params,
+            k: 1.0,
+            threads: threads.max(1),
+            steps: 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Adds positions in batches, as evaluating positions with different weights clears the pawn
+    /// hash table whenever the weights change
+    fn add(&mut self, mut positions: Vec<(Position, f64)>) {
+        let tuner = &*self;
+        let chunk_size = positions.len().div_ceil(self.threads).max(1);
+        let entries: Vec<Entry> = thread::scope(|scope| {
+            let handles: Vec<_> = positions
+                .chunks_mut(chunk_size)
+                .map(|chunk| scope.spawn(move || tuner.entries(chunk)))
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|h| h.join().unwrap())
+                .collect()
+        });
+        self.entries.extend(entries);
+    }
+
+    fn entries(&self, positions: &mut [(Position, f64)]) -> Vec<Entry> {
+        let mut entries: Vec<(usize, Entry)> = positions
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(i, (position, result))| {
+                Some((i, Entry::new(position, &self.base, *result)?))
+            })
+            .collect();
+
+        for (weight, params) in self.increased.iter().enumerate() {
+            for (i, entry) in entries.iter_mut() {
+                let position = &mut positions[*i].0;
+                position.set_params(params.clone());
+                let change = position.evaluate_pawns() + position.evaluate_activity() - entry.fixed;
+                if change != Score(0, 0) {
+                    entry.weights.push((weight as u16, change));
+                }
+            }
+        }
+
+        // The evaluation adds the weights back on top of the fixed part
+        entries
+            .into_iter()
+            .map(|(_, mut entry)| {
+                for &(weight, change) in entry.weights.iter() {
+                    let weight = self.params[2 * PARAMS + weight as usize] as i32;
+                    entry.fixed -= Score(change.0 * weight, change.1 * weight);
+                }
+                entry
+            })
+            .collect()
+    }
+
+    /// Loads positions from an EPD file with results, or from a file written by `datagen` if the
+    /// extension is `bin`
+    pub fn load(&mut self, path: &Path) -> Result<(), String> {
+        const BATCH_SIZE: usize = 1 << 14;
+
+        let file = File::open(path)
+            .map_err(|err| format!("Failed to open {}: {}", path.display(), err))?;
+        let mut reader = BufReader::new(file);
+        let mut positions = Vec::with_capacity(BATCH_SIZE);
+
+        if path.extension().is_some_and(|ext| ext == "bin") {
+            while let Some(record) = Record::read(&mut reader)? {
+                positions.push((record.position, record.result as f64 / 2.0));
+                if positions.len() == BATCH_SIZE {
+                    self.add(std::mem::take(&mut positions));
+                }
+            }
+            self.add(positions);
+            return Ok(());
+        }
+
+        for line in reader.lines() {
+            let line = line.map_err(|err| err.to_string())?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            positions.push(parse_epd(&line)?);
+            if positions.len() == BATCH_SIZE {
+                self.add(std::mem::take(&mut positions));
+            }
+        }
+        self.add(positions);
+
+        Ok(())
+    }
+
+    /// Mean squared error of all positions with the given scaling
+    pub fn error(&self, k: f64) -> f64 {
+        let chunk_size = self.entries.len().div_ceil(self.threads).max(1);
+        let total: f64 = thread::scope(|scope| {
+            let handles: Vec<_> = self
+                .entries
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|entry| {
+                                (entry.result - sigmoid(k, entry.evaluate(&self.params))).powi(2)
+                            })
+                            .sum::<f64>()
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).sum()
+        });
+        total / self.entries.len().max(1) as f64
+    }
+
+    /// Finds the scaling that best fits the current evaluation to the results, which needs to be
+    /// done once before tuning
+    pub fn optimize_k(&mut self) {
+        let (mut low, mut high) = (0.0, 10.0);
+        for _ in 0..50 {
+            let a = low + (high - low) / 3.0;
+            let b = high - (high - low) / 3.0;
+            if self.error(a) < self.error(b) {
+                high = b;
+            } else {
+                low = a;
+            }
+        }
+        self.k = (low + high) / 2.0;
+    }
+
+    fn gradient(&self) -> Vec<f64> {
+        let chunk_size = self.entries.len().div_ceil(self.threads).max(1);
+        thread::scope(|scope| {
+            let handles: Vec<_> = self
+                .entries
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        let mut gradient = vec![0.0; self.params.len()];
+                        for entry in chunk.iter() {
+                            let s = sigmoid(self.k, entry.evaluate(&self.params));
+                            // The constant factors are left out, as the optimizer normalizes the
+                            // size of the steps anyway
+                            let d = (s - entry.result) * s * (1.0 - s);
+                            let phase = entry.phase as f64 / MAX_PHASE as f64;
+                            for &(idx, sign) in entry.features.iter() {
+                                gradient[2 * idx as usize] += d * sign as f64 * phase;
+                                gradient[2 * idx as usize + 1] += d * sign as f64 * (1.0 - phase);
+                            }
+                            for &(weight, change) in entry.weights.iter() {
+                                gradient[2 * PARAMS + weight as usize] +=
+                                    d * (change.0 as f64 * phase + change.1 as f64 * (1.0 - phase));
+                            }
+                        }
+                        gradient
+                    })
+                })
+                .collect();
+
+            let mut gradient = vec![0.0; self.params.len()];
+            for handle in handles {
+                for (total, g) in gradient.iter_mut().zip(handle.join().unwrap()) {
+                    *total += g;
+                }
+            }
+            gradient
+        })
+    }
+
+    /// Runs one step of gradient descent over all positions
+    pub fn step(&mut self, learning_rate: f64) {
+        let gradient = self.gradient();
+        self.steps += 1;
+
+        let params = self
+            .params
+            .iter_mut()
+            .zip(self.momentum.iter_mut())
+            .zip(self.velocity.iter_mut())
+            .zip(gradient.iter());
+        for (((param, m), v), g) in params {
+            *m = BETA1 * *m + (1.0 - BETA1) * g;
+            *v = BETA2 * *v + (1.0 - BETA2) * g * g;
+
+            let m_hat = *m / (1.0 - BETA1.powi(self.steps));
+            let v_hat = *v / (1.0 - BETA2.powi(self.steps));
+            *param -= learning_rate * m_hat / (v_hat.sqrt() + EPSILON);
+        }
+    }
+
+    /// Splits the tuned tables into piece values and piece-square tables
+    fn tables(&self) -> ([Score; 6], [[Score; 64]; 6]) {
+        let mut values = [Score(0, 0); 6];
+        let mut tables = [[Score(0, 0); 64]; 6];
+        let param =
+            |kind: usize, idx: usize, phase: usize| self.params[2 * (kind * 64 + idx) + phase];
+
+        for kind in 1..6 {
+            // Pawns never stand on the first and last rank, so those squares don't count
+            let squares: Vec<usize> = if kind == 5 {
+                (8..56).collect()
+            } else {
+                (0..64).collect()
+            };
+            let mean = |phase: usize| {
+                squares
+                    .iter()
+                    .map(|&idx| param(kind, idx, phase))
+                    .sum::<f64>()
+                    / squares.len() as f64
+            };
+            values[kind] = Score(mean(0).round() as i32, mean(1).round() as i32);
+
+            for &idx in squares.iter() {
+                tables[kind][idx] = Score(
+                    param(kind, idx, 0).round() as i32 - values[kind].0,
+                    param(kind, idx, 1).round() as i32 - values[kind].1,
+                );
+            }
+        }
+
+        // Kings are always on the board, so their table doesn't need a separate value
+        let king = KING.0 as usize;
+        for (idx, score) in tables[king].iter_mut().enumerate() {
+            *score = Score(
+                param(king, idx, 0).round() as i32,
+                param(king, idx, 1).round() as i32,
+            );
+        }
+
+        (values, tables)
+    }
+
+    /// Returns the tuned weights
+    pub fn to_params(&self) -> EvalParams {
+        let mut value = serde_json::to_value(&*self.base).unwrap();
+        for (i, path) in self.weights.iter().enumerate() {
+            let mut weight = self.params[2 * PARAMS + i].round() as i64;
+            if DIVISORS.contains(&path.as_str()) {
+                weight = weight.max(1);
+            }
+            *value.pointer_mut(path).unwrap() = weight.into();
+        }
+        let mut params: EvalParams = serde_json::from_value(value).unwrap();
+
+        let (values, tables) = self.tables();
+        let mut mg_tables = *params.mg_tables();
+        let mut eg_tables = *params.eg_tables();
+        for (kind, table) in tables.iter().enumerate() {
+            for (idx, score) in table.iter().enumerate() {
+                mg_tables[kind][idx / 8][idx % 8] = score.0;
+                eg_tables[kind][idx / 8][idx % 8] = score.1;
+            }
+        }
+        params.set_piece_values(values);
+        params.set_tables(mg_tables, eg_tables);
+        params
+    }
+
+    /// Formats the tuned weights as Rust source, with the same declarations as the constants the
+    /// weights are compiled in from. The declarations of each file follow a comment with its
+    /// path.
+    pub fn to_rust_source(&self) -> String {
+        let params = self.to_params();
+        let mut source = String::new();
+
+        writeln!(source, "// src/position/psqt.rs\n").unwrap();
+        writeln!(
+            source,
+            "/// Middlegame and endgame values, indexed by `PieceKind`"
+        )
+        .unwrap();
+        writeln!(source, "pub(super) const PIECE_VALUES: [Score; 6] = [").unwrap();
+        for value in params.piece_values().iter() {
+            writeln!(source, "    Score({}, {}),", value.0, value.1).unwrap();
+        }
+        writeln!(source, "];").unwrap();
+
+        let names = ["KING", "QUEEN", "ROOK", "BISHOP", "KNIGHT", "PAWN"];
+        for (kind, name) in names.iter().enumerate() {
+            for (prefix, tables) in [("MG", params.mg_tables()), ("EG", params.eg_tables())] {
+                writeln!(source, "\n#[rustfmt::skip]").unwrap();
+                writeln!(source, "const {prefix}_{name}: [i32; 64] = [").unwrap();
+                for row in tables[kind].iter() {
+                    let row: Vec<String> = row.iter().map(|value| format!("{value:>3}")).collect();
+                    writeln!(source, "    {},", row.join(", ")).unwrap();
+                }
+                writeln!(source, "];").unwrap();
+            }
+        }
+
+        writeln!(source).unwrap();
+        for prefix in ["MG", "EG"] {
+            let tables: Vec<String> = names
+                .iter()
+                .map(|name| format!("{prefix}_{name}"))
+                .collect();
+            writeln!(
+                source,
+                "pub(super) const {prefix}_TABLES: [[i32; 64]; 6] =\n    [{}];",
+                tables.join(", ")
+            )
+            .unwrap();
+        }
+
+        let value = serde_json::to_value(&params).unwrap();
+        for (file, visibility, constants) in CONSTANTS {
+            writeln!(source, "\n// {file}\n").unwrap();
+            for (name, ty) in constants {
+                let name_upper = name.to_uppercase();
+                let value = rust_value(&value[name], ty);
+                writeln!(source, "{visibility}const {name_upper}: {ty} = {value};").unwrap();
+            }
+        }
+
+        source
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        piece::{Piece, PieceKind, PAWN},
+        position::EvalParams,
+        square::Square,
+        tune::{parse_epd, parse_result, Tuner, PARAMS, TABLE_WEIGHTS},
+        Position,
+    };
+    use serde_json::Value;
+
+    #[test]
+    fn parses_epd() {
+        assert_eq!(parse_result("c9 \"1-0\";"), Some(1.0));
+        assert_eq!(parse_result("c9 \"1/2-1/2\";"), Some(0.5));
+        assert_eq!(parse_result("[0.0]"), Some(0.0));
+        assert_eq!(parse_result("bm e4;"), None);
+
+        let (position, result) =
+            parse_epd("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - c9 \"0-1\";")
+                .unwrap();
+        assert_eq!(
+            position.to_fen(),
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1"
+        );
+        assert_eq!(result, 0.0);
+
+        let (_, result) = parse_epd("8/8/8/3k4/8/3K4/8/8 w - - 12 40 [0.5]").unwrap();
+        assert_eq!(result, 0.5);
+
+        assert!(parse_epd("8/8/8/3k4/8/3K4/8/8 w - -").is_err());
+    }
+
+    #[test]
+    fn reduces_error() {
+        // Positions where white is up a knight, but the games were lost anyway
+        let mut tuner = Tuner::new(EvalParams::shared_default(), 2);
+        tuner.add(
+            [
+                "r1bqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+                "rnbqkb1r/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+                "rnbqkb1r/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 0 1",
+            ]
+            .map(|fen| (Position::from_fen(fen), 0.0))
+            .to_vec(),
+        );
+        assert_eq!(tuner.len(), 3);
+        tuner.optimize_k();
+
+        let before = tuner.error(tuner.k);
+        let weights = tuner.params[2 * PARAMS..].to_vec();
+        for _ in 0..20 {
+            tuner.step(5.0);
+        }
+        assert!(tuner.error(tuner.k) < before);
+
+        // The mobility of the knights changes too
+        assert_ne!(tuner.params[2 * PARAMS..], weights);
+    }
+
+    #[test]
+    fn weights() {
+        let tuner = Tuner::new(EvalParams::shared_default(), 1);
+        assert!(tuner.weights.contains(&"/hanging/1".to_string()));
+        assert!(tuner.weights.contains(&"/king_danger_divisor".to_string()));
+        assert!(tuner.weights.iter().all(|path| !path.contains("tables")));
+
+        // Every weight is changed by exactly one
+        for (path, params) in tuner.weights.iter().zip(tuner.increased.iter()) {
+            let original = serde_json::to_value(EvalParams::default()).unwrap();
+            let increased = serde_json::to_value(&**params).unwrap();
+            let weight = |value: &Value| value.pointer(path).unwrap().as_i64().unwrap();
+            assert_eq!(weight(&increased), weight(&original) + 1);
+        }
+
+        // Divisors stay positive
+        let mut tuner = Tuner::new(EvalParams::shared_default(), 1);
+        let divisor = tuner
+            .weights
+            .iter()
+            .position(|path| path == "/king_danger_divisor");
+        tuner.params[2 * PARAMS + divisor.unwrap()] = -3.0;
+        assert_eq!(tuner.to_params().king_danger_divisor, 1);
+    }
+
+    #[test]
+    fn params() {
+        // Without any tuning, the weights evaluate exactly like the original ones
+        let params = Tuner::new(EvalParams::shared_default(), 1).to_params();
+        let weights = |params: &EvalParams| {
+            let mut value = serde_json::to_value(params).unwrap();
+            let fields = value.as_object_mut().unwrap();
+            fields.retain(|name, _| !TABLE_WEIGHTS.contains(&name.as_str()));
+            value
+        };
+        assert_eq!(weights(&params), weights(&EvalParams::default()));
+        for pc in 0..12 {
+            // Pawns never stand on the first and last rank
+            let squares = if Piece(pc).kind() == PAWN {
+                8..56
+            } else {
+                0..64
+            };
+            for sq in squares {
+                assert_eq!(
+                    params.piece_square(Piece(pc), Square(sq)),
+                    EvalParams::default().piece_square(Piece(pc), Square(sq))
+                );
+            }
+        }
+    }
+
+    /// Returns the visibility, name and type of each constant declared in the source
+    fn declarations(source: &str) -> Vec<(String, String, String)> {
+        source
+            .lines()
+            .filter_map(|line| {
+                let (visibility, declaration) = line.split_once("const ")?;
+                let (name, ty) = declaration.split_once(": ")?;
+                let (ty, _) = ty.split_once(" =")?;
+                (visibility.is_empty() || visibility.starts_with("pub"))
+                    .then(|| (visibility.to_string(), name.to_string(), ty.to_string()))
+            })
+            .collect()
+    }
+
+    /// Returns the numbers of each constant with a value spanning multiple lines
+    fn parse_source(source: &str) -> Vec<(String, Vec<i32>)> {
+        let mut constants = vec![];
+        let mut lines = source.lines();
+        while let Some(line) = lines.next() {
+            let Some((_, declaration)) = line.split_once("const ") else {
+                continue;
+            };
+            if !line.ends_with('[') {
+                continue;
+            }
+            let (name, _) = declaration.split_once(':').unwrap();
+            let values = lines
+                .by_ref()
+                .take_while(|&line| line != "];")
+                .flat_map(|line| line.split(|c: char| c != '-' && !c.is_ascii_digit()))
+                .filter(|value| !value.is_empty())
+                .map(|value| value.parse().unwrap())
+                .collect();
+            constants.push((name.to_string(), values));
+        }
+        constants
+    }
+
+    #[test]
+    fn rust_source() {
+        let source = Tuner::new(EvalParams::shared_default(), 1).to_rust_source();
+
+        // The source declares the same constants as the files the weights are compiled in from,
+        // and all of the constants of `psqt.rs` apart from the ones that aren't weights
+        let files = [
+            ("src/position/psqt.rs", include_str!("position/psqt.rs")),
+            ("src/position/pawns.rs", include_str!("position/pawns.rs")),
+            (
+                "src/position/mobility.rs",
+                include_str!("position/mobility.rs"),
+            ),
+            (
+                "src/position/endgame/mod.rs",
+                include_str!("position/endgame/mod.rs"),
+            ),
+        ];
+        let mut count = 0;
+        for section in source.split("// src/").skip(1) {
+            let (path, section) = section.split_once('\n').unwrap();
+            let path = format!("src/{path}");
+            let (_, file) = files.iter().find(|(p, _)| *p == path).unwrap();
+            let declared = declarations(section);
+            let original = declarations(file);
+            for declaration in declared.iter() {
+                assert!(original.contains(declaration), "{declaration:?} in {path}");
+            }
+            if path.ends_with("psqt.rs") {
+                let not_weights = ["MAX_PHASE", "PHASE_WEIGHTS", "MATERIAL"];
+                for declaration in original.iter() {
+                    assert!(
+                        not_weights.contains(&declaration.1.as_str())
+                            || declared.contains(declaration),
+                        "{declaration:?} missing"
+                    );
+                }
+            }
+            count += declared.len();
+        }
+        let weights = serde_json::to_value(EvalParams::default()).unwrap();
+        assert_eq!(count, 12 + 3 + weights.as_object().unwrap().len() - 3);
+
+        // Without any tuning, the values and tables in the source evaluate exactly like the
+        // original ones, even though the tables are centered around a different piece value
+        let constants = parse_source(&source);
+        let constant = |name: &str| {
+            let (_, values) = constants.iter().find(|(n, _)| n == name).unwrap();
+            values.clone()
+        };
+        assert_eq!(source.matches("#[rustfmt::skip]").count(), 12);
+
+        let params = EvalParams::default();
+        let piece_values = constant("PIECE_VALUES");
+        for (kind, name) in ["KING", "QUEEN", "ROOK", "BISHOP", "KNIGHT", "PAWN"]
+            .iter()
+            .enumerate()
+        {
+            let squares = if PieceKind(kind as u8) == PAWN {
+                8..56
+            } else {
+                0..64
+            };
+            for (phase, prefix, tables) in
+                [(0, "MG", params.mg_tables()), (1, "EG", params.eg_tables())]
+            {
+                let table = constant(&format!("{prefix}_{name}"));
+                assert_eq!(table.len(), 64);
+                for sq in squares.clone() {
+                    let original = params.piece_values()[kind];
+                    let original = [original.0, original.1][phase] + tables[kind][sq / 8][sq % 8];
+                    assert_eq!(
+                        piece_values[2 * kind + phase] + table[sq],
+                        original,
+                        "{prefix}_{name}[{sq}]"
+                    );
+                }
+            }
+        }
+        assert_eq!(
+            constant("PASSED"),
+            [0, 0, 2, 38, 15, 36, 22, 50, 64, 81, 166, 184, 284, 269, 0, 0]
+        );
+        assert!(source.contains("pub(super) const HANGING: Score = Score(32, 18);"));
+    }
+}
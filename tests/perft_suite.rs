@@ -0,0 +1,41 @@
+use std::{path::Path, process::Command};
+
+fn perft_suite(suite: &Path) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_mick"))
+        .arg("perft-suite")
+        .arg(suite)
+        .args(["--max-depth", "6", "--time-limit", "1"])
+        .output()
+        .unwrap();
+    (
+        output.status.success(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+#[test]
+fn standard_suite_passes() {
+    let suite = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data/perftsuite.epd");
+    let (success, output) = perft_suite(&suite);
+    assert!(success, "{output}");
+    assert!(!output.contains("FAIL"), "{output}");
+    // Some positions only have a D6 count, every one of them has to run at least that depth
+    assert!(!output.contains("SKIP"), "{output}");
+    assert!(output.contains("Passed 21 of 21 positions"), "{output}");
+}
+
+#[test]
+fn wrong_count_fails() {
+    let suite = std::env::temp_dir().join(format!("mick-perft-suite-{}.epd", std::process::id()));
+    std::fs::write(
+        &suite,
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 ;D1 20 ;D2 401\n",
+    )
+    .unwrap();
+    let (success, output) = perft_suite(&suite);
+    std::fs::remove_file(&suite).unwrap();
+
+    assert!(!success);
+    assert!(output.contains("D2: expected 401, got 400"), "{output}");
+    assert!(output.contains("Passed 0 of 1 positions"), "{output}");
+}